@@ -8,8 +8,8 @@ use ipmi_rs::{
         rmcp::{Active, Rmcp},
         File, IpmiCommand,
     },
-    storage::sdr,
-    Ipmi, IpmiCommandError, SdrIter,
+    storage::{sdr, sel},
+    Ipmi, IpmiCommandError, SdrIter, SdrIterError, SelIter, SelIterError,
 };
 
 #[allow(unused)]
@@ -26,7 +26,7 @@ enum SdrIterInner<'a> {
 }
 
 impl Iterator for SdrIterInner<'_> {
-    type Item = sdr::Record;
+    type Item = Result<sdr::Record, SdrIterError<std::io::Error>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
@@ -36,6 +36,22 @@ impl Iterator for SdrIterInner<'_> {
     }
 }
 
+enum SelIterInner<'a> {
+    Rmcp(SelIter<'a, Rmcp<Active>>),
+    File(SelIter<'a, File>),
+}
+
+impl Iterator for SelIterInner<'_> {
+    type Item = Result<sel::Entry, SelIterError<std::io::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            SelIterInner::Rmcp(rmcp) => rmcp.next(),
+            SelIterInner::File(file) => file.next(),
+        }
+    }
+}
+
 impl IpmiConnectionEnum {
     pub fn send_recv<CMD>(
         &mut self,
@@ -50,12 +66,23 @@ impl IpmiConnectionEnum {
         }
     }
 
-    pub fn sdrs(&mut self) -> impl Iterator<Item = sdr::Record> + '_ {
+    pub fn sdrs(
+        &mut self,
+    ) -> impl Iterator<Item = Result<sdr::Record, SdrIterError<std::io::Error>>> + '_ {
         match self {
             IpmiConnectionEnum::Rmcp(rmcp) => SdrIterInner::Rmcp(rmcp.sdrs()),
             IpmiConnectionEnum::File(file) => SdrIterInner::File(file.sdrs()),
         }
     }
+
+    pub fn sel_entries(
+        &mut self,
+    ) -> impl Iterator<Item = Result<sel::Entry, SelIterError<std::io::Error>>> + '_ {
+        match self {
+            IpmiConnectionEnum::Rmcp(rmcp) => SelIterInner::Rmcp(rmcp.sel_entries()),
+            IpmiConnectionEnum::File(file) => SelIterInner::File(file.sel_entries()),
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -9,7 +9,7 @@ use ipmi_rs::{
         record::RecordContents, GetDeviceSdrInfo, GetSdrAllocInfo, GetSdrRepositoryInfo, SdrCount,
         SdrOperation,
     },
-    storage::sel::{GetSelAllocInfo, GetSelEntry, GetSelInfo, RecordId as SelRecordId, SelCommand},
+    storage::sel::{GetSelAllocInfo, GetSelInfo, SelCommand},
     LogOutput, SensorRecord,
 };
 
@@ -38,12 +38,19 @@ fn main() -> std::io::Result<()> {
     }
 
     if info.entries > 0 {
-        log::info!("Getting first record");
-        let first_record = ipmi
-            .send_recv(GetSelEntry::new(None, SelRecordId::LAST))
-            .unwrap();
-
-        ipmi_rs::Logger::log(log_output, &first_record);
+        log::info!("Getting SEL entries");
+
+        for entry in ipmi.sel_entries() {
+            match entry {
+                Ok(entry) => {
+                    ipmi_rs::Logger::log(log_output, &entry);
+                    if let Some(event) = entry.system_event() {
+                        log::info!("  {event}");
+                    }
+                }
+                Err(e) => log::warn!("Failed to read SEL entry: {e:?}"),
+            }
+        }
     }
 
     let device_id = ipmi.send_recv(GetDeviceId).unwrap();
@@ -77,9 +84,10 @@ fn main() -> std::io::Result<()> {
 
     let sensors = ipmi
         .sdrs()
-        .map(|v| {
+        .filter_map(|v| {
             progress_bar.inc(1);
-            v
+            v.map_err(|e| log::warn!("Failed to read SDR record: {e:?}"))
+                .ok()
         })
         .collect::<Vec<_>>();
 
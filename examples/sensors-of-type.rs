@@ -45,6 +45,10 @@ fn main() -> std::io::Result<()> {
 
     let sdrs_of_type: Vec<Record> = ipmi
         .sdrs()
+        .filter_map(|s| {
+            s.map_err(|e| log::warn!("Failed to read SDR record: {e:?}"))
+                .ok()
+        })
         .filter(|s| {
             s.common_data()
                 .map(|c| sensor_types.contains(&c.ty))
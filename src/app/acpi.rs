@@ -0,0 +1,196 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// The ACPI power state of the system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SystemPowerState {
+    /// S0/G0, working.
+    S0,
+    S1,
+    S2,
+    S3,
+    S4,
+    /// S5/G2, soft off.
+    S5,
+    /// Either S4 or S5.
+    S4S5,
+    /// G3, mechanical off.
+    G3,
+    /// Sleeping in S1, S2 or S3.
+    Sleeping,
+    /// G1, sleeping in an unknown state.
+    G1,
+    /// S5, entered by an override such as a power button press.
+    Override,
+    LegacyOn,
+    LegacyOff,
+    Unknown,
+    Reserved(u8),
+}
+
+impl From<u8> for SystemPowerState {
+    fn from(value: u8) -> Self {
+        match value & 0x7F {
+            0x00 => Self::S0,
+            0x01 => Self::S1,
+            0x02 => Self::S2,
+            0x03 => Self::S3,
+            0x04 => Self::S4,
+            0x05 => Self::S5,
+            0x06 => Self::S4S5,
+            0x07 => Self::G3,
+            0x08 => Self::Sleeping,
+            0x09 => Self::G1,
+            0x0A => Self::Override,
+            0x20 => Self::LegacyOn,
+            0x21 => Self::LegacyOff,
+            0x2A => Self::Unknown,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<SystemPowerState> for u8 {
+    fn from(value: SystemPowerState) -> Self {
+        match value {
+            SystemPowerState::S0 => 0x00,
+            SystemPowerState::S1 => 0x01,
+            SystemPowerState::S2 => 0x02,
+            SystemPowerState::S3 => 0x03,
+            SystemPowerState::S4 => 0x04,
+            SystemPowerState::S5 => 0x05,
+            SystemPowerState::S4S5 => 0x06,
+            SystemPowerState::G3 => 0x07,
+            SystemPowerState::Sleeping => 0x08,
+            SystemPowerState::G1 => 0x09,
+            SystemPowerState::Override => 0x0A,
+            SystemPowerState::LegacyOn => 0x20,
+            SystemPowerState::LegacyOff => 0x21,
+            SystemPowerState::Unknown => 0x2A,
+            SystemPowerState::Reserved(v) => v & 0x7F,
+        }
+    }
+}
+
+/// The ACPI power state of the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DevicePowerState {
+    D0,
+    D1,
+    D2,
+    D3,
+    Unknown,
+    Reserved(u8),
+}
+
+impl From<u8> for DevicePowerState {
+    fn from(value: u8) -> Self {
+        match value & 0x7F {
+            0x00 => Self::D0,
+            0x01 => Self::D1,
+            0x02 => Self::D2,
+            0x03 => Self::D3,
+            0x2A => Self::Unknown,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<DevicePowerState> for u8 {
+    fn from(value: DevicePowerState) -> Self {
+        match value {
+            DevicePowerState::D0 => 0x00,
+            DevicePowerState::D1 => 0x01,
+            DevicePowerState::D2 => 0x02,
+            DevicePowerState::D3 => 0x03,
+            DevicePowerState::Unknown => 0x2A,
+            DevicePowerState::Reserved(v) => v & 0x7F,
+        }
+    }
+}
+
+/// The ACPI power states of the system and the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AcpiPowerState {
+    pub system: SystemPowerState,
+    pub device: DevicePowerState,
+}
+
+/// Get the ACPI power states that were last set with
+/// [`SetAcpiPowerState`].
+pub struct GetAcpiPowerState;
+
+impl From<GetAcpiPowerState> for Message {
+    fn from(_: GetAcpiPowerState) -> Self {
+        Message::new_request(NetFn::App, 0x07, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetAcpiPowerState {
+    type Output = AcpiPowerState;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(AcpiPowerState {
+            system: data[0].into(),
+            device: data[1].into(),
+        })
+    }
+}
+
+/// Inform the BMC of the ACPI power states of the system and the
+/// device.
+///
+/// This does not change the power state of the system: it only
+/// records the state, which is normally set by system software.
+pub struct SetAcpiPowerState {
+    system: Option<SystemPowerState>,
+    device: Option<DevicePowerState>,
+}
+
+impl SetAcpiPowerState {
+    /// Set the given power states. The states that are `None` are
+    /// left unchanged.
+    pub fn new(system: Option<SystemPowerState>, device: Option<DevicePowerState>) -> Self {
+        Self { system, device }
+    }
+}
+
+impl From<SetAcpiPowerState> for Message {
+    fn from(value: SetAcpiPowerState) -> Self {
+        // A state of 0x7F without the "set" bit means "no change".
+        let state = |state: Option<u8>| state.map(|v| 0x80 | v).unwrap_or(0x7F);
+
+        Message::new_request(
+            NetFn::App,
+            0x06,
+            vec![
+                state(value.system.map(u8::from)),
+                state(value.device.map(u8::from)),
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for SetAcpiPowerState {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// Close a session.
+///
+/// Payloads, such as SOL, that were activated on the session are
+/// deactivated when it is closed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloseSession {
+    session_id: u32,
+}
+
+impl CloseSession {
+    /// Close the session with BMC session ID `session_id`.
+    pub fn new(session_id: u32) -> Self {
+        Self { session_id }
+    }
+}
+
+impl From<CloseSession> for Message {
+    fn from(value: CloseSession) -> Self {
+        Message::new_request(NetFn::App, 0x3C, value.session_id.to_le_bytes().to_vec())
+    }
+}
+
+impl IpmiCommand for CloseSession {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)
+    }
+}
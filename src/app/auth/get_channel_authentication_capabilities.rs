@@ -45,6 +45,15 @@ pub enum Channel {
     Number(u8),
 }
 
+impl From<Channel> for u8 {
+    fn from(value: Channel) -> Self {
+        match value {
+            Channel::Current => 0xE,
+            Channel::Number(n) => n & 0x0F,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GetChannelAuthenticationCapabilities {
     channel_number: u8,
@@ -53,13 +62,8 @@ pub struct GetChannelAuthenticationCapabilities {
 
 impl GetChannelAuthenticationCapabilities {
     pub fn new(channel_number: Channel, privilege_level: PrivilegeLevel) -> Self {
-        let channel_number = match channel_number {
-            Channel::Current => 0xE,
-            Channel::Number(n) => n & 0x0F,
-        };
-
         Self {
-            channel_number,
+            channel_number: channel_number.into(),
             privilege_level,
         }
     }
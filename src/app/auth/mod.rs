@@ -11,6 +11,12 @@ pub use get_session_challenge::{GetSessionChallenge, SessionChallenge};
 mod activate_session;
 pub use activate_session::{ActivateSession, BeginSessionInfo};
 
+mod set_session_privilege_level;
+pub use set_session_privilege_level::SetSessionPrivilegeLevel;
+
+mod close_session;
+pub use close_session::CloseSession;
+
 #[derive(Debug, Clone)]
 pub enum AuthError {
     /// A non-zero session ID was received at a stage where
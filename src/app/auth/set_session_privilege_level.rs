@@ -0,0 +1,49 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+use super::{AuthError, PrivilegeLevel};
+
+/// Set the privilege level of the current session.
+///
+/// Sessions start at the User privilege level, and can be raised up to
+/// the maximum privilege level that was requested when the session was
+/// activated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetSessionPrivilegeLevel {
+    privilege_level: PrivilegeLevel,
+}
+
+impl SetSessionPrivilegeLevel {
+    pub fn new(privilege_level: PrivilegeLevel) -> Self {
+        Self { privilege_level }
+    }
+}
+
+impl From<SetSessionPrivilegeLevel> for Message {
+    fn from(value: SetSessionPrivilegeLevel) -> Self {
+        Message::new_request(NetFn::App, 0x3B, vec![value.privilege_level.into()])
+    }
+}
+
+impl IpmiCommand for SetSessionPrivilegeLevel {
+    /// The new privilege level of the session.
+    type Output = PrivilegeLevel;
+
+    type Error = AuthError;
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let level = data[0]
+            .try_into()
+            .map_err(|_| AuthError::InvalidPrivilegeLevel(data[0]))?;
+
+        Ok(level)
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    app::auth::{Channel, PrivilegeLevel},
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+};
+
+/// The storage of channel access settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChannelAccessStorage {
+    /// The settings that are used after the BMC is reset.
+    NonVolatile,
+    /// The settings that are currently in use.
+    Volatile,
+}
+
+impl ChannelAccessStorage {
+    fn value(&self) -> u8 {
+        match self {
+            ChannelAccessStorage::NonVolatile => 0b01,
+            ChannelAccessStorage::Volatile => 0b10,
+        }
+    }
+}
+
+/// When a channel is available for IPMI messaging.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessMode {
+    Disabled,
+    PreBootOnly,
+    AlwaysAvailable,
+    /// The channel is shared with system software.
+    Shared,
+    Reserved(u8),
+}
+
+impl From<u8> for AccessMode {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0 => Self::Disabled,
+            1 => Self::PreBootOnly,
+            2 => Self::AlwaysAvailable,
+            3 => Self::Shared,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<AccessMode> for u8 {
+    fn from(value: AccessMode) -> Self {
+        match value {
+            AccessMode::Disabled => 0,
+            AccessMode::PreBootOnly => 1,
+            AccessMode::AlwaysAvailable => 2,
+            AccessMode::Shared => 3,
+            AccessMode::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+/// The access settings of a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelAccess {
+    pub alerting_enabled: bool,
+    pub per_message_authentication_enabled: bool,
+    pub user_level_authentication_enabled: bool,
+    pub access_mode: AccessMode,
+    /// The maximum privilege level that can be used on the channel.
+    ///
+    /// When setting the channel access, the privilege level limit is
+    /// not changed if this is `None`.
+    pub privilege_limit: Option<PrivilegeLevel>,
+}
+
+impl ChannelAccess {
+    fn access_byte(&self) -> u8 {
+        (!self.alerting_enabled as u8) << 5
+            | (!self.per_message_authentication_enabled as u8) << 4
+            | (!self.user_level_authentication_enabled as u8) << 3
+            | u8::from(self.access_mode)
+    }
+}
+
+/// Get the volatile or non-volatile access settings of a channel.
+pub struct GetChannelAccess {
+    channel: Channel,
+    storage: ChannelAccessStorage,
+}
+
+impl GetChannelAccess {
+    pub fn new(channel: Channel, storage: ChannelAccessStorage) -> Self {
+        Self { channel, storage }
+    }
+}
+
+impl From<GetChannelAccess> for Message {
+    fn from(value: GetChannelAccess) -> Self {
+        Message::new_request(
+            NetFn::App,
+            0x41,
+            vec![u8::from(value.channel), value.storage.value() << 6],
+        )
+    }
+}
+
+impl IpmiCommand for GetChannelAccess {
+    type Output = ChannelAccess;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(ChannelAccess {
+            alerting_enabled: (data[0] & 0x20) == 0,
+            per_message_authentication_enabled: (data[0] & 0x10) == 0,
+            user_level_authentication_enabled: (data[0] & 0x08) == 0,
+            access_mode: AccessMode::from(data[0]),
+            privilege_limit: PrivilegeLevel::try_from(data[1] & 0xF).ok(),
+        })
+    }
+}
+
+/// Set the volatile or non-volatile access settings of a channel.
+pub struct SetChannelAccess {
+    channel: Channel,
+    storage: ChannelAccessStorage,
+    access: ChannelAccess,
+}
+
+impl SetChannelAccess {
+    pub fn new(channel: Channel, storage: ChannelAccessStorage, access: ChannelAccess) -> Self {
+        Self {
+            channel,
+            storage,
+            access,
+        }
+    }
+}
+
+impl From<SetChannelAccess> for Message {
+    fn from(value: SetChannelAccess) -> Self {
+        let storage = value.storage.value() << 6;
+
+        let privilege = match value.access.privilege_limit {
+            Some(level) => storage | u8::from(level),
+            None => 0x00,
+        };
+
+        Message::new_request(
+            NetFn::App,
+            0x40,
+            vec![
+                u8::from(value.channel),
+                storage | value.access.access_byte(),
+                privilege,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for SetChannelAccess {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    app::auth::Channel,
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+};
+
+/// The medium of a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChannelMedium {
+    Ipmb,
+    IcmbV10,
+    IcmbV09,
+    /// An 802.3 LAN.
+    Lan802_3,
+    /// An asynchronous serial or modem connection.
+    Serial,
+    OtherLan,
+    PciSmbus,
+    SmbusV1,
+    SmbusV2,
+    UsbV1,
+    UsbV2,
+    /// The system interface, such as KCS, SMIC, BT or SSIF.
+    SystemInterface,
+    Oem(u8),
+    Reserved(u8),
+}
+
+impl From<u8> for ChannelMedium {
+    fn from(value: u8) -> Self {
+        match value & 0x7F {
+            0x01 => Self::Ipmb,
+            0x02 => Self::IcmbV10,
+            0x03 => Self::IcmbV09,
+            0x04 => Self::Lan802_3,
+            0x05 => Self::Serial,
+            0x06 => Self::OtherLan,
+            0x07 => Self::PciSmbus,
+            0x08 => Self::SmbusV1,
+            0x09 => Self::SmbusV2,
+            0x0A => Self::UsbV1,
+            0x0B => Self::UsbV2,
+            0x0C => Self::SystemInterface,
+            v @ 0x60..=0x7F => Self::Oem(v),
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl ChannelMedium {
+    /// Whether this medium is a LAN.
+    pub fn is_lan(&self) -> bool {
+        matches!(self, Self::Lan802_3 | Self::OtherLan)
+    }
+}
+
+/// The messaging protocol of a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChannelProtocol {
+    NotApplicable,
+    IpmbV10,
+    IcmbV10,
+    IpmiSmbus,
+    Kcs,
+    Smic,
+    BtV10,
+    BtV15,
+    /// Terminal mode.
+    TMode,
+    Oem(u8),
+    Reserved(u8),
+}
+
+impl From<u8> for ChannelProtocol {
+    fn from(value: u8) -> Self {
+        match value & 0x1F {
+            0x00 => Self::NotApplicable,
+            0x01 => Self::IpmbV10,
+            0x02 => Self::IcmbV10,
+            0x04 => Self::IpmiSmbus,
+            0x05 => Self::Kcs,
+            0x06 => Self::Smic,
+            0x07 => Self::BtV10,
+            0x08 => Self::BtV15,
+            0x09 => Self::TMode,
+            v @ 0x1C..=0x1F => Self::Oem(v),
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+/// The session support of a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SessionSupport {
+    SessionLess,
+    SingleSession,
+    MultiSession,
+    /// The channel supports either single or multiple sessions.
+    SessionBased,
+}
+
+impl From<u8> for SessionSupport {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0b00 => Self::SessionLess,
+            0b01 => Self::SingleSession,
+            0b10 => Self::MultiSession,
+            _ => Self::SessionBased,
+        }
+    }
+}
+
+/// Information about a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelInfo {
+    /// The number of the channel. This is the actual number of the
+    /// channel if the current channel was requested.
+    pub number: u8,
+    pub medium: ChannelMedium,
+    pub protocol: ChannelProtocol,
+    pub session_support: SessionSupport,
+    /// The number of active sessions on the channel.
+    pub active_sessions: u8,
+    /// The IANA enterprise number of the vendor that defined the protocol.
+    pub vendor_id: u32,
+    /// Auxiliary information, which depends on the channel.
+    pub auxiliary: [u8; 2],
+}
+
+/// Get information about a channel.
+pub struct GetChannelInfo {
+    channel: Channel,
+}
+
+impl GetChannelInfo {
+    pub fn new(channel: Channel) -> Self {
+        Self { channel }
+    }
+}
+
+impl From<GetChannelInfo> for Message {
+    fn from(value: GetChannelInfo) -> Self {
+        Message::new_request(NetFn::App, 0x42, vec![u8::from(value.channel)])
+    }
+}
+
+impl IpmiCommand for GetChannelInfo {
+    type Output = ChannelInfo;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 9 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(ChannelInfo {
+            number: data[0] & 0xF,
+            medium: ChannelMedium::from(data[1]),
+            protocol: ChannelProtocol::from(data[2]),
+            session_support: SessionSupport::from(data[3] >> 6),
+            active_sessions: data[3] & 0x3F,
+            vendor_id: u32::from_le_bytes([data[4], data[5], data[6], 0]),
+            auxiliary: [data[7], data[8]],
+        })
+    }
+}
@@ -0,0 +1,9 @@
+//! Channel discovery and access configuration.
+
+mod info;
+pub use info::{ChannelInfo, ChannelMedium, ChannelProtocol, GetChannelInfo, SessionSupport};
+
+mod access;
+pub use access::{
+    AccessMode, ChannelAccess, ChannelAccessStorage, GetChannelAccess, SetChannelAccess,
+};
@@ -0,0 +1,68 @@
+use std::fmt;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// A globally unique identifier, in the byte order used by IPMI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guid(pub [u8; 16]);
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_guid(data: &[u8]) -> Result<Guid, ParseResponseError<()>> {
+    data.get(..16)
+        .map(|v| Guid(v.try_into().unwrap()))
+        .ok_or(ParseResponseError::NotEnoughData)
+}
+
+/// Get the GUID of the management controller.
+pub struct GetDeviceGuid;
+
+impl From<GetDeviceGuid> for Message {
+    fn from(_: GetDeviceGuid) -> Self {
+        Message::new_request(NetFn::App, 0x08, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetDeviceGuid {
+    type Output = Guid;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        parse_guid(data)
+    }
+}
+
+/// Get the GUID of the system that the BMC manages.
+pub struct GetSystemGuid;
+
+impl From<GetSystemGuid> for Message {
+    fn from(_: GetSystemGuid) -> Self {
+        Message::new_request(NetFn::App, 0x37, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetSystemGuid {
+    type Output = Guid;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        parse_guid(data)
+    }
+}
@@ -1,9 +1,31 @@
 mod get_device_id;
 pub use get_device_id::{DeviceId, GetDeviceId};
 
+mod guid;
+pub use guid::{GetDeviceGuid, GetSystemGuid, Guid};
+
 mod get_channel_authentication_capabilities;
 pub use get_channel_authentication_capabilities::{
     Channel, ChannelAuthenticationCapabilities, GetChannelAuthenticationCapabilities,
 };
 
+mod reset;
+pub use reset::{ColdReset, WarmReset};
+
+mod self_test;
+pub use self_test::{GetSelfTestResults, SelfTestFailures, SelfTestResult};
+
+mod acpi;
+pub use acpi::{
+    AcpiPowerState, DevicePowerState, GetAcpiPowerState, SetAcpiPowerState, SystemPowerState,
+};
+
 pub mod auth;
+
+pub mod user;
+
+pub mod channel;
+
+pub mod watchdog;
+
+pub mod payload;
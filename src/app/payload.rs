@@ -0,0 +1,210 @@
+//! Activation of payloads, such as Serial over LAN, on an RMCP+ session.
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// The payload type of Serial over LAN.
+pub const SOL_PAYLOAD_TYPE: u8 = 0x01;
+
+/// How the serial port behaves while SOL is activated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolActivation {
+    /// Encrypt the SOL payload.
+    pub encryption: bool,
+    /// Authenticate the SOL payload.
+    pub authentication: bool,
+    /// Activate SOL in test mode, if supported.
+    pub test_mode: bool,
+    /// Deassert CTS, DCD and DSR until the remote console sends its first
+    /// SOL packet, instead of asserting them immediately.
+    pub startup_handshake: bool,
+}
+
+/// SOL payloads are encrypted and authenticated by default, as
+/// required to exchange them over an RMCP+ session.
+impl Default for SolActivation {
+    fn default() -> Self {
+        Self {
+            encryption: true,
+            authentication: true,
+            test_mode: false,
+            startup_handshake: false,
+        }
+    }
+}
+
+impl SolActivation {
+    fn auxiliary_data(&self) -> [u8; 4] {
+        let value = (self.encryption as u8) << 7
+            | (self.authentication as u8) << 6
+            | (self.test_mode as u8) << 5
+            | (self.startup_handshake as u8) << 1;
+
+        [value, 0x00, 0x00, 0x00]
+    }
+}
+
+/// An activated payload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActivatedPayload {
+    /// The maximum size of the payloads that can be sent to the BMC.
+    pub inbound_payload_size: u16,
+    /// The maximum size of the payloads that the BMC sends.
+    pub outbound_payload_size: u16,
+    /// The UDP port over which the payload is transferred.
+    pub port: u16,
+    /// The VLAN over which the payload is transferred, if any.
+    pub vlan: Option<u16>,
+}
+
+/// Errors specific to [`ActivatePayload`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PayloadActivationError {
+    /// The payload is already active on another session.
+    AlreadyActive,
+    /// The payload type is disabled.
+    Disabled,
+    /// The maximum number of instances of the payload is active.
+    LimitReached,
+    /// The payload cannot be activated with encryption.
+    EncryptionUnavailable,
+    /// The payload cannot be activated without encryption.
+    EncryptionRequired,
+}
+
+/// Activate a payload on the current session.
+pub struct ActivatePayload {
+    payload_type: u8,
+    instance: u8,
+    auxiliary_data: [u8; 4],
+}
+
+impl ActivatePayload {
+    pub fn new(payload_type: u8, instance: u8, auxiliary_data: [u8; 4]) -> Self {
+        Self {
+            payload_type,
+            instance,
+            auxiliary_data,
+        }
+    }
+
+    /// Activate SOL payload instance `instance`.
+    pub fn sol(instance: u8, activation: SolActivation) -> Self {
+        Self::new(SOL_PAYLOAD_TYPE, instance, activation.auxiliary_data())
+    }
+}
+
+impl From<ActivatePayload> for Message {
+    fn from(value: ActivatePayload) -> Self {
+        let mut data = vec![value.payload_type & 0x3F, value.instance & 0xF];
+        data.extend_from_slice(&value.auxiliary_data);
+
+        Message::new_request(NetFn::App, 0x48, data)
+    }
+}
+
+impl IpmiCommand for ActivatePayload {
+    type Output = ActivatedPayload;
+
+    type Error = PayloadActivationError;
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        use PayloadActivationError::*;
+
+        let error = match completion_code {
+            CompletionCode::CommandSpecific(0x80) => Some(AlreadyActive),
+            CompletionCode::CommandSpecific(0x81) => Some(Disabled),
+            CompletionCode::CommandSpecific(0x82) => Some(LimitReached),
+            CompletionCode::CommandSpecific(0x83) => Some(EncryptionUnavailable),
+            CompletionCode::CommandSpecific(0x84) => Some(EncryptionRequired),
+            _ => None,
+        };
+
+        if let Some(error) = error {
+            return Err(error.into());
+        }
+
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 12 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let u16 = |idx: usize| u16::from_le_bytes([data[idx], data[idx + 1]]);
+        let vlan = u16(10);
+
+        Ok(ActivatedPayload {
+            inbound_payload_size: u16(4),
+            outbound_payload_size: u16(6),
+            port: u16(8),
+            vlan: (vlan != 0xFFFF).then_some(vlan),
+        })
+    }
+}
+
+/// Errors specific to [`DeactivatePayload`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PayloadDeactivationError {
+    /// The payload is not active.
+    AlreadyDeactivated,
+    /// The payload type is disabled.
+    Disabled,
+}
+
+/// Deactivate a payload.
+pub struct DeactivatePayload {
+    payload_type: u8,
+    instance: u8,
+}
+
+impl DeactivatePayload {
+    pub fn new(payload_type: u8, instance: u8) -> Self {
+        Self {
+            payload_type,
+            instance,
+        }
+    }
+
+    /// Deactivate SOL payload instance `instance`.
+    pub fn sol(instance: u8) -> Self {
+        Self::new(SOL_PAYLOAD_TYPE, instance)
+    }
+}
+
+impl From<DeactivatePayload> for Message {
+    fn from(value: DeactivatePayload) -> Self {
+        Message::new_request(
+            NetFn::App,
+            0x49,
+            vec![
+                value.payload_type & 0x3F,
+                value.instance & 0xF,
+                0x00,
+                0x00,
+                0x00,
+                0x00,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for DeactivatePayload {
+    type Output = ();
+
+    type Error = PayloadDeactivationError;
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        match completion_code {
+            CompletionCode::CommandSpecific(0x80) => {
+                Err(PayloadDeactivationError::AlreadyDeactivated.into())
+            }
+            CompletionCode::CommandSpecific(0x81) => Err(PayloadDeactivationError::Disabled.into()),
+            cc => Self::check_cc_success(cc),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// Reset the BMC and its firmware, as if it were powered up.
+///
+/// The reset closes all sessions and clears the volatile state of
+/// the BMC, such as the watchdog timer and SEL reservations, so the
+/// connection must be re-established after the BMC has come back up.
+/// Some BMCs reset before responding, in which case sending the
+/// command fails with a connection error even though the reset was
+/// performed.
+pub struct ColdReset;
+
+impl From<ColdReset> for Message {
+    fn from(_: ColdReset) -> Self {
+        Message::new_request(NetFn::App, 0x02, Vec::new())
+    }
+}
+
+impl IpmiCommand for ColdReset {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
+
+/// Reset the communication interfaces and state of the BMC, without
+/// resetting its sensors or restarting its firmware.
+///
+/// Like [`ColdReset`], this closes all sessions, so the connection
+/// must be re-established afterwards.
+pub struct WarmReset;
+
+impl From<WarmReset> for Message {
+    fn from(_: WarmReset) -> Self {
+        Message::new_request(NetFn::App, 0x03, Vec::new())
+    }
+}
+
+impl IpmiCommand for WarmReset {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+bitflags::bitflags! {
+    /// The devices and data that failed the self test of the BMC.
+    #[derive(Default)]
+    pub struct SelfTestFailures: u8 {
+        const SEL_INACCESSIBLE = 1 << 7;
+        const SDR_REPOSITORY_INACCESSIBLE = 1 << 6;
+        const FRU_INACCESSIBLE = 1 << 5;
+        /// The IPMB signal lines do not respond.
+        const IPMB_UNRESPONSIVE = 1 << 4;
+        const SDR_REPOSITORY_EMPTY = 1 << 3;
+        /// The internal use area of the FRU device of the BMC is corrupted.
+        const FRU_CORRUPTED = 1 << 2;
+        /// The boot block firmware of the BMC is corrupted.
+        const BOOT_BLOCK_CORRUPTED = 1 << 1;
+        /// The operational firmware of the BMC is corrupted.
+        const FIRMWARE_CORRUPTED = 1 << 0;
+    }
+}
+
+/// The result of the self test of the BMC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelfTestResult {
+    Passed,
+    /// The BMC does not implement a self test.
+    NotImplemented,
+    /// Devices or data are inaccessible or corrupted.
+    Failed(SelfTestFailures),
+    /// A fatal hardware error, with a device-specific code.
+    FatalHardwareError(u8),
+    /// A device-specific failure.
+    DeviceSpecific {
+        code: u8,
+        data: u8,
+    },
+}
+
+impl SelfTestResult {
+    /// Whether the self test passed.
+    ///
+    /// Returns `false` if the BMC does not implement a self test.
+    pub fn passed(&self) -> bool {
+        self == &Self::Passed
+    }
+}
+
+/// Get the results of the self test that the BMC runs on power up.
+pub struct GetSelfTestResults;
+
+impl From<GetSelfTestResults> for Message {
+    fn from(_: GetSelfTestResults) -> Self {
+        Message::new_request(NetFn::App, 0x04, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetSelfTestResults {
+    type Output = SelfTestResult;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let result = match data[0] {
+            0x55 => SelfTestResult::Passed,
+            0x56 => SelfTestResult::NotImplemented,
+            0x57 => SelfTestResult::Failed(SelfTestFailures::from_bits_truncate(data[1])),
+            0x58 => SelfTestResult::FatalHardwareError(data[1]),
+            code => SelfTestResult::DeviceSpecific {
+                code,
+                data: data[1],
+            },
+        };
+
+        Ok(result)
+    }
+}
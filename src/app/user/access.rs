@@ -0,0 +1,181 @@
+use crate::{
+    app::auth::{Channel, PrivilegeLevel},
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+};
+
+/// The access of a user on a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UserAccess {
+    /// Restrict the user to callback connections.
+    pub callback_only: bool,
+    /// Enable link authentication for the user.
+    pub link_authentication: bool,
+    /// Enable IPMI messaging for the user.
+    pub ipmi_messaging: bool,
+    /// The maximum privilege level of the user, or `None` if the user
+    /// has no access to the channel.
+    pub privilege_limit: Option<PrivilegeLevel>,
+}
+
+impl UserAccess {
+    fn parse(value: u8) -> Self {
+        Self {
+            callback_only: (value & 0x40) == 0x40,
+            link_authentication: (value & 0x20) == 0x20,
+            ipmi_messaging: (value & 0x10) == 0x10,
+            privilege_limit: PrivilegeLevel::try_from(value & 0xF).ok(),
+        }
+    }
+
+    fn flags(&self) -> u8 {
+        (self.callback_only as u8) << 6
+            | (self.link_authentication as u8) << 5
+            | (self.ipmi_messaging as u8) << 4
+    }
+
+    fn privilege_limit(&self) -> u8 {
+        self.privilege_limit.map(u8::from).unwrap_or(0xF)
+    }
+}
+
+/// Whether a user is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserEnableStatus {
+    Unspecified,
+    /// The user was enabled using Set User Password.
+    Enabled,
+    /// The user was disabled using Set User Password.
+    Disabled,
+    Reserved,
+}
+
+impl From<u8> for UserEnableStatus {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0b00 => Self::Unspecified,
+            0b01 => Self::Enabled,
+            0b10 => Self::Disabled,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+/// The access of a user on a channel, and the user
+/// limits of the channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UserAccessInfo {
+    /// The maximum number of user IDs.
+    pub max_user_ids: u8,
+    pub enable_status: UserEnableStatus,
+    /// The number of enabled user IDs.
+    pub enabled_user_ids: u8,
+    /// The number of user IDs with fixed names, starting at user ID 1.
+    pub fixed_name_user_ids: u8,
+    pub access: UserAccess,
+}
+
+/// Get the access of a user on a channel.
+pub struct GetUserAccess {
+    channel: Channel,
+    user_id: u8,
+}
+
+impl GetUserAccess {
+    pub fn new(channel: Channel, user_id: u8) -> Self {
+        Self { channel, user_id }
+    }
+}
+
+impl From<GetUserAccess> for Message {
+    fn from(value: GetUserAccess) -> Self {
+        Message::new_request(
+            NetFn::App,
+            0x44,
+            vec![u8::from(value.channel), value.user_id & 0x3F],
+        )
+    }
+}
+
+impl IpmiCommand for GetUserAccess {
+    type Output = UserAccessInfo;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 4 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(UserAccessInfo {
+            max_user_ids: data[0] & 0x3F,
+            enable_status: UserEnableStatus::from(data[1] >> 6),
+            enabled_user_ids: data[1] & 0x3F,
+            fixed_name_user_ids: data[2] & 0x3F,
+            access: UserAccess::parse(data[3]),
+        })
+    }
+}
+
+/// Set the access of a user on a channel.
+pub struct SetUserAccess {
+    channel: Channel,
+    user_id: u8,
+    access: UserAccess,
+    session_limit: Option<u8>,
+}
+
+impl SetUserAccess {
+    /// Set the access of user `user_id` on `channel` to `access`.
+    ///
+    /// If `session_limit` is set, it limits the amount of simultaneous
+    /// sessions of the user on the channel.
+    pub fn new(
+        channel: Channel,
+        user_id: u8,
+        access: UserAccess,
+        session_limit: Option<u8>,
+    ) -> Self {
+        Self {
+            channel,
+            user_id,
+            access,
+            session_limit,
+        }
+    }
+}
+
+impl From<SetUserAccess> for Message {
+    fn from(value: SetUserAccess) -> Self {
+        // Bit 7 enables changing the flags in the first byte.
+        let mut data = vec![
+            0x80 | value.access.flags() | u8::from(value.channel),
+            value.user_id & 0x3F,
+            value.access.privilege_limit(),
+        ];
+
+        if let Some(limit) = value.session_limit {
+            data.push(limit & 0xF);
+        }
+
+        Message::new_request(NetFn::App, 0x43, data)
+    }
+}
+
+impl IpmiCommand for SetUserAccess {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    app::auth::{Channel, PrivilegeLevel},
+    connection::{IpmiConnection, ParseResponseError},
+    Ipmi, IpmiCommandError, IpmiError,
+};
+
+use super::{
+    GetUserAccess, GetUserName, Password, PasswordError, PasswordOperation, SetUserAccess,
+    SetUserName, SetUserPassword, UserAccess, UserEnableStatus,
+};
+
+#[derive(Debug)]
+pub enum UserAccountError<CON> {
+    /// The name of the user is longer than 16 bytes.
+    NameTooLong,
+    Command(IpmiCommandError<CON, ()>),
+    Password(IpmiCommandError<CON, PasswordError>),
+}
+
+impl<CON> From<IpmiCommandError<CON, ()>> for UserAccountError<CON> {
+    fn from(value: IpmiCommandError<CON, ()>) -> Self {
+        Self::Command(value)
+    }
+}
+
+impl<CON> From<IpmiCommandError<CON, PasswordError>> for UserAccountError<CON> {
+    fn from(value: IpmiCommandError<CON, PasswordError>) -> Self {
+        Self::Password(value)
+    }
+}
+
+/// The changes that [`UserAccount::ensure`] made.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UserAccountChanges {
+    pub name: bool,
+    pub password: bool,
+    pub enabled: bool,
+    /// The channels on which the access of the user was changed.
+    pub channels: Vec<Channel>,
+}
+
+impl UserAccountChanges {
+    /// Whether any changes were made.
+    pub fn any(&self) -> bool {
+        self.name || self.password || self.enabled || !self.channels.is_empty()
+    }
+}
+
+/// The desired state of a user account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserAccount {
+    pub user_id: u8,
+    pub name: String,
+    pub password: Password,
+    /// The privilege limit of the user on each of `channels`.
+    pub privilege: PrivilegeLevel,
+    /// The channels on which the user is given access with IPMI messaging.
+    pub channels: Vec<Channel>,
+}
+
+impl UserAccount {
+    /// The access of the user on each of its channels.
+    pub fn access(&self) -> UserAccess {
+        UserAccess {
+            callback_only: false,
+            link_authentication: false,
+            ipmi_messaging: true,
+            privilege_limit: Some(self.privilege),
+        }
+    }
+
+    /// Ensure that the user exists with this name, password and access,
+    /// and enable it if it is disabled.
+    ///
+    /// The current state of the user is read first, and only the
+    /// settings that differ are changed. Access on channels that are
+    /// not in `channels` is left unchanged. BMCs that do not report
+    /// whether the user is enabled are not asked to enable it.
+    pub fn ensure<CON>(
+        &self,
+        ipmi: &mut Ipmi<CON>,
+    ) -> Result<UserAccountChanges, UserAccountError<CON::Error>>
+    where
+        CON: IpmiConnection,
+    {
+        let mut changes = UserAccountChanges::default();
+
+        let set_name =
+            SetUserName::new(self.user_id, &self.name).ok_or(UserAccountError::NameTooLong)?;
+
+        if ipmi.send_recv(GetUserName::new(self.user_id))? != self.name {
+            ipmi.send_recv(set_name)?;
+            changes.name = true;
+        }
+
+        let test = SetUserPassword::new(self.user_id, PasswordOperation::Test(self.password));
+
+        match ipmi.send_recv(test) {
+            Ok(()) => {}
+            Err(IpmiError::ParsingFailed {
+                error: ParseResponseError::Parse(_),
+                ..
+            }) => {
+                let set = PasswordOperation::Set(self.password);
+                ipmi.send_recv(SetUserPassword::new(self.user_id, set))?;
+                changes.password = true;
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let access = self.access();
+        let mut enable_status = None;
+
+        for channel in &self.channels {
+            let info = ipmi.send_recv(GetUserAccess::new(*channel, self.user_id))?;
+            enable_status = Some(info.enable_status);
+
+            if info.access != access {
+                ipmi.send_recv(SetUserAccess::new(*channel, self.user_id, access, None))?;
+                changes.channels.push(*channel);
+            }
+        }
+
+        let enable_status = match enable_status {
+            Some(v) => v,
+            None => {
+                let info = ipmi.send_recv(GetUserAccess::new(Channel::Current, self.user_id))?;
+                info.enable_status
+            }
+        };
+
+        if enable_status == UserEnableStatus::Disabled {
+            let enable = SetUserPassword::new(self.user_id, PasswordOperation::Enable);
+            ipmi.send_recv(enable)?;
+            changes.enabled = true;
+        }
+
+        Ok(changes)
+    }
+}
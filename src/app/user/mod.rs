@@ -0,0 +1,16 @@
+//! User account management.
+
+mod access;
+pub use access::{GetUserAccess, SetUserAccess, UserAccess, UserAccessInfo, UserEnableStatus};
+
+mod name;
+pub use name::{GetUserName, SetUserName};
+
+mod password;
+pub use password::{Password, PasswordError, PasswordOperation, SetUserPassword};
+
+mod payload;
+pub use payload::{PayloadAccess, PayloadAccessChange, SetUserPayloadAccess};
+
+mod account;
+pub use account::{UserAccount, UserAccountChanges, UserAccountError};
@@ -0,0 +1,90 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// The length of a user name.
+const NAME_LEN: usize = 16;
+
+/// Get the name of a user.
+pub struct GetUserName {
+    user_id: u8,
+}
+
+impl GetUserName {
+    pub fn new(user_id: u8) -> Self {
+        Self { user_id }
+    }
+}
+
+impl From<GetUserName> for Message {
+    fn from(value: GetUserName) -> Self {
+        Message::new_request(NetFn::App, 0x46, vec![value.user_id & 0x3F])
+    }
+}
+
+impl IpmiCommand for GetUserName {
+    type Output = String;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < NAME_LEN {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let name = &data[..NAME_LEN];
+        let len = name.iter().position(|v| *v == 0).unwrap_or(NAME_LEN);
+
+        Ok(String::from_utf8_lossy(&name[..len]).into_owned())
+    }
+}
+
+/// Set the name of a user.
+pub struct SetUserName {
+    user_id: u8,
+    name: [u8; NAME_LEN],
+}
+
+impl SetUserName {
+    /// Set the name of user `user_id` to `name`.
+    ///
+    /// Returns `None` if `name` is longer than 16 bytes.
+    pub fn new(user_id: u8, name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+
+        if bytes.len() > NAME_LEN {
+            return None;
+        }
+
+        let mut name = [0u8; NAME_LEN];
+        name[..bytes.len()].copy_from_slice(bytes);
+
+        Some(Self { user_id, name })
+    }
+}
+
+impl From<SetUserName> for Message {
+    fn from(value: SetUserName) -> Self {
+        let mut data = vec![value.user_id & 0x3F];
+        data.extend_from_slice(&value.name);
+
+        Message::new_request(NetFn::App, 0x45, data)
+    }
+}
+
+impl IpmiCommand for SetUserName {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
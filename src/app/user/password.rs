@@ -0,0 +1,133 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// A user password.
+///
+/// Passwords are stored as either 16 or 20 bytes, padded with null bytes.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Password {
+    Short([u8; 16]),
+    Long([u8; 20]),
+}
+
+impl core::fmt::Debug for Password {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Short(_) => write!(f, "Short(..)"),
+            Self::Long(_) => write!(f, "Long(..)"),
+        }
+    }
+}
+
+impl Password {
+    /// Create a password from `password`. Passwords of up to 16 bytes
+    /// are stored as 16-byte passwords.
+    ///
+    /// Returns `None` if `password` is longer than 20 bytes.
+    pub fn new(password: &[u8]) -> Option<Self> {
+        if password.len() <= 16 {
+            let mut data = [0u8; 16];
+            data[..password.len()].copy_from_slice(password);
+            Some(Self::Short(data))
+        } else {
+            Self::long(password)
+        }
+    }
+
+    /// Create a 20-byte password from `password`.
+    ///
+    /// Returns `None` if `password` is longer than 20 bytes.
+    pub fn long(password: &[u8]) -> Option<Self> {
+        if password.len() > 20 {
+            return None;
+        }
+
+        let mut data = [0u8; 20];
+        data[..password.len()].copy_from_slice(password);
+        Some(Self::Long(data))
+    }
+
+    fn is_long(&self) -> bool {
+        matches!(self, Self::Long(_))
+    }
+
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Self::Short(v) => v,
+            Self::Long(v) => v,
+        }
+    }
+}
+
+/// The operation performed by [`SetUserPassword`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PasswordOperation {
+    /// Disable the user.
+    Disable,
+    /// Enable the user.
+    Enable,
+    /// Set the password of the user.
+    Set(Password),
+    /// Test whether the password of the user is equal to the given
+    /// password.
+    Test(Password),
+}
+
+/// Errors specific to [`SetUserPassword`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PasswordError {
+    /// The tested password does not match the password of the user.
+    TestFailed,
+    /// The size of the tested password does not match the size of
+    /// the password of the user.
+    WrongSize,
+}
+
+/// Set or test the password of a user, or enable or disable the user.
+pub struct SetUserPassword {
+    user_id: u8,
+    operation: PasswordOperation,
+}
+
+impl SetUserPassword {
+    pub fn new(user_id: u8, operation: PasswordOperation) -> Self {
+        Self { user_id, operation }
+    }
+}
+
+impl From<SetUserPassword> for Message {
+    fn from(value: SetUserPassword) -> Self {
+        let (operation, password) = match value.operation {
+            PasswordOperation::Disable => (0b00, None),
+            PasswordOperation::Enable => (0b01, None),
+            PasswordOperation::Set(password) => (0b10, Some(password)),
+            PasswordOperation::Test(password) => (0b11, Some(password)),
+        };
+
+        let long = password.map(|p| p.is_long()).unwrap_or(false);
+
+        let mut data = vec![(long as u8) << 7 | (value.user_id & 0x3F), operation];
+
+        if let Some(password) = password {
+            data.extend_from_slice(password.bytes());
+        }
+
+        Message::new_request(NetFn::App, 0x47, data)
+    }
+}
+
+impl IpmiCommand for SetUserPassword {
+    type Output = ();
+
+    type Error = PasswordError;
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        match completion_code {
+            CompletionCode::CommandSpecific(0x80) => Err(PasswordError::TestFailed.into()),
+            CompletionCode::CommandSpecific(0x81) => Err(PasswordError::WrongSize.into()),
+            cc => Self::check_cc_success(cc),
+        }
+    }
+}
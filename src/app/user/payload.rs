@@ -0,0 +1,95 @@
+use crate::{
+    app::auth::Channel,
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+};
+
+/// A set of payload types.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PayloadAccess {
+    /// The standard payload types. Bit N corresponds to payload type N,
+    /// for payload types 1 through 7.
+    pub standard: u8,
+    /// The OEM payload types. Bit N corresponds to OEM payload N, which
+    /// is payload type `0x20 + N`.
+    pub oem: u8,
+}
+
+impl PayloadAccess {
+    /// Only the Serial Over LAN payload.
+    pub fn sol() -> Self {
+        Self {
+            standard: 1 << 1,
+            oem: 0,
+        }
+    }
+}
+
+/// The change made by [`SetUserPayloadAccess`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PayloadAccessChange {
+    Enable,
+    Disable,
+}
+
+/// Enable or disable the access of a user to payload types on
+/// a channel.
+pub struct SetUserPayloadAccess {
+    channel: Channel,
+    user_id: u8,
+    change: PayloadAccessChange,
+    payloads: PayloadAccess,
+}
+
+impl SetUserPayloadAccess {
+    /// Apply `change` to the access of user `user_id` to `payloads`. The access
+    /// to payloads that are not selected is not changed.
+    pub fn new(
+        channel: Channel,
+        user_id: u8,
+        change: PayloadAccessChange,
+        payloads: PayloadAccess,
+    ) -> Self {
+        Self {
+            channel,
+            user_id,
+            change,
+            payloads,
+        }
+    }
+}
+
+impl From<SetUserPayloadAccess> for Message {
+    fn from(value: SetUserPayloadAccess) -> Self {
+        let change = match value.change {
+            PayloadAccessChange::Enable => 0x00,
+            PayloadAccessChange::Disable => 0x40,
+        };
+
+        Message::new_request(
+            NetFn::App,
+            0x4C,
+            vec![
+                u8::from(value.channel),
+                change | (value.user_id & 0x3F),
+                value.payloads.standard & 0xFE,
+                0x00,
+                value.payloads.oem,
+                0x00,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for SetUserPayloadAccess {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
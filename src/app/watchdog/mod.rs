@@ -0,0 +1,312 @@
+//! The BMC watchdog timer.
+
+mod petter;
+pub use petter::WatchdogPetter;
+
+use std::time::Duration;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// The use of the watchdog timer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerUse {
+    BiosFrb2,
+    BiosPost,
+    OsLoad,
+    /// The timer is used by system management software or the OS.
+    SmsOs,
+    Oem,
+    Reserved(u8),
+}
+
+impl From<u8> for TimerUse {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            1 => Self::BiosFrb2,
+            2 => Self::BiosPost,
+            3 => Self::OsLoad,
+            4 => Self::SmsOs,
+            5 => Self::Oem,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<TimerUse> for u8 {
+    fn from(value: TimerUse) -> Self {
+        match value {
+            TimerUse::BiosFrb2 => 1,
+            TimerUse::BiosPost => 2,
+            TimerUse::OsLoad => 3,
+            TimerUse::SmsOs => 4,
+            TimerUse::Oem => 5,
+            TimerUse::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+/// The interrupt that is generated when the pre-timeout interval
+/// of the watchdog timer expires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreTimeoutInterrupt {
+    None,
+    Smi,
+    /// An NMI or diagnostic interrupt.
+    Nmi,
+    MessagingInterrupt,
+    Reserved(u8),
+}
+
+impl From<u8> for PreTimeoutInterrupt {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0 => Self::None,
+            1 => Self::Smi,
+            2 => Self::Nmi,
+            3 => Self::MessagingInterrupt,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<PreTimeoutInterrupt> for u8 {
+    fn from(value: PreTimeoutInterrupt) -> Self {
+        match value {
+            PreTimeoutInterrupt::None => 0,
+            PreTimeoutInterrupt::Smi => 1,
+            PreTimeoutInterrupt::Nmi => 2,
+            PreTimeoutInterrupt::MessagingInterrupt => 3,
+            PreTimeoutInterrupt::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+/// The action that is performed when the watchdog timer expires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeoutAction {
+    NoAction,
+    HardReset,
+    PowerDown,
+    PowerCycle,
+    Reserved(u8),
+}
+
+impl From<u8> for TimeoutAction {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0 => Self::NoAction,
+            1 => Self::HardReset,
+            2 => Self::PowerDown,
+            3 => Self::PowerCycle,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<TimeoutAction> for u8 {
+    fn from(value: TimeoutAction) -> Self {
+        match value {
+            TimeoutAction::NoAction => 0,
+            TimeoutAction::HardReset => 1,
+            TimeoutAction::PowerDown => 2,
+            TimeoutAction::PowerCycle => 3,
+            TimeoutAction::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// The timer uses for which the watchdog timer expired.
+    #[derive(Default)]
+    pub struct ExpirationFlags: u8 {
+        const OEM = 1 << 5;
+        const SMS_OS = 1 << 4;
+        const OS_LOAD = 1 << 3;
+        const BIOS_POST = 1 << 2;
+        const BIOS_FRB2 = 1 << 1;
+    }
+}
+
+/// The configuration of the watchdog timer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WatchdogTimer {
+    pub timer_use: TimerUse,
+    /// Do not log an event in the SEL when the timer expires.
+    pub dont_log: bool,
+    pub pre_timeout_interrupt: PreTimeoutInterrupt,
+    pub timeout_action: TimeoutAction,
+    /// The time before the timeout at which the pre-timeout interrupt
+    /// is generated, in seconds.
+    pub pre_timeout_interval: u8,
+    /// The value that the timer counts down from, in units of 100 ms.
+    pub initial_countdown: u16,
+}
+
+impl WatchdogTimer {
+    /// The duration of a single count of the watchdog timer.
+    pub const TICK: Duration = Duration::from_millis(100);
+
+    /// Create a timer for `timer_use` that performs `timeout_action` after
+    /// `timeout`, which is rounded down to a multiple of 100 ms and limited
+    /// to the maximum countdown.
+    pub fn new(timer_use: TimerUse, timeout_action: TimeoutAction, timeout: Duration) -> Self {
+        Self {
+            timer_use,
+            dont_log: false,
+            pre_timeout_interrupt: PreTimeoutInterrupt::None,
+            timeout_action,
+            pre_timeout_interval: 0,
+            initial_countdown: Self::countdown(timeout),
+        }
+    }
+
+    /// The countdown value for `timeout`.
+    pub fn countdown(timeout: Duration) -> u16 {
+        let ticks = timeout.as_millis() / Self::TICK.as_millis();
+        ticks.min(u16::MAX as u128) as u16
+    }
+
+    /// The timeout of the timer.
+    pub fn timeout(&self) -> Duration {
+        Self::TICK * self.initial_countdown as u32
+    }
+
+    fn actions(&self) -> u8 {
+        u8::from(self.pre_timeout_interrupt) << 4 | u8::from(self.timeout_action)
+    }
+}
+
+/// The configuration and state of the watchdog timer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WatchdogTimerStatus {
+    pub timer: WatchdogTimer,
+    pub running: bool,
+    pub expiration_flags: ExpirationFlags,
+    /// The present value of the countdown, in units of 100 ms.
+    pub present_countdown: u16,
+}
+
+/// Get the configuration and state of the watchdog timer.
+pub struct GetWatchdogTimer;
+
+impl From<GetWatchdogTimer> for Message {
+    fn from(_: GetWatchdogTimer) -> Self {
+        Message::new_request(NetFn::App, 0x25, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetWatchdogTimer {
+    type Output = WatchdogTimerStatus;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 8 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let timer = WatchdogTimer {
+            timer_use: TimerUse::from(data[0]),
+            dont_log: (data[0] & 0x80) == 0x80,
+            pre_timeout_interrupt: PreTimeoutInterrupt::from(data[1] >> 4),
+            timeout_action: TimeoutAction::from(data[1]),
+            pre_timeout_interval: data[2],
+            initial_countdown: u16::from_le_bytes([data[4], data[5]]),
+        };
+
+        Ok(WatchdogTimerStatus {
+            timer,
+            running: (data[0] & 0x40) == 0x40,
+            expiration_flags: ExpirationFlags::from_bits_truncate(data[3]),
+            present_countdown: u16::from_le_bytes([data[6], data[7]]),
+        })
+    }
+}
+
+/// Configure the watchdog timer.
+///
+/// The timer is started by [`ResetWatchdogTimer`].
+pub struct SetWatchdogTimer {
+    timer: WatchdogTimer,
+    keep_running: bool,
+    clear_flags: ExpirationFlags,
+}
+
+impl SetWatchdogTimer {
+    /// Set the configuration of the watchdog timer to `timer`, and
+    /// clear the expiration flags in `clear_flags`.
+    ///
+    /// If `keep_running` is `false`, the timer is stopped. Otherwise, a running
+    /// timer keeps running and restarts with the new countdown value.
+    pub fn new(timer: WatchdogTimer, keep_running: bool, clear_flags: ExpirationFlags) -> Self {
+        Self {
+            timer,
+            keep_running,
+            clear_flags,
+        }
+    }
+}
+
+impl From<SetWatchdogTimer> for Message {
+    fn from(value: SetWatchdogTimer) -> Self {
+        let timer = value.timer;
+
+        let mut data = vec![
+            (timer.dont_log as u8) << 7
+                | (value.keep_running as u8) << 6
+                | u8::from(timer.timer_use),
+            timer.actions(),
+            timer.pre_timeout_interval,
+            value.clear_flags.bits(),
+        ];
+        data.extend_from_slice(&timer.initial_countdown.to_le_bytes());
+
+        Message::new_request(NetFn::App, 0x24, data)
+    }
+}
+
+impl IpmiCommand for SetWatchdogTimer {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
+
+/// Restart the countdown of the watchdog timer, starting the timer
+/// if it is stopped.
+///
+/// The BMC responds with completion code `0x80` if the timer has not
+/// been configured using [`SetWatchdogTimer`].
+pub struct ResetWatchdogTimer;
+
+impl From<ResetWatchdogTimer> for Message {
+    fn from(_: ResetWatchdogTimer) -> Self {
+        Message::new_request(NetFn::App, 0x22, Vec::new())
+    }
+}
+
+impl IpmiCommand for ResetWatchdogTimer {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
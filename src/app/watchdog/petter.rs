@@ -0,0 +1,132 @@
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use crate::{
+    connection::{CompletionCode, IpmiConnection},
+    Ipmi, IpmiCommandError,
+};
+
+use super::{ExpirationFlags, ResetWatchdogTimer, SetWatchdogTimer, TimeoutAction, WatchdogTimer};
+
+type PetterResult<CON> = Result<Ipmi<CON>, IpmiCommandError<<CON as IpmiConnection>::Error, ()>>;
+
+/// Keeps the watchdog timer from expiring by resetting it from a
+/// background thread.
+///
+/// [`WatchdogPetter::stop`] disarms the watchdog timer, and so does
+/// dropping the petter. If the petter is dropped while the program
+/// panics, the background thread stops resetting the timer but does not
+/// disarm it, so the watchdog expires and performs its timeout action.
+pub struct WatchdogPetter<CON>
+where
+    CON: IpmiConnection,
+{
+    stop: mpsc::Sender<()>,
+    thread: Option<JoinHandle<PetterResult<CON>>>,
+}
+
+impl<CON> WatchdogPetter<CON>
+where
+    CON: IpmiConnection + Send + 'static,
+    CON::Error: Send,
+{
+    /// Configure the watchdog timer using `timer`, start it, and reset
+    /// it every `interval`.
+    ///
+    /// `interval` should be well below the timeout of `timer`. Failures to
+    /// reset the timer are logged, and the petter keeps trying. If the BMC
+    /// reports that the timer is not initialized, for instance because it
+    /// was reconfigured by someone else, the timer is configured again.
+    pub fn start(
+        mut ipmi: Ipmi<CON>,
+        timer: WatchdogTimer,
+        interval: Duration,
+    ) -> Result<Self, IpmiCommandError<CON::Error, ()>> {
+        let arm = move || SetWatchdogTimer::new(timer, false, ExpirationFlags::all());
+
+        ipmi.send_recv(arm())?;
+        ipmi.send_recv(ResetWatchdogTimer)?;
+
+        let (stop, stopped) = mpsc::channel();
+
+        let thread = std::thread::spawn(move || loop {
+            match stopped.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => match ipmi.send_recv(ResetWatchdogTimer) {
+                    Ok(()) => {}
+                    Err(e)
+                        if e.completion_code() == Some(CompletionCode::CommandSpecific(0x80)) =>
+                    {
+                        log::warn!("Watchdog timer is not initialized, configuring it again");
+
+                        if let Err(e) = ipmi.send_recv(arm()) {
+                            log::warn!("Failed to configure watchdog timer: {e:?}");
+                        } else if let Err(e) = ipmi.send_recv(ResetWatchdogTimer) {
+                            log::warn!("Failed to reset watchdog timer: {e:?}");
+                        }
+                    }
+                    Err(e) => log::warn!("Failed to reset watchdog timer: {e:?}"),
+                },
+                Ok(()) => {
+                    let disarmed = WatchdogTimer {
+                        timeout_action: TimeoutAction::NoAction,
+                        ..timer
+                    };
+
+                    let disarm = SetWatchdogTimer::new(disarmed, false, ExpirationFlags::empty());
+                    return ipmi.send_recv(disarm).map(|_| ipmi);
+                }
+                Err(RecvTimeoutError::Disconnected) => return Ok(ipmi),
+            }
+        });
+
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Stop resetting the watchdog timer, and disarm it.
+    ///
+    /// Returns the connection if the timer was disarmed. If the
+    /// background thread panicked, the panic is resumed on the calling
+    /// thread.
+    pub fn stop(mut self) -> PetterResult<CON> {
+        let thread = self.thread.take().expect("Thread is only taken once");
+
+        // The thread only exits after it has received this.
+        let _ = self.stop.send(());
+
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+impl<CON> Drop for WatchdogPetter<CON>
+where
+    CON: IpmiConnection,
+{
+    fn drop(&mut self) {
+        let Some(thread) = self.thread.take() else {
+            return;
+        };
+
+        // While panicking, leave the watchdog armed: dropping the sender
+        // makes the thread exit without disarming the timer.
+        if std::thread::panicking() {
+            return;
+        }
+
+        let _ = self.stop.send(());
+
+        match thread.join() {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => log::warn!("Failed to disarm watchdog timer: {e:?}"),
+            Err(_) => log::warn!("Watchdog petter thread panicked"),
+        }
+    }
+}
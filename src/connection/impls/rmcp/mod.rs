@@ -2,6 +2,7 @@
 // BE = most significant byte first = RMCP/ASF
 
 use std::{
+    collections::VecDeque,
     io::{Error, ErrorKind},
     net::{ToSocketAddrs, UdpSocket},
     num::NonZeroU32,
@@ -10,10 +11,12 @@ use std::{
 
 use crate::{
     app::auth::{
-        self, ActivateSession, AuthError, Channel, GetChannelAuthenticationCapabilities,
-        GetSessionChallenge, PrivilegeLevel,
+        self, ActivateSession, AuthError, Channel, CloseSession,
+        GetChannelAuthenticationCapabilities, GetSessionChallenge, PrivilegeLevel,
+        SetSessionPrivilegeLevel,
     },
-    connection::{IpmiConnection, LogicalUnit, Response},
+    connection::{IpmiConnection, LogicalUnit, Request, RequestTargetAddress, Response},
+    transport::sol::SolTransport,
     IpmiCommandError,
 };
 
@@ -22,6 +25,12 @@ mod wire;
 use rmcp::*;
 
 mod encapsulation;
+use encapsulation::PayloadType;
+
+mod plus;
+
+mod rakp;
+pub use rakp::{RakpError, RakpStatus};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Inactive;
@@ -34,6 +43,46 @@ pub struct Active {
     request_sequence: u32,
 }
 
+/// An active RMCP+ (IPMI v2.0) session.
+///
+/// The session is closed when it is dropped, which also deactivates
+/// the payloads, such as SOL, that were activated on it. Use
+/// [`Rmcp::close`] to wait until the BMC has closed the session.
+pub struct ActivePlus {
+    session: plus::Session,
+    /// SOL payloads that were received while waiting for a response.
+    sol_payloads: VecDeque<Vec<u8>>,
+    /// Closes the session when it is dropped, unless it was closed already.
+    close_on_drop: Option<CloseOnDrop>,
+}
+
+/// Sends a Close Session request when an RMCP+ session is dropped,
+/// without waiting for the response.
+struct CloseOnDrop {
+    socket: UdpSocket,
+    /// The encoded Close Session request.
+    message: Vec<u8>,
+}
+
+impl Drop for ActivePlus {
+    fn drop(&mut self) {
+        if let Some(close) = self.close_on_drop.take() {
+            log::debug!("Closing RMCP+ session");
+
+            let result = plus::write_packet(
+                Some(&mut self.session),
+                PayloadType::IpmiMessage,
+                &close.message,
+            )
+            .and_then(|packet| close.socket.send(&packet));
+
+            if let Err(e) = result {
+                log::warn!("Failed to close RMCP+ session: {e}");
+            }
+        }
+    }
+}
+
 pub struct Rmcp<T> {
     inner: UdpSocket,
     ipmb_sequence: u8,
@@ -56,6 +105,16 @@ impl<T> Rmcp<T> {
     }
 }
 
+/// Receive an RMCP+ packet, returning its payload type and payload.
+fn recv_packet(
+    socket: &UdpSocket,
+    session: Option<&mut plus::Session>,
+) -> std::io::Result<(PayloadType, Vec<u8>)> {
+    let mut buffer = [0u8; 1024];
+    let received = socket.recv(&mut buffer)?;
+    plus::read_packet(session, &buffer[..received])
+}
+
 type CommandError<T> = IpmiCommandError<<Rmcp<Active> as IpmiConnection>::Error, T>;
 
 #[derive(Debug)]
@@ -67,6 +126,9 @@ pub enum ActivationError {
     GetChannelAuthenticationCapabilities(CommandError<()>),
     GetSessionChallenge(CommandError<AuthError>),
     ActivateSession(CommandError<AuthError>),
+    /// Establishing an RMCP+ session failed.
+    Rakp(RakpError),
+    SetSessionPrivilegeLevel(CommandError<AuthError>),
 }
 
 impl From<std::io::Error> for ActivationError {
@@ -106,20 +168,8 @@ impl Rmcp<Inactive> {
         })
     }
 
-    pub fn activate(
-        self,
-        username: Option<&str>,
-        password: &[u8],
-    ) -> Result<Rmcp<Active>, ActivationError> {
-        let challenge_command = match GetSessionChallenge::new(auth::AuthType::None, username) {
-            Some(v) => v,
-            None => return Err(ActivationError::UsernameTooLong),
-        };
-
-        if password.len() > 16 {
-            return Err(ActivationError::PasswordTooLong);
-        }
-
+    /// Check that the remote supports IPMI using an ASF presence ping.
+    fn ping(&self) -> Result<SupportedInteractions, ActivationError> {
         let ping = RmcpMessage::new(
             0xFF,
             RmcpClass::ASF(ASFMessage {
@@ -128,7 +178,6 @@ impl Rmcp<Inactive> {
             }),
         );
 
-        log::debug!("Starting RMCP activation sequence");
         self.inner.send(&ping.to_bytes())?;
 
         let mut buf = [0u8; 1024];
@@ -163,6 +212,161 @@ impl Rmcp<Inactive> {
             .into());
         }
 
+        Ok(supported_interactions)
+    }
+
+    /// Send a message that establishes an RMCP+ session, and receive
+    /// the response of type `response_type`.
+    fn exchange(
+        &self,
+        request_type: PayloadType,
+        request: &[u8],
+        response_type: PayloadType,
+    ) -> std::io::Result<Vec<u8>> {
+        self.inner
+            .send(&plus::write_packet(None, request_type, request)?)?;
+
+        loop {
+            let (payload_type, payload) = recv_packet(&self.inner, None)?;
+
+            if payload_type == response_type {
+                return Ok(payload);
+            }
+
+            log::debug!("Ignoring {payload_type:?} payload while establishing session");
+        }
+    }
+
+    /// Establish an RMCP+ (IPMI v2.0) session with privilege level
+    /// `privilege_level`.
+    ///
+    /// The session uses cipher suite 3: RAKP-HMAC-SHA1 authentication,
+    /// and HMAC-SHA1-96 integrity and AES-CBC-128 confidentiality for all
+    /// payloads.
+    pub fn activate_plus(
+        self,
+        username: Option<&str>,
+        password: &[u8],
+        privilege_level: PrivilegeLevel,
+    ) -> Result<Rmcp<ActivePlus>, ActivationError> {
+        let username = username.map(str::as_bytes).unwrap_or(&[]);
+
+        if username.len() > 16 {
+            return Err(ActivationError::UsernameTooLong);
+        }
+
+        if password.len() > 20 {
+            return Err(ActivationError::PasswordTooLong);
+        }
+
+        log::debug!("Starting RMCP+ activation sequence");
+        self.ping()?;
+
+        let mut console_id = [0u8; 4];
+        let mut console_random = [0u8; 16];
+        plus::random(&mut console_id)?;
+        plus::random(&mut console_random)?;
+
+        let mut handshake = rakp::Handshake {
+            tag: 0,
+            // Session ID 0 is reserved for messages outside of a session.
+            console_id: u32::from_le_bytes(console_id).max(1),
+            bmc_id: 0,
+            console_random,
+            bmc_random: [0; 16],
+            bmc_guid: [0; 16],
+            // Look up the user by name only.
+            role: 0x10 | u8::from(privilege_level),
+            username,
+            kuid: password,
+        };
+
+        log::debug!("Opening session");
+
+        let response = self.exchange(
+            PayloadType::RmcpPlusOpenSessionRequest,
+            &handshake.open_session_request(),
+            PayloadType::RmcpPlusOpenSessionResponse,
+        )?;
+
+        handshake
+            .open_session_response(&response)
+            .map_err(ActivationError::Rakp)?;
+
+        log::debug!("Exchanging keys");
+
+        let response = self.exchange(
+            PayloadType::RAKPMessage1,
+            &handshake.rakp_message_1(),
+            PayloadType::RAKPMessage2,
+        )?;
+
+        handshake
+            .rakp_message_2(&response)
+            .map_err(ActivationError::Rakp)?;
+
+        let response = self.exchange(
+            PayloadType::RAKPMessage3,
+            &handshake.rakp_message_3(),
+            PayloadType::RAKPMessage4,
+        )?;
+
+        handshake
+            .rakp_message_4(&response)
+            .map_err(ActivationError::Rakp)?;
+
+        let session = plus::Session::new(handshake.console_id, handshake.bmc_id, &handshake.sik());
+
+        let mut ipmb_sequence = self.ipmb_sequence;
+        let close_request = Request::new(
+            CloseSession::new(session.remote_id).into(),
+            RequestTargetAddress::Bmc(LogicalUnit::Zero),
+        );
+        let close_on_drop = CloseOnDrop {
+            socket: self.inner.try_clone()?,
+            message: wire::encode_message(
+                self.requestor_addr,
+                self.responder_addr,
+                &mut ipmb_sequence,
+                self.requestor_lun,
+                &close_request,
+            ),
+        };
+
+        let activated = self.convert(ActivePlus {
+            session,
+            sol_payloads: VecDeque::new(),
+            close_on_drop: Some(close_on_drop),
+        });
+
+        let mut ipmi = crate::Ipmi::new(activated);
+
+        // Sessions start at the User privilege level.
+        ipmi.send_recv(SetSessionPrivilegeLevel::new(privilege_level))
+            .map_err(ActivationError::SetSessionPrivilegeLevel)?;
+
+        log::debug!("Succesfully started an RMCP+ session");
+
+        Ok(ipmi.release())
+    }
+
+    pub fn activate(
+        self,
+        username: Option<&str>,
+        password: &[u8],
+    ) -> Result<Rmcp<Active>, ActivationError> {
+        let challenge_command = match GetSessionChallenge::new(auth::AuthType::None, username) {
+            Some(v) => v,
+            None => return Err(ActivationError::UsernameTooLong),
+        };
+
+        if password.len() > 16 {
+            return Err(ActivationError::PasswordTooLong);
+        }
+
+        log::debug!("Starting RMCP activation sequence");
+        let supported_interactions = self.ping()?;
+
         let privilege_level = PrivilegeLevel::Administrator;
 
         let mut password_padded = [0u8; 16];
@@ -267,3 +471,394 @@ impl IpmiConnection for Rmcp<Active> {
         self.recv()
     }
 }
+
+impl Rmcp<ActivePlus> {
+    /// Close the session, which also deactivates the payloads, such
+    /// as SOL, that were activated on it.
+    pub fn close(self) -> Result<(), CommandError<()>> {
+        let session_id = self.state.session.remote_id;
+        let mut ipmi = crate::Ipmi::new(self);
+
+        ipmi.send_recv(CloseSession::new(session_id))?;
+        ipmi.inner_mut().state.close_on_drop = None;
+
+        log::debug!("Closed RMCP+ session");
+
+        Ok(())
+    }
+}
+
+impl IpmiConnection for Rmcp<ActivePlus> {
+    type SendError = Error;
+
+    type RecvError = Error;
+
+    type Error = Error;
+
+    fn send(&mut self, request: &mut crate::connection::Request) -> Result<(), Self::SendError> {
+        let message = wire::encode_message(
+            self.requestor_addr,
+            self.responder_addr,
+            &mut self.ipmb_sequence,
+            self.requestor_lun,
+            request,
+        );
+
+        let packet = plus::write_packet(
+            Some(&mut self.state.session),
+            PayloadType::IpmiMessage,
+            &message,
+        )?;
+
+        self.inner.send(&packet).map(|_| ())
+    }
+
+    fn recv(&mut self) -> Result<Response, Self::RecvError> {
+        loop {
+            match recv_packet(&self.inner, Some(&mut self.state.session)) {
+                Ok((PayloadType::IpmiMessage, payload)) => return wire::decode_response(&payload),
+                Ok((PayloadType::Sol, payload)) => self.state.sol_payloads.push_back(payload),
+                Ok((payload_type, _)) => log::debug!("Ignoring {payload_type:?} payload"),
+                Err(e) if e.kind() == ErrorKind::InvalidData => {
+                    log::warn!("Ignoring invalid RMCP+ packet: {e}")
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn send_recv(
+        &mut self,
+        request: &mut crate::connection::Request,
+    ) -> Result<Response, Self::Error> {
+        IpmiConnection::send(self, request)?;
+        IpmiConnection::recv(self)
+    }
+}
+
+/// SOL payloads are exchanged over the session, so SOL must be activated
+/// with both encryption and authentication.
+impl SolTransport for Rmcp<ActivePlus> {
+    fn send(&mut self, payload: &[u8]) -> std::io::Result<()> {
+        let packet = plus::write_packet(Some(&mut self.state.session), PayloadType::Sol, payload)?;
+        self.inner.send(&packet).map(|_| ())
+    }
+
+    fn recv(&mut self, timeout: Duration) -> std::io::Result<Option<Vec<u8>>> {
+        if let Some(payload) = self.state.sol_payloads.pop_front() {
+            return Ok(Some(payload));
+        }
+
+        let read_timeout = self.inner.read_timeout()?;
+        self.inner
+            .set_read_timeout(Some(timeout.max(Duration::from_millis(1))))?;
+
+        let result = loop {
+            match recv_packet(&self.inner, Some(&mut self.state.session)) {
+                Ok((PayloadType::Sol, payload)) => break Ok(Some(payload)),
+                Ok((payload_type, _)) => log::debug!("Ignoring {payload_type:?} payload"),
+                Err(e) if e.kind() == ErrorKind::InvalidData => {
+                    log::warn!("Ignoring invalid RMCP+ packet: {e}")
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    break Ok(None)
+                }
+                Err(e) => break Err(e),
+            }
+        };
+
+        self.inner.set_read_timeout(read_timeout)?;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::SocketAddr,
+    };
+
+    use crate::transport::sol::{SolPacket, SolStream, SolStreamConfig};
+
+    use super::*;
+
+    const BMC_ID: u32 = 0x0A0B_0C0D;
+
+    /// Answer the presence ping and establish an RMCP+ session with
+    /// `privilege_level` as a BMC with user key `kuid`, returning the session if the remote console
+    /// authenticated itself.
+    fn accept_session(
+        socket: &UdpSocket,
+        kuid: &[u8],
+        privilege_level: PrivilegeLevel,
+    ) -> Option<(plus::Session, SocketAddr)> {
+        let mut buffer = [0u8; 1024];
+
+        let (_, console) = socket.recv_from(&mut buffer).unwrap();
+        let pong = RmcpMessage::new(
+            0xFF,
+            RmcpClass::ASF(ASFMessage {
+                message_tag: 0x00,
+                message_type: ASFMessageType::Pong {
+                    enterprise_number: 4542,
+                    oem_data: 0,
+                    supported_entities: SupportedEntities { ipmi: true },
+                    supported_interactions: SupportedInteractions {
+                        rcmp_security: false,
+                        dmtf_dash: false,
+                    },
+                },
+            }),
+        );
+        socket.send_to(&pong.to_bytes(), console).unwrap();
+
+        let mut recv = |expected| {
+            let received = socket.recv(&mut buffer).unwrap();
+            let (payload_type, data) = plus::read_packet(None, &buffer[..received]).unwrap();
+            assert_eq!(expected, payload_type);
+            data
+        };
+
+        let send = |payload_type, data: &[u8]| {
+            let packet = plus::write_packet(None, payload_type, data).unwrap();
+            socket.send_to(&packet, console).unwrap();
+        };
+
+        let request = recv(PayloadType::RmcpPlusOpenSessionRequest);
+        let console_id = u32::from_le_bytes(request[4..8].try_into().unwrap());
+        let mut response = vec![request[0], 0x00, 0x04, 0x00];
+        response.extend_from_slice(&console_id.to_le_bytes());
+        response.extend_from_slice(&BMC_ID.to_le_bytes());
+        response.extend_from_slice(&plus::ALGORITHMS);
+        send(PayloadType::RmcpPlusOpenSessionResponse, &response);
+
+        let rakp_1 = recv(PayloadType::RAKPMessage1);
+        assert_eq!(BMC_ID.to_le_bytes(), rakp_1[4..8]);
+        assert_eq!(0x10 | u8::from(privilege_level), rakp_1[24]);
+        let username = &rakp_1[28..28 + rakp_1[27] as usize];
+
+        let handshake = rakp::Handshake {
+            tag: rakp_1[0],
+            console_id,
+            bmc_id: BMC_ID,
+            console_random: rakp_1[8..24].try_into().unwrap(),
+            bmc_random: [0x33; 16],
+            bmc_guid: [0x44; 16],
+            role: rakp_1[24],
+            username,
+            kuid,
+        };
+
+        let mut rakp_2 = vec![handshake.tag, 0x00, 0x00, 0x00];
+        rakp_2.extend_from_slice(&console_id.to_le_bytes());
+        rakp_2.extend_from_slice(&handshake.bmc_random);
+        rakp_2.extend_from_slice(&handshake.bmc_guid);
+        rakp_2.extend_from_slice(&handshake.rakp_2_auth_code());
+        send(PayloadType::RAKPMessage2, &rakp_2);
+
+        socket
+            .set_read_timeout(Some(Duration::from_millis(200)))
+            .unwrap();
+        let received = socket.recv(&mut buffer).ok()?;
+        socket.set_read_timeout(None).unwrap();
+
+        let (_, rakp_3) = plus::read_packet(None, &buffer[..received]).unwrap();
+        assert_eq!(handshake.rakp_3_auth_code(), rakp_3[8..28]);
+
+        let mut rakp_4 = vec![handshake.tag, 0x00, 0x00, 0x00];
+        rakp_4.extend_from_slice(&console_id.to_le_bytes());
+        rakp_4.extend_from_slice(&handshake.rakp_4_check_value());
+        send(PayloadType::RAKPMessage4, &rakp_4);
+
+        Some((
+            plus::Session::new(BMC_ID, console_id, &handshake.sik()),
+            console,
+        ))
+    }
+
+    fn bmc() -> (UdpSocket, SocketAddr) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let address = socket.local_addr().unwrap();
+        (socket, address)
+    }
+
+    /// Receive the next IPMI message in `session`, ignoring SOL payloads.
+    fn recv_message(socket: &UdpSocket, session: &mut plus::Session) -> Vec<u8> {
+        let mut buffer = [0u8; 1024];
+
+        loop {
+            let received = socket.recv(&mut buffer).unwrap();
+            match plus::read_packet(Some(session), &buffer[..received]).unwrap() {
+                (PayloadType::IpmiMessage, request) => return request,
+                (payload_type, _) => assert_eq!(PayloadType::Sol, payload_type),
+            }
+        }
+    }
+
+    /// Respond to `request` with a successful response that contains `data`.
+    fn respond(
+        socket: &UdpSocket,
+        console: SocketAddr,
+        session: &mut plus::Session,
+        request: &[u8],
+        data: &[u8],
+    ) {
+        let netfn = request[1] >> 2;
+        let mut response = vec![
+            0x81,
+            (netfn + 1) << 2,
+            0x00,
+            0x20,
+            request[4],
+            request[5],
+            0x00,
+        ];
+        response.extend_from_slice(data);
+        response.push(0x00);
+
+        let packet = plus::write_packet(Some(session), PayloadType::IpmiMessage, &response);
+        socket.send_to(&packet.unwrap(), console).unwrap();
+    }
+
+    /// Accept a session, and the request that sets its privilege level.
+    fn accept_operator_session(socket: &UdpSocket) -> (plus::Session, SocketAddr) {
+        let (mut session, console) =
+            accept_session(socket, b"hunter2", PrivilegeLevel::Operator).unwrap();
+
+        let request = recv_message(socket, &mut session);
+        assert_eq!(
+            (0x06 << 2, 0x3B, 0x03),
+            (request[1], request[5], request[6])
+        );
+        respond(socket, console, &mut session, &request, &[0x03]);
+
+        (session, console)
+    }
+
+    /// Receive the request that closes `session`, and confirm it.
+    fn accept_close(socket: &UdpSocket, console: SocketAddr, session: &mut plus::Session) {
+        let request = recv_message(socket, session);
+        assert_eq!((0x06 << 2, 0x3C), (request[1], request[5]));
+        assert_eq!(BMC_ID.to_le_bytes(), request[6..10]);
+        respond(socket, console, session, &request, &[]);
+    }
+
+    #[test]
+    fn rmcp_plus_sol() {
+        let (socket, address) = bmc();
+
+        let bmc = std::thread::spawn(move || {
+            let (mut session, console) = accept_operator_session(&socket);
+            let mut buffer = [0u8; 1024];
+
+            let send_sol = |session: &mut plus::Session, packet: SolPacket| {
+                let mut data = Vec::new();
+                packet.write_data(&mut data);
+                let packet = plus::write_packet(Some(session), PayloadType::Sol, &data);
+                socket.send_to(&packet.unwrap(), console).unwrap();
+            };
+
+            send_sol(
+                &mut session,
+                SolPacket {
+                    sequence: 1,
+                    ack_sequence: 0,
+                    accepted: 0,
+                    operation_status: 0,
+                    data: b"login: ".to_vec(),
+                },
+            );
+
+            let mut received = Vec::new();
+            while received != b"root\n" {
+                let len = socket.recv(&mut buffer).unwrap();
+                let (payload_type, payload) =
+                    plus::read_packet(Some(&mut session), &buffer[..len]).unwrap();
+                assert_eq!(PayloadType::Sol, payload_type);
+
+                let packet = SolPacket::parse(&payload).unwrap();
+                if packet.sequence != 0 {
+                    received.extend_from_slice(&packet.data);
+                    send_sol(
+                        &mut session,
+                        SolPacket {
+                            sequence: 0,
+                            ack_sequence: packet.sequence,
+                            accepted: packet.data.len() as u8,
+                            operation_status: 0,
+                            data: Vec::new(),
+                        },
+                    );
+                }
+            }
+
+            accept_close(&socket, console, &mut session);
+        });
+
+        let rmcp = Rmcp::new(address, Duration::from_secs(2))
+            .unwrap()
+            .activate_plus(Some("admin"), b"hunter2", PrivilegeLevel::Operator)
+            .unwrap();
+
+        let config = SolStreamConfig {
+            max_characters: 64,
+            accumulate_interval: Duration::from_millis(10),
+            send_threshold: 64,
+            retry_interval: Duration::from_millis(500),
+            retry_count: 3,
+        };
+
+        let mut stream = SolStream::new(rmcp, config);
+        stream.set_read_timeout(Some(Duration::from_secs(2)));
+
+        let mut buffer = [0u8; 16];
+        let len = stream.read(&mut buffer).unwrap();
+        assert_eq!(b"login: ", &buffer[..len]);
+
+        stream.write_all(b"root\n").unwrap();
+        stream.flush().unwrap();
+
+        stream.into_inner().close().unwrap();
+
+        bmc.join().unwrap();
+    }
+
+    #[test]
+    fn rmcp_plus_close_on_drop() {
+        let (socket, address) = bmc();
+
+        let bmc = std::thread::spawn(move || {
+            let (mut session, console) = accept_operator_session(&socket);
+            accept_close(&socket, console, &mut session);
+        });
+
+        let rmcp = Rmcp::new(address, Duration::from_secs(2))
+            .unwrap()
+            .activate_plus(Some("admin"), b"hunter2", PrivilegeLevel::Operator)
+            .unwrap();
+
+        drop(rmcp);
+
+        bmc.join().unwrap();
+    }
+
+    #[test]
+    fn rmcp_plus_wrong_password() {
+        let (socket, address) = bmc();
+
+        let bmc = std::thread::spawn(move || {
+            accept_session(&socket, b"hunter2", PrivilegeLevel::Administrator).is_none()
+        });
+
+        let result = Rmcp::new(address, Duration::from_secs(2))
+            .unwrap()
+            .activate_plus(Some("admin"), b"hunter3", PrivilegeLevel::Administrator);
+
+        assert!(matches!(
+            result,
+            Err(ActivationError::Rakp(RakpError::AuthCodeMismatch))
+        ));
+        assert!(bmc.join().unwrap());
+    }
+}
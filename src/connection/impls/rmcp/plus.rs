@@ -0,0 +1,411 @@
+//! RMCP+ (IPMI v2.0) session packets.
+//!
+//! Sessions use cipher suite 3: RAKP-HMAC-SHA1 authentication,
+//! HMAC-SHA1-96 integrity and AES-CBC-128 confidentiality.
+
+use std::io::{Error, ErrorKind};
+
+use aes::{
+    cipher::{block_padding::NoPadding, BlockDecryptMut, BlockEncryptMut, KeyIvInit},
+    Aes128,
+};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+use super::encapsulation::PayloadType;
+
+/// The authentication type that identifies RMCP+ session headers.
+const AUTH_TYPE_RMCP_PLUS: u8 = 0x06;
+
+/// The length of an HMAC-SHA1-96 authentication code.
+pub const INTEGRITY_LEN: usize = 12;
+
+const AES_BLOCK_LEN: usize = 16;
+
+/// The algorithm payloads of an Open Session Request, proposing
+/// RAKP-HMAC-SHA1, HMAC-SHA1-96 and AES-CBC-128.
+pub const ALGORITHMS: [u8; 24] = [
+    0x00, 0x00, 0x00, 0x08, 0x01, 0x00, 0x00, 0x00, // Authentication
+    0x01, 0x00, 0x00, 0x08, 0x01, 0x00, 0x00, 0x00, // Integrity
+    0x02, 0x00, 0x00, 0x08, 0x01, 0x00, 0x00, 0x00, // Confidentiality
+];
+
+fn invalid(message: &'static str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// Calculate the HMAC-SHA1 of the concatenation of `parts`.
+pub fn hmac_sha1(key: &[u8], parts: &[&[u8]]) -> [u8; 20] {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any length");
+
+    for part in parts {
+        mac.update(part);
+    }
+
+    mac.finalize().into_bytes().into()
+}
+
+/// Fill `buffer` with random data.
+pub fn random(buffer: &mut [u8]) -> std::io::Result<()> {
+    getrandom::getrandom(buffer).map_err(Error::from)
+}
+
+/// The amount of sequence numbers above the highest received sequence
+/// number that are accepted (IPMI v2.0, section 6.12.13).
+const SEQUENCE_WINDOW_AHEAD: u32 = 32;
+
+/// The amount of sequence numbers below the highest received sequence
+/// number that are accepted, if they were not received yet.
+const SEQUENCE_WINDOW_BEHIND: u32 = 16;
+
+/// The keys and sequence numbers of an active RMCP+ session.
+#[derive(Clone)]
+pub struct Session {
+    /// The session ID that the peer uses to address us.
+    pub local_id: u32,
+    /// The session ID that we use to address the peer.
+    pub remote_id: u32,
+    pub sequence: u32,
+    /// The highest sequence number that was received, or 0 if no
+    /// packets were received yet.
+    inbound_sequence: u32,
+    /// Bit `n` is set if sequence number `inbound_sequence - 1 - n`
+    /// was received.
+    inbound_received: u16,
+    k1: [u8; 20],
+    aes_key: [u8; 16],
+}
+
+impl Session {
+    /// Derive the keys of a session from its Session Integrity Key.
+    pub fn new(local_id: u32, remote_id: u32, sik: &[u8; 20]) -> Self {
+        let k1 = hmac_sha1(sik, &[&[0x01; 20]]);
+        let k2 = hmac_sha1(sik, &[&[0x02; 20]]);
+
+        Self {
+            local_id,
+            remote_id,
+            sequence: 0,
+            inbound_sequence: 0,
+            inbound_received: 0,
+            k1,
+            aes_key: k2[..16].try_into().unwrap(),
+        }
+    }
+
+    fn encrypt(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        let mut iv = [0u8; AES_BLOCK_LEN];
+        random(&mut iv)?;
+
+        // Confidentiality pad bytes are 1, 2, 3, ..., followed by the
+        // amount of pad bytes.
+        let pad_len = (AES_BLOCK_LEN - (data.len() + 1) % AES_BLOCK_LEN) % AES_BLOCK_LEN;
+
+        let mut plain = data.to_vec();
+        plain.extend(1..=pad_len as u8);
+        plain.push(pad_len as u8);
+
+        let len = plain.len();
+        cbc::Encryptor::<Aes128>::new(&self.aes_key.into(), &iv.into())
+            .encrypt_padded_mut::<NoPadding>(&mut plain, len)
+            .map_err(|_| invalid("Confidentiality pad is incorrect"))?;
+
+        let mut encrypted = iv.to_vec();
+        encrypted.extend(plain);
+        Ok(encrypted)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        // `usize::is_multiple_of` requires Rust 1.87.
+        #[allow(clippy::manual_is_multiple_of)]
+        if data.len() < 2 * AES_BLOCK_LEN || data.len() % AES_BLOCK_LEN != 0 {
+            return Err(invalid("Encrypted payload has an invalid length"));
+        }
+
+        let (iv, encrypted) = data.split_at(AES_BLOCK_LEN);
+        let iv: [u8; AES_BLOCK_LEN] = iv.try_into().unwrap();
+
+        let mut plain = encrypted.to_vec();
+        cbc::Decryptor::<Aes128>::new(&self.aes_key.into(), &iv.into())
+            .decrypt_padded_mut::<NoPadding>(&mut plain)
+            .map_err(|_| invalid("Encrypted payload has an invalid length"))?;
+
+        let pad_len = *plain.last().unwrap() as usize;
+        let data_len = plain
+            .len()
+            .checked_sub(pad_len + 1)
+            .ok_or_else(|| invalid("Confidentiality pad is too long"))?;
+
+        if !plain[data_len..data_len + pad_len]
+            .iter()
+            .zip(1..)
+            .all(|(v, expected)| *v == expected)
+        {
+            return Err(invalid("Confidentiality pad is incorrect"));
+        }
+
+        plain.truncate(data_len);
+        Ok(plain)
+    }
+
+    /// Accept inbound sequence number `sequence` if it lies in the
+    /// sliding window, and has not been received before.
+    fn accept_sequence(&mut self, sequence: u32) -> std::io::Result<()> {
+        if sequence == 0 {
+            return Err(invalid("Session packet has sequence number 0"));
+        }
+
+        if self.inbound_sequence == 0 {
+            self.inbound_sequence = sequence;
+            return Ok(());
+        }
+
+        let ahead = sequence.wrapping_sub(self.inbound_sequence);
+        let behind = self.inbound_sequence.wrapping_sub(sequence);
+
+        if (1..=SEQUENCE_WINDOW_AHEAD).contains(&ahead) {
+            // The previous highest sequence number becomes bit `ahead - 1`.
+            let received = (u32::from(self.inbound_received) << 1 | 1) << (ahead - 1);
+
+            self.inbound_received = received as u16;
+            self.inbound_sequence = sequence;
+            Ok(())
+        } else if (1..=SEQUENCE_WINDOW_BEHIND).contains(&behind) {
+            let bit = 1 << (behind - 1);
+
+            if self.inbound_received & bit == bit {
+                return Err(invalid("Session packet was already received"));
+            }
+
+            self.inbound_received |= bit;
+            Ok(())
+        } else if behind == 0 {
+            Err(invalid("Session packet was already received"))
+        } else {
+            Err(invalid("Session sequence number is outside of the window"))
+        }
+    }
+
+    fn auth_code(&self, data: &[u8]) -> [u8; INTEGRITY_LEN] {
+        hmac_sha1(&self.k1, &[data])[..INTEGRITY_LEN]
+            .try_into()
+            .unwrap()
+    }
+}
+
+/// Write an RMCP+ packet that contains `payload`.
+///
+/// Packets that are sent outside of a session, such as the messages
+/// that establish one, are neither encrypted nor authenticated. Packets
+/// that are sent in `session` are both.
+pub fn write_packet(
+    session: Option<&mut Session>,
+    payload_type: PayloadType,
+    payload: &[u8],
+) -> std::io::Result<Vec<u8>> {
+    // RMCP header: version 6, no RMCP ACK, class IPMI.
+    let mut packet = vec![0x06, 0x00, 0xFF, 0x07];
+    let session_start = packet.len();
+
+    let session = match session {
+        Some(session) => session,
+        None => {
+            packet.push(AUTH_TYPE_RMCP_PLUS);
+            packet.push(u8::from(payload_type));
+            packet.extend_from_slice(&0u32.to_le_bytes());
+            packet.extend_from_slice(&0u32.to_le_bytes());
+            packet.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+            packet.extend_from_slice(payload);
+            return Ok(packet);
+        }
+    };
+
+    session.sequence = session.sequence.wrapping_add(1).max(1);
+    let encrypted = session.encrypt(payload)?;
+
+    packet.push(AUTH_TYPE_RMCP_PLUS);
+    packet.push(0x80 | 0x40 | u8::from(payload_type));
+    packet.extend_from_slice(&session.remote_id.to_le_bytes());
+    packet.extend_from_slice(&session.sequence.to_le_bytes());
+    packet.extend_from_slice(&(encrypted.len() as u16).to_le_bytes());
+    packet.extend_from_slice(&encrypted);
+
+    // The integrity pad makes the authenticated data, which ends with
+    // the pad length and next header fields, a multiple of 4 bytes.
+    let pad_len = (4 - (packet.len() - session_start + 2) % 4) % 4;
+    // `std::iter::repeat_n` requires Rust 1.82.
+    #[allow(clippy::manual_repeat_n)]
+    packet.extend(std::iter::repeat(0xFF).take(pad_len));
+    packet.push(pad_len as u8);
+    packet.push(0x07);
+
+    let auth_code = session.auth_code(&packet[session_start..]);
+    packet.extend_from_slice(&auth_code);
+
+    Ok(packet)
+}
+
+/// Read an RMCP+ packet, returning its payload type and payload.
+///
+/// Packets that are received in `session` must be authenticated, and their
+/// sequence number must lie in the sliding window of the session, to reject
+/// replayed packets. They are decrypted if they are encrypted.
+pub fn read_packet(
+    session: Option<&mut Session>,
+    data: &[u8],
+) -> std::io::Result<(PayloadType, Vec<u8>)> {
+    if data.len() < 16 || data[0] != 0x06 || data[3] != 0x07 {
+        return Err(invalid("Packet is not an IPMI RMCP packet"));
+    }
+
+    let data = &data[4..];
+
+    if data[0] != AUTH_TYPE_RMCP_PLUS {
+        return Err(invalid("Packet does not have an RMCP+ session header"));
+    }
+
+    let encrypted = data[1] & 0x80 == 0x80;
+    let authenticated = data[1] & 0x40 == 0x40;
+    let payload_type =
+        PayloadType::try_from(data[1]).map_err(|_| invalid("Unknown payload type"))?;
+    let session_id = u32::from_le_bytes(data[2..6].try_into().unwrap());
+    let sequence = u32::from_le_bytes(data[6..10].try_into().unwrap());
+    let payload_len = u16::from_le_bytes(data[10..12].try_into().unwrap()) as usize;
+
+    let payload = data
+        .get(12..12 + payload_len)
+        .ok_or_else(|| invalid("Payload length is not correct"))?;
+
+    let session = match session {
+        Some(session) => session,
+        None => {
+            if encrypted || authenticated || session_id != 0 {
+                return Err(invalid("Received a session packet outside of a session"));
+            }
+            return Ok((payload_type, payload.to_vec()));
+        }
+    };
+
+    if session_id != session.local_id {
+        return Err(invalid("Packet belongs to a different session"));
+    }
+
+    if !authenticated {
+        return Err(invalid("Session packet is not authenticated"));
+    }
+
+    let authenticated_len = data
+        .len()
+        .checked_sub(INTEGRITY_LEN)
+        .filter(|len| *len >= 12 + payload_len + 2)
+        .ok_or_else(|| invalid("Session trailer is missing"))?;
+
+    let (authenticated_data, auth_code) = data.split_at(authenticated_len);
+
+    if session.auth_code(authenticated_data) != auth_code {
+        return Err(invalid("Integrity check failed"));
+    }
+
+    session.accept_sequence(sequence)?;
+
+    let payload = if encrypted {
+        session.decrypt(payload)?
+    } else {
+        payload.to_vec()
+    };
+
+    Ok((payload_type, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sessions() -> (Session, Session) {
+        let sik = [0x5A; 20];
+        (Session::new(1, 2, &sik), Session::new(2, 1, &sik))
+    }
+
+    #[test]
+    fn round_trip() {
+        let (mut console, mut bmc) = sessions();
+
+        for len in [0, 1, 15, 16, 17, 40] {
+            let payload: Vec<u8> = (0..len).collect();
+            let packet = write_packet(Some(&mut console), PayloadType::Sol, &payload).unwrap();
+
+            // Authenticated data is a multiple of 4 bytes.
+            assert_eq!(0, (packet.len() - 4 - INTEGRITY_LEN) % 4);
+
+            let (ty, received) = read_packet(Some(&mut bmc), &packet).unwrap();
+            assert_eq!(PayloadType::Sol, ty);
+            assert_eq!(payload, received);
+        }
+
+        assert_eq!(6, console.sequence);
+    }
+
+    #[test]
+    fn rejects_modified_packets() {
+        let (mut console, mut bmc) = sessions();
+
+        let mut packet = write_packet(Some(&mut console), PayloadType::Sol, &[1, 2, 3]).unwrap();
+        packet[20] ^= 0x01;
+
+        assert!(read_packet(Some(&mut bmc), &packet).is_err());
+
+        // The packet is addressed to the BMC, not to the console.
+        let packet = write_packet(Some(&mut console), PayloadType::Sol, &[1, 2, 3]).unwrap();
+        assert!(read_packet(Some(&mut console.clone()), &packet).is_err());
+    }
+
+    #[test]
+    fn sequence_window() {
+        let (mut console, mut bmc) = sessions();
+
+        let mut packet = |sequence: u32| {
+            console.sequence = sequence - 1;
+            write_packet(Some(&mut console), PayloadType::Sol, &[1, 2, 3]).unwrap()
+        };
+
+        let packets: Vec<_> = (1..=40).map(&mut packet).collect();
+        let mut accepted =
+            |sequence: u32| read_packet(Some(&mut bmc), &packets[sequence as usize - 1]).is_ok();
+
+        assert!(accepted(20));
+        // Replayed
+        assert!(!accepted(20));
+        // Out of order, but in the window
+        assert!(accepted(4));
+        assert!(accepted(19));
+        assert!(!accepted(19));
+        // Too far behind
+        assert!(!accepted(3));
+        // Ahead
+        assert!(accepted(25));
+        assert!(accepted(21));
+        assert!(!accepted(21));
+        assert!(!accepted(8));
+        assert!(accepted(9));
+
+        // Too far ahead
+        let far_ahead = packet(58);
+        assert!(read_packet(Some(&mut bmc), &far_ahead).is_err());
+        let ahead = packet(57);
+        assert!(read_packet(Some(&mut bmc), &ahead).is_ok());
+        assert!(read_packet(Some(&mut bmc), &far_ahead).is_ok());
+    }
+
+    #[test]
+    fn sessionless() {
+        let packet = write_packet(None, PayloadType::RAKPMessage1, &[1, 2, 3]).unwrap();
+        assert_eq!(
+            &[6, 0, 0xFF, 7, 6, 0x12, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 2, 3],
+            &packet[..]
+        );
+
+        let (ty, payload) = read_packet(None, &packet).unwrap();
+        assert_eq!(PayloadType::RAKPMessage1, ty);
+        assert_eq!(vec![1, 2, 3], payload);
+    }
+}
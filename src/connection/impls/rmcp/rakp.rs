@@ -0,0 +1,246 @@
+//! The messages that establish an RMCP+ session: the Open Session
+//! Request and Response, and RAKP messages 1 through 4.
+
+use super::plus::{hmac_sha1, ALGORITHMS, INTEGRITY_LEN};
+
+/// A status code of an Open Session Response or RAKP message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RakpStatus {
+    InsufficientResources,
+    InvalidSessionId,
+    InvalidPayloadType,
+    InvalidAuthenticationAlgorithm,
+    InvalidIntegrityAlgorithm,
+    NoMatchingAuthenticationPayload,
+    NoMatchingIntegrityPayload,
+    InactiveSessionId,
+    InvalidRole,
+    UnauthorizedRole,
+    InsufficientResourcesForRole,
+    InvalidNameLength,
+    UnauthorizedName,
+    UnauthorizedGuid,
+    InvalidIntegrityCheckValue,
+    InvalidConfidentialityAlgorithm,
+    NoCipherSuiteMatch,
+    IllegalParameter,
+    Other(u8),
+}
+
+impl From<u8> for RakpStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::InsufficientResources,
+            0x02 => Self::InvalidSessionId,
+            0x03 => Self::InvalidPayloadType,
+            0x04 => Self::InvalidAuthenticationAlgorithm,
+            0x05 => Self::InvalidIntegrityAlgorithm,
+            0x06 => Self::NoMatchingAuthenticationPayload,
+            0x07 => Self::NoMatchingIntegrityPayload,
+            0x08 => Self::InactiveSessionId,
+            0x09 => Self::InvalidRole,
+            0x0A => Self::UnauthorizedRole,
+            0x0B => Self::InsufficientResourcesForRole,
+            0x0C => Self::InvalidNameLength,
+            0x0D => Self::UnauthorizedName,
+            0x0E => Self::UnauthorizedGuid,
+            0x0F => Self::InvalidIntegrityCheckValue,
+            0x10 => Self::InvalidConfidentialityAlgorithm,
+            0x11 => Self::NoCipherSuiteMatch,
+            0x12 => Self::IllegalParameter,
+            v => Self::Other(v),
+        }
+    }
+}
+
+/// A message of the RMCP+ session establishment that could not
+/// be accepted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RakpError {
+    /// The message is too short.
+    NotEnoughData,
+    /// The message tag or a session ID does not match the request.
+    Mismatch,
+    /// The BMC reported an error.
+    Status(RakpStatus),
+    /// The BMC selected algorithms other than the proposed ones.
+    UnsupportedAlgorithms,
+    /// The key exchange authentication code of RAKP message 2, or the
+    /// integrity check value of RAKP message 4, is incorrect.
+    /// This usually means that the password is incorrect.
+    AuthCodeMismatch,
+}
+
+fn check_status(data: &[u8], min_len: usize, tag: u8) -> Result<(), RakpError> {
+    if data.len() < 2 {
+        return Err(RakpError::NotEnoughData);
+    }
+
+    if data[0] != tag {
+        return Err(RakpError::Mismatch);
+    }
+
+    if data[1] != 0 {
+        return Err(RakpError::Status(data[1].into()));
+    }
+
+    if data.len() < min_len {
+        return Err(RakpError::NotEnoughData);
+    }
+
+    Ok(())
+}
+
+/// The state of the remote console while establishing a session.
+pub struct Handshake<'a> {
+    pub tag: u8,
+    /// The remote console session ID.
+    pub console_id: u32,
+    /// The managed system session ID.
+    pub bmc_id: u32,
+    /// The remote console random number.
+    pub console_random: [u8; 16],
+    pub bmc_random: [u8; 16],
+    pub bmc_guid: [u8; 16],
+    /// The requested maximum privilege level and lookup flags.
+    pub role: u8,
+    pub username: &'a [u8],
+    /// The user key, which is the password of the user.
+    pub kuid: &'a [u8],
+}
+
+impl Handshake<'_> {
+    pub fn open_session_request(&self) -> Vec<u8> {
+        let mut data = vec![self.tag, self.role & 0x0F, 0x00, 0x00];
+        data.extend_from_slice(&self.console_id.to_le_bytes());
+        data.extend_from_slice(&ALGORITHMS);
+        data
+    }
+
+    pub fn open_session_response(&mut self, data: &[u8]) -> Result<(), RakpError> {
+        check_status(data, 36, self.tag)?;
+
+        if data[4..8] != self.console_id.to_le_bytes() {
+            return Err(RakpError::Mismatch);
+        }
+
+        // The BMC returns the algorithm payloads that it selected.
+        let algorithms = |data: &[u8]| [data[0], data[4] & 0x3F];
+        if data[12..36]
+            .chunks(8)
+            .zip(ALGORITHMS.chunks(8))
+            .any(|(selected, proposed)| algorithms(selected) != algorithms(proposed))
+        {
+            return Err(RakpError::UnsupportedAlgorithms);
+        }
+
+        self.bmc_id = u32::from_le_bytes(data[8..12].try_into().unwrap());
+        Ok(())
+    }
+
+    pub fn rakp_message_1(&self) -> Vec<u8> {
+        let mut data = vec![self.tag, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&self.bmc_id.to_le_bytes());
+        data.extend_from_slice(&self.console_random);
+        data.extend_from_slice(&[self.role, 0x00, 0x00, self.username.len() as u8]);
+        data.extend_from_slice(self.username);
+        data
+    }
+
+    /// The key exchange authentication code of RAKP message 2.
+    pub fn rakp_2_auth_code(&self) -> [u8; 20] {
+        hmac_sha1(
+            self.kuid,
+            &[
+                &self.console_id.to_le_bytes(),
+                &self.bmc_id.to_le_bytes(),
+                &self.console_random,
+                &self.bmc_random,
+                &self.bmc_guid,
+                &[self.role, self.username.len() as u8],
+                self.username,
+            ],
+        )
+    }
+
+    pub fn rakp_message_2(&mut self, data: &[u8]) -> Result<(), RakpError> {
+        check_status(data, 60, self.tag)?;
+
+        if data[4..8] != self.console_id.to_le_bytes() {
+            return Err(RakpError::Mismatch);
+        }
+
+        self.bmc_random.copy_from_slice(&data[8..24]);
+        self.bmc_guid.copy_from_slice(&data[24..40]);
+
+        if self.rakp_2_auth_code() != data[40..60] {
+            return Err(RakpError::AuthCodeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// The key exchange authentication code of RAKP message 3.
+    pub fn rakp_3_auth_code(&self) -> [u8; 20] {
+        hmac_sha1(
+            self.kuid,
+            &[
+                &self.bmc_random,
+                &self.console_id.to_le_bytes(),
+                &[self.role, self.username.len() as u8],
+                self.username,
+            ],
+        )
+    }
+
+    pub fn rakp_message_3(&self) -> Vec<u8> {
+        let mut data = vec![self.tag, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&self.bmc_id.to_le_bytes());
+        data.extend_from_slice(&self.rakp_3_auth_code());
+        data
+    }
+
+    /// The Session Integrity Key.
+    ///
+    /// The BMC key (K_G) is not supported, so the user key is used
+    /// in its place.
+    pub fn sik(&self) -> [u8; 20] {
+        hmac_sha1(
+            self.kuid,
+            &[
+                &self.console_random,
+                &self.bmc_random,
+                &[self.role, self.username.len() as u8],
+                self.username,
+            ],
+        )
+    }
+
+    /// The integrity check value of RAKP message 4.
+    pub fn rakp_4_check_value(&self) -> [u8; INTEGRITY_LEN] {
+        hmac_sha1(
+            &self.sik(),
+            &[
+                &self.console_random,
+                &self.bmc_id.to_le_bytes(),
+                &self.bmc_guid,
+            ],
+        )[..INTEGRITY_LEN]
+            .try_into()
+            .unwrap()
+    }
+
+    pub fn rakp_message_4(&self, data: &[u8]) -> Result<(), RakpError> {
+        check_status(data, 8 + INTEGRITY_LEN, self.tag)?;
+
+        if data[4..8] != self.console_id.to_le_bytes() {
+            return Err(RakpError::Mismatch);
+        }
+
+        if self.rakp_4_check_value() != data[8..8 + INTEGRITY_LEN] {
+            return Err(RakpError::AuthCodeMismatch);
+        }
+
+        Ok(())
+    }
+}
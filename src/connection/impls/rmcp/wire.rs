@@ -52,20 +52,15 @@ pub fn checksum(data: impl IntoIterator<Item = u8>) -> impl Iterator<Item = u8>
     }
 }
 
-pub fn send(
-    inner: &mut UdpSocket,
-    auth_type: auth::AuthType,
+/// Encode `request` as an IPMI LAN message, which is the payload of
+/// both IPMI v1.5 and RMCP+ sessions.
+pub fn encode_message(
     requestor_addr: u8,
     responder_addr: u8,
     ipmb_sequence: &mut u8,
     requestor_lun: LogicalUnit,
-    request_sequence: &mut u32,
-    session_id: Option<NonZeroU32>,
-    password: &[u8; 16],
-    request: &mut Request,
-) -> std::io::Result<usize> {
-    log::trace!("Sending message with auth type {:?}", auth_type);
-
+    request: &Request,
+) -> Vec<u8> {
     let rs_addr = responder_addr;
     let netfn_rslun: u8 = (request.netfn().request_value() << 2) | request.target().lun().value();
 
@@ -85,7 +80,60 @@ pub fn send(
             .chain(request.data().iter().map(|v| *v)),
     );
 
-    let final_data: Vec<_> = first_part.chain(second_part).collect();
+    first_part.chain(second_part).collect()
+}
+
+/// Decode an IPMI LAN message that contains a response.
+pub fn decode_response(data: &[u8]) -> Result<Response, Error> {
+    if data.len() < 7 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Incomplete IPMI message",
+        ));
+    }
+
+    let _req_addr = data[0];
+    let netfn = data[1] >> 2;
+    let _checksum1 = data[2];
+    let _rs_addr = data[3];
+    let _rqseq = data[4];
+    let cmd = data[5];
+    let response_data: Vec<_> = data[6..data.len() - 1].iter().map(|v| *v).collect();
+    let _checksum2 = data[data.len() - 1];
+
+    // TODO: validate sequence, checksums, etc.
+
+    let response = if let Some(resp) = Response::new(Message::new_raw(netfn, cmd, response_data), 0)
+    {
+        resp
+    } else {
+        return Err(Error::new(ErrorKind::Other, "Response data was empty"));
+    };
+
+    Ok(response)
+}
+
+pub fn send(
+    inner: &mut UdpSocket,
+    auth_type: auth::AuthType,
+    requestor_addr: u8,
+    responder_addr: u8,
+    ipmb_sequence: &mut u8,
+    requestor_lun: LogicalUnit,
+    request_sequence: &mut u32,
+    session_id: Option<NonZeroU32>,
+    password: &[u8; 16],
+    request: &mut Request,
+) -> std::io::Result<usize> {
+    log::trace!("Sending message with auth type {:?}", auth_type);
+
+    let final_data = encode_message(
+        requestor_addr,
+        responder_addr,
+        ipmb_sequence,
+        requestor_lun,
+        request,
+    );
 
     let session_sequence = *request_sequence;
 
@@ -142,27 +190,7 @@ pub fn recv(inner: &mut UdpSocket) -> Result<Response, Error> {
         ));
     };
 
-    let data = encapsulated_message.payload;
-
-    let _req_addr = data[0];
-    let netfn = data[1] >> 2;
-    let _checksum1 = data[2];
-    let _rs_addr = data[3];
-    let _rqseq = data[4];
-    let cmd = data[5];
-    let response_data: Vec<_> = data[6..data.len() - 1].iter().map(|v| *v).collect();
-    let _checksum2 = data[data.len() - 1];
-
-    // TODO: validate sequence, checksums, etc.
-
-    let response = if let Some(resp) = Response::new(Message::new_raw(netfn, cmd, response_data), 0)
-    {
-        resp
-    } else {
-        return Err(Error::new(ErrorKind::Other, "Response data was empty"));
-    };
-
-    Ok(response)
+    decode_response(&encapsulated_message.payload)
 }
 
 #[test]
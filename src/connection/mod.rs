@@ -11,13 +11,18 @@ pub use impls::rmcp;
 mod netfn;
 pub use netfn::NetFn;
 
+mod parameter;
+pub use parameter::{
+    ConfigurationParameter, GetConfigurationParameter, RawParameter, SetConfigurationParameter,
+};
+
 mod request;
 pub use request::{Address, Channel, Request, RequestTargetAddress};
 
 mod response;
 pub use response::Response;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum LogicalUnit {
     Zero,
     One,
@@ -0,0 +1,157 @@
+use core::marker::PhantomData;
+
+use super::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// A configuration parameter, as used by the Get/Set Configuration
+/// Parameters commands of PEF, LAN and SOL.
+pub trait ConfigurationParameter: Sized {
+    /// The selector that identifies a parameter.
+    type Selector: Copy + Into<u8>;
+
+    /// The network function of the Get and Set commands.
+    const NETFN: NetFn;
+    /// The command number of the Get command.
+    const GET_CMD: u8;
+    /// The command number of the Set command.
+    const SET_CMD: u8;
+
+    /// The selector of this parameter.
+    fn selector(&self) -> Self::Selector;
+
+    /// Parse `data` as the parameter selected by `selector`.
+    fn parse(selector: Self::Selector, data: &[u8]) -> Option<Self>;
+
+    /// Write the data of this parameter, without the selector.
+    fn write_data(&self, buffer: &mut Vec<u8>);
+}
+
+/// The data of a configuration parameter, as returned by
+/// [`GetConfigurationParameter`].
+///
+/// The response does not contain the parameter selector, so the data
+/// is parsed using the selector of the request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawParameter<P> {
+    /// The revision of the parameter.
+    pub revision: u8,
+    pub data: Vec<u8>,
+    _parameter: PhantomData<P>,
+}
+
+impl<P: ConfigurationParameter> RawParameter<P> {
+    pub fn new(revision: u8, data: Vec<u8>) -> Self {
+        Self {
+            revision,
+            data,
+            _parameter: PhantomData,
+        }
+    }
+
+    /// Parse the data as the parameter selected by `selector`.
+    ///
+    /// Returns `None` if the data is too short, or uses a format that
+    /// is not supported.
+    pub fn parse(&self, selector: P::Selector) -> Option<P> {
+        P::parse(selector, &self.data)
+    }
+}
+
+/// Get a configuration parameter.
+pub struct GetConfigurationParameter<P: ConfigurationParameter> {
+    channel: Option<u8>,
+    selector: P::Selector,
+    set_selector: u8,
+    block_selector: u8,
+}
+
+impl<P: ConfigurationParameter> GetConfigurationParameter<P> {
+    /// Get the parameter selected by `selector`, of `channel` if the
+    /// command addresses a channel.
+    pub(crate) fn request(
+        channel: Option<u8>,
+        selector: P::Selector,
+        set_selector: u8,
+        block_selector: u8,
+    ) -> Self {
+        Self {
+            channel,
+            selector,
+            set_selector,
+            block_selector,
+        }
+    }
+
+    /// The selector of the requested parameter.
+    pub fn selector(&self) -> P::Selector {
+        self.selector
+    }
+}
+
+impl<P: ConfigurationParameter> From<GetConfigurationParameter<P>> for Message {
+    fn from(value: GetConfigurationParameter<P>) -> Self {
+        let mut data: Vec<u8> = value.channel.into_iter().collect();
+        data.extend([
+            value.selector.into(),
+            value.set_selector,
+            value.block_selector,
+        ]);
+
+        Message::new_request(P::NETFN, P::GET_CMD, data)
+    }
+}
+
+impl<P: ConfigurationParameter> IpmiCommand for GetConfigurationParameter<P> {
+    type Output = RawParameter<P>;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(RawParameter::new(data[0], data[1..].to_vec()))
+    }
+}
+
+/// Set a configuration parameter.
+pub struct SetConfigurationParameter<P: ConfigurationParameter> {
+    channel: Option<u8>,
+    parameter: P,
+}
+
+impl<P: ConfigurationParameter> SetConfigurationParameter<P> {
+    /// Set `parameter`, of `channel` if the command addresses a channel.
+    pub(crate) fn request(channel: Option<u8>, parameter: P) -> Self {
+        Self { channel, parameter }
+    }
+}
+
+impl<P: ConfigurationParameter> From<SetConfigurationParameter<P>> for Message {
+    fn from(value: SetConfigurationParameter<P>) -> Self {
+        let mut data: Vec<u8> = value.channel.into_iter().collect();
+        data.push(value.parameter.selector().into());
+        value.parameter.write_data(&mut data);
+
+        Message::new_request(P::NETFN, P::SET_CMD, data)
+    }
+}
+
+impl<P: ConfigurationParameter> IpmiCommand for SetConfigurationParameter<P> {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
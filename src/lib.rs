@@ -7,6 +7,8 @@ pub use storage::sdr::record::SensorRecord;
 
 pub mod sensor_event;
 
+pub mod transport;
+
 #[macro_use]
 mod fmt;
 #[cfg(test)]
@@ -14,10 +16,17 @@ mod tests;
 
 pub use fmt::{LogOutput, Loggable, Logger};
 
+use std::{collections::HashSet, num::NonZeroU16};
+
 use connection::{
-    IpmiCommand, LogicalUnit, NetFn, ParseResponseError, Request, RequestTargetAddress,
+    CompletionCode, IpmiCommand, LogicalUnit, NetFn, ParseResponseError, Request,
+    RequestTargetAddress,
+};
+use nonmax::NonMaxU8;
+use storage::{
+    sdr::{self, record::Record as SdrRecord},
+    sel,
 };
-use storage::sdr::{self, record::Record as SdrRecord};
 
 pub struct Ipmi<CON> {
     inner: CON,
@@ -65,6 +74,20 @@ impl<CON, P> From<CON> for IpmiError<CON, P> {
 
 pub type IpmiCommandError<T, E> = IpmiError<T, ParseResponseError<E>>;
 
+impl<CON, E> IpmiCommandError<CON, E> {
+    /// Get the completion code of the response, if this error
+    /// occured because the command was not completed succesfully.
+    pub fn completion_code(&self) -> Option<CompletionCode> {
+        match self {
+            IpmiError::ParsingFailed {
+                error: ParseResponseError::Failed(cc),
+                ..
+            } => Some(*cc),
+            _ => None,
+        }
+    }
+}
+
 impl<CON> Ipmi<CON>
 where
     CON: connection::IpmiConnection,
@@ -81,7 +104,85 @@ where
         SdrIter {
             ipmi: self,
             next_id: Some(sdr::RecordId::FIRST),
+            reservation_id: None,
+            partial_read_size: None,
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Read the raw data of all SDR records.
+    ///
+    /// Unlike [`Ipmi::sdrs`], this fails if any of the records
+    /// cannot be read. Records that cannot be parsed are included.
+    pub fn sdr_dump(&mut self) -> Result<sdr::SdrDump, SdrIterError<CON::Error>> {
+        let mut dump = sdr::SdrDump::new();
+        let mut iter = self.sdrs();
+
+        while let Some(raw) = iter.next_raw() {
+            let RawSdrRecord {
+                record_id,
+                next_entry,
+                data,
+            } = raw?;
+
+            if !dump.push(data.clone()) {
+                return Err(SdrIterError::InvalidRecord {
+                    record_id,
+                    next_entry,
+                    data,
+                });
+            }
+        }
+
+        Ok(dump)
+    }
+
+    /// Get information about all channels, `0` through `0xB`.
+    ///
+    /// Channels for which the BMC reports an error, which it does for
+    /// channels that are not implemented, are skipped.
+    pub fn channels(
+        &mut self,
+    ) -> Result<Vec<app::channel::ChannelInfo>, IpmiCommandError<CON::Error, ()>> {
+        let mut channels = Vec::new();
+
+        for number in 0..=0xB {
+            let request = app::channel::GetChannelInfo::new(app::auth::Channel::Number(number));
+
+            match self.send_recv(request) {
+                Ok(info) => channels.push(info),
+                Err(e) if e.completion_code().is_some() => continue,
+                Err(e) => return Err(e),
+            }
         }
+
+        Ok(channels)
+    }
+
+    /// Iterate over all SEL entries, starting with the oldest entry.
+    pub fn sel_entries(&mut self) -> SelIter<'_, CON> {
+        SelIter::new(self, sel::RecordId::FIRST)
+    }
+
+    /// Iterate over all SEL entries, starting with the newest entry.
+    ///
+    /// The SEL can only be traversed from oldest to newest entry, so all
+    /// entries are read before the first item is returned.
+    pub fn sel_entries_rev(&mut self) -> SelIter<'_, CON> {
+        let mut iter = SelIter::new(self, sel::RecordId::FIRST);
+        iter.reversed = Some(Vec::new());
+        iter
+    }
+
+    /// Iterate over all SEL entries that were added after the entry with
+    /// ID `record_id`, which is not returned itself.
+    ///
+    /// If the entry with ID `record_id` no longer exists, for instance because
+    /// the SEL was cleared, iteration starts at the oldest entry instead.
+    pub fn sel_entries_after(&mut self, record_id: sel::RecordId) -> SelIter<'_, CON> {
+        let mut iter = SelIter::new(self, record_id);
+        iter.resume_after = Some(record_id);
+        iter
     }
 
     pub fn send_recv<CMD>(
@@ -122,33 +223,492 @@ where
     }
 }
 
+/// An iterator over the SDR records of a device.
+///
+/// Every item is either a record, or an error describing why
+/// reading a record failed. If the failing record could be read but
+/// not parsed, iteration continues with the next record. All other
+/// errors end the iteration.
 pub struct SdrIter<'ipmi, CON> {
     ipmi: &'ipmi mut Ipmi<CON>,
     next_id: Option<sdr::RecordId>,
+    reservation_id: Option<NonZeroU16>,
+    /// The amount of bytes to read per request, if records
+    /// must be read using partial reads.
+    partial_read_size: Option<u8>,
+    /// The IDs of all records that have been read so far, used
+    /// to detect a repository that links back to an earlier record.
+    visited: HashSet<sdr::RecordId>,
+}
+
+/// The raw data of a record, including its header.
+struct RawSdrRecord {
+    record_id: sdr::RecordId,
+    next_entry: sdr::RecordId,
+    data: Vec<u8>,
+}
+
+/// An error that occured while iterating over SDR records.
+#[derive(Debug)]
+pub enum SdrIterError<CON> {
+    /// Sending a command, or parsing its response, failed.
+    Command {
+        record_id: sdr::RecordId,
+        error: IpmiCommandError<CON, ()>,
+    },
+    /// The record was read succesfully, but could not be parsed.
+    InvalidRecord {
+        record_id: sdr::RecordId,
+        next_entry: sdr::RecordId,
+        data: Vec<u8>,
+    },
+    /// The repository links back to record `record_id`, which
+    /// was already read.
+    Loop { record_id: sdr::RecordId },
+}
+
+impl<CON> SdrIterError<CON> {
+    /// The ID of the record that could not be read.
+    pub fn record_id(&self) -> sdr::RecordId {
+        match self {
+            SdrIterError::Command { record_id, .. }
+            | SdrIterError::InvalidRecord { record_id, .. }
+            | SdrIterError::Loop { record_id } => *record_id,
+        }
+    }
+}
+
+impl<T> SdrIter<'_, T>
+where
+    T: connection::IpmiConnection,
+{
+    const INITIAL_PARTIAL_READ_SIZE: u8 = 32;
+    const MIN_PARTIAL_READ_SIZE: u8 = 4;
+    const MAX_RESERVATION_ATTEMPTS: usize = 8;
+
+    fn read_record(
+        &mut self,
+        record_id: sdr::RecordId,
+    ) -> Result<sdr::PartialSdrRecordInfo, SdrIterError<T::Error>> {
+        if self.partial_read_size.is_none() {
+            match self
+                .ipmi
+                .send_recv(sdr::GetDeviceSdrPartial::whole_record(record_id))
+            {
+                Ok(record) => return Ok(record),
+                Err(e)
+                    if e.completion_code()
+                        == Some(CompletionCode::CannotReturnNumOfRequestedBytes) =>
+                {
+                    log::debug!("BMC cannot return full SDRs, falling back to partial reads.");
+                    self.partial_read_size = Some(Self::INITIAL_PARTIAL_READ_SIZE);
+                }
+                Err(error) => return Err(SdrIterError::Command { record_id, error }),
+            }
+        }
+
+        self.read_record_partial(record_id)
+    }
+
+    fn read_record_partial(
+        &mut self,
+        record_id: sdr::RecordId,
+    ) -> Result<sdr::PartialSdrRecordInfo, SdrIterError<T::Error>> {
+        const HEADER_LEN: usize = 5;
+
+        let command_error = |error| SdrIterError::Command { record_id, error };
+
+        let mut reservation_attempts = 0;
+
+        'record: loop {
+            let reservation_id = match self.reservation_id {
+                Some(reservation_id) => reservation_id,
+                None => {
+                    reservation_attempts += 1;
+                    let reservation_id = self
+                        .ipmi
+                        .send_recv(sdr::ReserveSdrRepository)
+                        .map_err(command_error)?;
+                    self.reservation_id = Some(reservation_id);
+                    reservation_id
+                }
+            };
+
+            let mut next_entry = sdr::RecordId::LAST;
+            let mut data = Vec::new();
+            let mut record_len = HEADER_LEN;
+
+            while data.len() < record_len {
+                let invalid_record = |next_entry, data: Vec<u8>| SdrIterError::InvalidRecord {
+                    record_id,
+                    next_entry,
+                    data,
+                };
+
+                let offset = match u8::try_from(data.len()) {
+                    Ok(offset) => offset,
+                    Err(_) => return Err(invalid_record(next_entry, data)),
+                };
+
+                let read_size = self
+                    .partial_read_size
+                    .unwrap_or(Self::INITIAL_PARTIAL_READ_SIZE)
+                    .min((record_len - data.len()) as u8);
+
+                let bytes_to_read = NonMaxU8::new(read_size).unwrap();
+                let request =
+                    sdr::GetDeviceSdrPartial::new(reservation_id, record_id, offset, bytes_to_read);
+
+                match self.ipmi.send_recv(request) {
+                    Ok(partial) if partial.data.is_empty() => {
+                        return Err(invalid_record(partial.next_entry, data))
+                    }
+                    Ok(partial) => {
+                        next_entry = partial.next_entry;
+                        data.extend_from_slice(&partial.data);
+
+                        if data.len() >= HEADER_LEN {
+                            record_len = HEADER_LEN + data[4] as usize;
+                        }
+                    }
+                    Err(e) => match e.completion_code() {
+                        Some(CompletionCode::ReservationCancelledOrInvalidId)
+                            if reservation_attempts < Self::MAX_RESERVATION_ATTEMPTS =>
+                        {
+                            log::debug!(
+                                "SDR reservation was cancelled, restarting read of record."
+                            );
+                            self.reservation_id.take();
+                            continue 'record;
+                        }
+                        Some(CompletionCode::CannotReturnNumOfRequestedBytes)
+                            if read_size > Self::MIN_PARTIAL_READ_SIZE =>
+                        {
+                            let new_read_size = (read_size / 2).max(Self::MIN_PARTIAL_READ_SIZE);
+                            log::debug!("Reducing partial SDR read size to {new_read_size} bytes.");
+                            self.partial_read_size = Some(new_read_size);
+                        }
+                        _ => return Err(command_error(e)),
+                    },
+                }
+            }
+
+            data.truncate(record_len);
+
+            return Ok(sdr::PartialSdrRecordInfo { next_entry, data });
+        }
+    }
+
+    /// Read the raw data of the next record.
+    fn next_raw(&mut self) -> Option<Result<RawSdrRecord, SdrIterError<T::Error>>> {
+        let record_id = self.next_id.take()?;
+
+        if !self.visited.insert(record_id) {
+            return Some(Err(SdrIterError::Loop { record_id }));
+        }
+
+        let info = match self.read_record(record_id) {
+            Ok(info) => info,
+            Err(e) => {
+                // Skip records that could not be read completely, if possible.
+                if let SdrIterError::InvalidRecord { next_entry, .. } = &e {
+                    self.next_id = Some(*next_entry).filter(|id| !id.is_last());
+                }
+
+                return Some(Err(e));
+            }
+        };
+
+        // The first record is requested using a placeholder ID,
+        // so also remember the actual ID of the record.
+        if let [low, high, ..] = info.data[..] {
+            let actual_id = sdr::RecordId::new_raw(u16::from_le_bytes([low, high]));
+            self.visited.insert(actual_id);
+        }
+
+        if !info.next_entry.is_last() {
+            self.next_id = Some(info.next_entry);
+        }
+
+        Some(Ok(RawSdrRecord {
+            record_id,
+            next_entry: info.next_entry,
+            data: info.data,
+        }))
+    }
 }
 
 impl<T> Iterator for SdrIter<'_, T>
 where
     T: connection::IpmiConnection,
 {
-    type Item = SdrRecord;
+    type Item = Result<SdrRecord, SdrIterError<T::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let RawSdrRecord {
+            record_id,
+            next_entry,
+            data,
+        } = match self.next_raw()? {
+            Ok(raw) => raw,
+            Err(e) => return Some(Err(e)),
+        };
+
+        match sdr::Record::parse(&data) {
+            Some(record) => Some(Ok(record)),
+            None => Some(Err(SdrIterError::InvalidRecord {
+                record_id,
+                next_entry,
+                data,
+            })),
+        }
+    }
+}
+
+/// An iterator over the entries of the SEL.
+///
+/// Every item is either an entry, or an error describing why reading
+/// an entry failed. If the failing entry could be read but not parsed,
+/// iteration continues with the next entry. All other errors end the
+/// iteration.
+pub struct SelIter<'ipmi, CON>
+where
+    CON: connection::IpmiConnection,
+{
+    ipmi: &'ipmi mut Ipmi<CON>,
+    next_id: Option<sel::RecordId>,
+    reservation_id: Option<NonZeroU16>,
+    /// Whether entries must be read using partial reads.
+    partial_reads: bool,
+    /// The IDs of all entries that have been read so far, used to
+    /// detect a SEL that links back to an earlier entry.
+    visited: HashSet<sel::RecordId>,
+    /// The entry after which iteration should start.
+    resume_after: Option<sel::RecordId>,
+    /// All items, in reverse order, if iterating from newest to oldest.
+    reversed: Option<Vec<Result<sel::Entry, SelIterError<CON::Error>>>>,
+}
+
+/// An error that occured while iterating over SEL entries.
+#[derive(Debug)]
+pub enum SelIterError<CON> {
+    /// Sending a command, or parsing its response, failed.
+    Command {
+        record_id: sel::RecordId,
+        error: IpmiCommandError<CON, ()>,
+    },
+    /// The entry was read succesfully, but could not be parsed.
+    InvalidEntry {
+        record_id: sel::RecordId,
+        next_entry: sel::RecordId,
+        data: Vec<u8>,
+        error: sel::ParseEntryError,
+    },
+    /// The SEL links back to entry `record_id`, which was already read.
+    Loop { record_id: sel::RecordId },
+}
+
+impl<CON> SelIterError<CON> {
+    /// The ID of the entry that could not be read.
+    pub fn record_id(&self) -> sel::RecordId {
+        match self {
+            SelIterError::Command { record_id, .. }
+            | SelIterError::InvalidEntry { record_id, .. }
+            | SelIterError::Loop { record_id } => *record_id,
+        }
+    }
+}
+
+impl<'ipmi, T> SelIter<'ipmi, T>
+where
+    T: connection::IpmiConnection,
+{
+    const PARTIAL_READ_SIZE: u8 = 8;
+    const MAX_RESERVATION_ATTEMPTS: usize = 8;
+
+    fn new(ipmi: &'ipmi mut Ipmi<T>, first: sel::RecordId) -> Self {
+        Self {
+            ipmi,
+            next_id: Some(first),
+            reservation_id: None,
+            partial_reads: false,
+            visited: HashSet::new(),
+            resume_after: None,
+            reversed: None,
+        }
+    }
+
+    fn read_entry(
+        &mut self,
+        record_id: sel::RecordId,
+    ) -> Result<sel::PartialSelEntryInfo, SelIterError<T::Error>> {
+        if !self.partial_reads {
+            match self
+                .ipmi
+                .send_recv(sel::GetSelEntryPartial::whole_entry(record_id))
+            {
+                Ok(entry) => return Ok(entry),
+                Err(e)
+                    if e.completion_code()
+                        == Some(CompletionCode::CannotReturnNumOfRequestedBytes) =>
+                {
+                    log::debug!(
+                        "BMC cannot return full SEL entries, falling back to partial reads."
+                    );
+                    self.partial_reads = true;
+                }
+                Err(error) => return Err(SelIterError::Command { record_id, error }),
+            }
+        }
+
+        self.read_entry_partial(record_id)
+    }
+
+    fn read_entry_partial(
+        &mut self,
+        record_id: sel::RecordId,
+    ) -> Result<sel::PartialSelEntryInfo, SelIterError<T::Error>> {
+        let command_error = |error| SelIterError::Command { record_id, error };
+
+        let mut reservation_attempts = 0;
+
+        'entry: loop {
+            let reservation_id = match self.reservation_id {
+                Some(reservation_id) => reservation_id,
+                None => {
+                    reservation_attempts += 1;
+                    let reservation_id = self
+                        .ipmi
+                        .send_recv(sel::ReserveSel)
+                        .map_err(command_error)?;
+                    self.reservation_id = Some(reservation_id);
+                    reservation_id
+                }
+            };
+
+            let mut next_entry = sel::RecordId::LAST;
+            let mut data = Vec::new();
+
+            while data.len() < sel::Entry::LEN {
+                let offset = data.len() as u8;
+                let read_size = Self::PARTIAL_READ_SIZE.min((sel::Entry::LEN - data.len()) as u8);
+
+                let bytes_to_read = NonMaxU8::new(read_size).unwrap();
+                let request =
+                    sel::GetSelEntryPartial::new(reservation_id, record_id, offset, bytes_to_read);
+
+                match self.ipmi.send_recv(request) {
+                    // Return the incomplete entry, so that it is reported as invalid.
+                    Ok(partial) if partial.data.is_empty() => {
+                        return Ok(sel::PartialSelEntryInfo {
+                            next_entry: partial.next_entry,
+                            data,
+                        })
+                    }
+                    Ok(partial) => {
+                        next_entry = partial.next_entry;
+                        data.extend_from_slice(&partial.data);
+                    }
+                    Err(e) => match e.completion_code() {
+                        Some(CompletionCode::ReservationCancelledOrInvalidId)
+                            if reservation_attempts < Self::MAX_RESERVATION_ATTEMPTS =>
+                        {
+                            log::debug!("SEL reservation was cancelled, restarting read of entry.");
+                            self.reservation_id.take();
+                            continue 'entry;
+                        }
+                        _ => return Err(command_error(e)),
+                    },
+                }
+            }
+
+            data.truncate(sel::Entry::LEN);
+
+            return Ok(sel::PartialSelEntryInfo { next_entry, data });
+        }
+    }
+
+    /// Find the ID of the entry after `record_id`, or the first entry if
+    /// `record_id` no longer exists.
+    fn resume(&mut self, record_id: sel::RecordId) -> Result<(), SelIterError<T::Error>> {
+        self.next_id = match self.read_entry(record_id) {
+            Ok(info) => {
+                self.visited.insert(record_id);
+                Some(info.next_entry).filter(|id| !id.is_last())
+            }
+            Err(SelIterError::Command { error, .. })
+                if error.completion_code()
+                    == Some(CompletionCode::RequestedDatapointNotPresent) =>
+            {
+                log::debug!(
+                    "SEL entry 0x{:04X} no longer exists, starting at first entry.",
+                    record_id.value()
+                );
+                Some(sel::RecordId::FIRST)
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(())
+    }
+
+    fn next_forward(&mut self) -> Option<Result<sel::Entry, SelIterError<T::Error>>> {
+        if let Some(record_id) = self.resume_after.take() {
+            if let Err(e) = self.resume(record_id) {
+                return Some(Err(e));
+            }
+        }
+
+        let record_id = self.next_id.take()?;
+
+        if !self.visited.insert(record_id) {
+            return Some(Err(SelIterError::Loop { record_id }));
+        }
+
+        let info = match self.read_entry(record_id) {
+            Ok(info) => info,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if !info.next_entry.is_last() {
+            self.next_id = Some(info.next_entry);
+        }
+
+        match sel::Entry::parse(&info.data) {
+            Ok(entry) => {
+                // The first entry is requested using a placeholder ID,
+                // so also remember the actual ID of the entry.
+                self.visited.insert(entry.record_id());
+                Some(Ok(entry))
+            }
+            Err(error) => Some(Err(SelIterError::InvalidEntry {
+                record_id,
+                next_entry: info.next_entry,
+                data: info.data,
+                error,
+            })),
+        }
+    }
+}
+
+impl<T> Iterator for SelIter<'_, T>
+where
+    T: connection::IpmiConnection,
+{
+    type Item = Result<sel::Entry, SelIterError<T::Error>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_id = self.next_id?;
-        let next_record = self
-            .ipmi
-            .send_recv(sdr::GetDeviceSdr::new(None, next_id))
-            .map_err(|e| {
-                log::error!("Error occured while iterating SDR records: {e:?}");
-            })
-            .ok()?;
-
-        if !next_record.next_entry.is_last() {
-            self.next_id = Some(next_record.next_entry);
-        } else {
-            self.next_id.take();
-        }
-
-        Some(next_record.record)
+        if self.reversed.is_none() {
+            return self.next_forward();
+        }
+
+        // Read all entries if that has not happened yet.
+        if self.next_id.is_some() {
+            while let Some(item) = self.next_forward() {
+                self.reversed.as_mut().unwrap().push(item);
+            }
+        }
+
+        self.reversed.as_mut()?.pop()
     }
 }
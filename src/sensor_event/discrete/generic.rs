@@ -0,0 +1,151 @@
+//! States of the generic discrete event/reading types (`0x02` through `0x0C`).
+
+use crate::{
+    sensor_event::{FromSensorReading, RawSensorReading},
+    storage::sdr::event_reading_type_code as codes,
+};
+
+use super::{DiscreteReading, DiscreteStates};
+
+discrete_states! {
+    /// States of the DMI-based usage state event/reading type (`0x02`).
+    UsageState {
+        0x00 => Idle: "Transition to Idle",
+        0x01 => Active: "Transition to Active",
+        0x02 => Busy: "Transition to Busy",
+    }
+
+    /// States of the digital discrete event/reading type (`0x03`).
+    StateAssertionState {
+        0x00 => Deasserted: "State Deasserted",
+        0x01 => Asserted: "State Asserted",
+    }
+
+    /// States of the predictive failure event/reading type (`0x04`).
+    PredictiveFailureState {
+        0x00 => Deasserted: "Predictive Failure deasserted",
+        0x01 => Asserted: "Predictive Failure asserted",
+    }
+
+    /// States of the limit event/reading type (`0x05`).
+    LimitState {
+        0x00 => NotExceeded: "Limit Not Exceeded",
+        0x01 => Exceeded: "Limit Exceeded",
+    }
+
+    /// States of the performance event/reading type (`0x06`).
+    PerformanceState {
+        0x00 => Met: "Performance Met",
+        0x01 => Lags: "Performance Lags",
+    }
+
+    /// States of the severity event/reading type (`0x07`).
+    SeverityState {
+        0x00 => Ok: "Transition to OK",
+        0x01 => NonCriticalFromOk: "Transition to Non-critical from OK",
+        0x02 => CriticalFromLessSevere: "Transition to Critical from less severe",
+        0x03 => NonRecoverableFromLessSevere: "Transition to Non-recoverable from less severe",
+        0x04 => NonCriticalFromMoreSevere: "Transition to Non-critical from more severe",
+        0x05 => CriticalFromNonRecoverable: "Transition to Critical from Non-recoverable",
+        0x06 => NonRecoverable: "Transition to Non-recoverable",
+        0x07 => Monitor: "Monitor",
+        0x08 => Informational: "Informational",
+    }
+
+    /// States of the device presence event/reading type (`0x08`).
+    DevicePresenceState {
+        0x00 => Absent: "Device Absent",
+        0x01 => Present: "Device Present",
+    }
+
+    /// States of the device enabled event/reading type (`0x09`).
+    DeviceEnabledState {
+        0x00 => Disabled: "Device Disabled",
+        0x01 => Enabled: "Device Enabled",
+    }
+
+    /// States of the availability event/reading type (`0x0A`).
+    AvailabilityState {
+        0x00 => Running: "Transition to Running",
+        0x01 => InTest: "Transition to In Test",
+        0x02 => PowerOff: "Transition to Power Off",
+        0x03 => OnLine: "Transition to On Line",
+        0x04 => OffLine: "Transition to Off Line",
+        0x05 => OffDuty: "Transition to Off Duty",
+        0x06 => Degraded: "Transition to Degraded",
+        0x07 => PowerSave: "Transition to Power Save",
+        0x08 => InstallError: "Install Error",
+    }
+
+    /// States of the redundancy event/reading type (`0x0B`).
+    RedundancyState {
+        0x00 => FullyRedundant: "Fully Redundant",
+        0x01 => RedundancyLost: "Redundancy Lost",
+        0x02 => RedundancyDegraded: "Redundancy Degraded",
+        0x03 => NonRedundantSufficientFromRedundant:
+            "Non-redundant: Sufficient Resources from Redundant",
+        0x04 => NonRedundantSufficientFromInsufficient:
+            "Non-redundant: Sufficient Resources from Insufficient Resources",
+        0x05 => NonRedundantInsufficient: "Non-redundant: Insufficient Resources",
+        0x06 => DegradedFromFullyRedundant: "Redundancy Degraded from Fully Redundant",
+        0x07 => DegradedFromNonRedundant: "Redundancy Degraded from Non-redundant",
+    }
+
+    /// States of the ACPI device power state event/reading type (`0x0C`).
+    AcpiDevicePowerState {
+        0x00 => D0: "D0 Power State",
+        0x01 => D1: "D1 Power State",
+        0x02 => D2: "D2 Power State",
+        0x03 => D3: "D3 Power State",
+    }
+}
+
+state_kinds! {
+    /// A state of a sensor with a generic discrete event/reading type,
+    /// selected by its event/reading type code.
+    GenericState(u8) {
+        0x02 => Usage(UsageState),
+        0x03 => StateAssertion(StateAssertionState),
+        0x04 => PredictiveFailure(PredictiveFailureState),
+        0x05 => Limit(LimitState),
+        0x06 => Performance(PerformanceState),
+        0x07 => Severity(SeverityState),
+        0x08 => DevicePresence(DevicePresenceState),
+        0x09 => DeviceEnabled(DeviceEnabledState),
+        0x0A => Availability(AvailabilityState),
+        0x0B => Redundancy(RedundancyState),
+        0x0C => AcpiDevicePower(AcpiDevicePowerState),
+    }
+}
+
+macro_rules! from_sensor_reading {
+    ($($code:ty => $state:ty,)*) => {
+        $(
+            impl FromSensorReading for DiscreteStates<$state> {
+                type Sensor = $code;
+
+                /// The asserted states. If the state of the sensor is
+                /// unavailable, no states are asserted.
+                fn from(_: &Self::Sensor, reading: &RawSensorReading) -> Self {
+                    DiscreteReading::from(reading)
+                        .states()
+                        .unwrap_or(DiscreteStates::from_bits(0))
+                }
+            }
+        )*
+    };
+}
+
+from_sensor_reading! {
+    codes::UsageState => UsageState,
+    codes::StateAssertion => StateAssertionState,
+    codes::PredictiveFailure => PredictiveFailureState,
+    codes::LimitExcess => LimitState,
+    codes::PerformanceMetric => PerformanceState,
+    codes::SeverityEvents => SeverityState,
+    codes::DevicePresence => DevicePresenceState,
+    codes::DeviceEnabledStatus => DeviceEnabledState,
+    codes::PowerState => AvailabilityState,
+    codes::RedundancyState => RedundancyState,
+    codes::AcpiDevicePowerState => AcpiDevicePowerState,
+}
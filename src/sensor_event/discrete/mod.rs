@@ -0,0 +1,250 @@
+//! Typed states of discrete sensors.
+//!
+//! Discrete sensors report up to 15 states, identified by their offset.
+//! The meaning of each offset is defined by the event/reading type code
+//! of the sensor, or by its sensor type for sensor-specific sensors.
+
+use std::marker::PhantomData;
+
+use crate::storage::sdr::SensorType;
+
+use super::RawSensorReading;
+
+/// A state of a discrete sensor.
+pub trait DiscreteState: Sized + Copy {
+    /// Get the state identified by `offset`.
+    fn from_offset(offset: u8) -> Option<Self>;
+
+    /// The offset of this state.
+    fn offset(&self) -> u8;
+
+    /// A human-readable description of this state.
+    fn description(&self) -> &'static str;
+}
+
+macro_rules! discrete_states {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident {
+            $($offset:literal => $variant:ident: $description:literal,)*
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum $name {
+                $($variant,)*
+            }
+
+            impl $crate::sensor_event::discrete::DiscreteState for $name {
+                fn from_offset(offset: u8) -> Option<Self> {
+                    match offset {
+                        $($offset => Some(Self::$variant),)*
+                        _ => None,
+                    }
+                }
+
+                fn offset(&self) -> u8 {
+                    match self {
+                        $(Self::$variant => $offset,)*
+                    }
+                }
+
+                fn description(&self) -> &'static str {
+                    match self {
+                        $(Self::$variant => $description,)*
+                    }
+                }
+            }
+
+            impl core::fmt::Display for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    use $crate::sensor_event::discrete::DiscreteState;
+                    write!(f, "{}", self.description())
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `From<$inner> for $outer`, and a `new` and
+/// `description` for `$outer`, which wraps the states of several
+/// different state types.
+macro_rules! state_kinds {
+    (
+        $(#[$meta:meta])*
+        $outer:ident($selector:ty) {
+            $($pattern:pat => $variant:ident($inner:ty),)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $outer {
+            $($variant($inner),)*
+        }
+
+        impl $outer {
+            /// Get the state at `offset` for sensors of kind `kind`.
+            pub fn new(kind: $selector, offset: u8) -> Option<Self> {
+                use $crate::sensor_event::discrete::DiscreteState;
+
+                match kind {
+                    $($pattern => <$inner>::from_offset(offset).map(Self::$variant),)*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+
+            /// The offset of this state.
+            pub fn offset(&self) -> u8 {
+                use $crate::sensor_event::discrete::DiscreteState;
+
+                match self {
+                    $(Self::$variant(state) => state.offset(),)*
+                }
+            }
+
+            /// A human-readable description of this state.
+            pub fn description(&self) -> &'static str {
+                use $crate::sensor_event::discrete::DiscreteState;
+
+                match self {
+                    $(Self::$variant(state) => state.description(),)*
+                }
+            }
+        }
+
+        impl core::fmt::Display for $outer {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.description())
+            }
+        }
+
+        $(
+            impl From<$inner> for $outer {
+                fn from(value: $inner) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+pub mod generic;
+pub use generic::GenericState;
+
+pub mod sensor_specific;
+pub use sensor_specific::SensorSpecificState;
+
+/// A set of states of a discrete sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscreteStates<S> {
+    bits: u16,
+    _state: PhantomData<S>,
+}
+
+impl<S> DiscreteStates<S>
+where
+    S: DiscreteState,
+{
+    /// Create a set of states from a mask, where bit N indicates
+    /// that the state at offset N is part of the set.
+    pub fn from_bits(bits: u16) -> Self {
+        Self {
+            bits: bits & 0x7FFF,
+            _state: PhantomData,
+        }
+    }
+
+    /// The mask of states in this set.
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Whether `state` is part of this set.
+    pub fn contains(&self, state: S) -> bool {
+        state.offset() < 15 && (self.bits >> state.offset()) & 0x1 == 0x1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// The states in this set.
+    ///
+    /// Offsets that do not correspond to a state of type `S` are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = S> + '_ {
+        (0..15)
+            .filter(|offset| (self.bits >> offset) & 0x1 == 0x1)
+            .filter_map(S::from_offset)
+    }
+}
+
+/// The reading of a discrete sensor.
+#[derive(Debug, Clone, Copy)]
+pub struct DiscreteReading {
+    pub all_event_messages_disabled: bool,
+    pub scanning_disabled: bool,
+    /// The mask of asserted states, where bit N indicates that the
+    /// state at offset N is asserted.
+    ///
+    /// This is `None` if the state of the sensor is unavailable.
+    pub asserted: Option<u16>,
+}
+
+impl From<&RawSensorReading> for DiscreteReading {
+    fn from(in_reading: &RawSensorReading) -> Self {
+        let asserted = if in_reading.reading_or_state_unavailable {
+            None
+        } else {
+            let low = in_reading.offset_data_1.unwrap_or(0) as u16;
+            let high = (in_reading.offset_data_2.unwrap_or(0) & 0x7F) as u16;
+            Some(low | high << 8)
+        };
+
+        Self {
+            all_event_messages_disabled: in_reading.all_event_messages_disabled,
+            scanning_disabled: in_reading.scanning_disabled,
+            asserted,
+        }
+    }
+}
+
+impl DiscreteReading {
+    /// The asserted states, interpreted as states of type `S`.
+    pub fn states<S>(&self) -> Option<DiscreteStates<S>>
+    where
+        S: DiscreteState,
+    {
+        self.asserted.map(DiscreteStates::from_bits)
+    }
+
+    /// The asserted states of a sensor with the generic
+    /// event/reading type code `event_reading_type_code`.
+    pub fn generic_states(&self, event_reading_type_code: u8) -> Option<Vec<GenericState>> {
+        let asserted = self.asserted?;
+
+        Some(
+            (0..15)
+                .filter(|offset| (asserted >> offset) & 0x1 == 0x1)
+                .filter_map(|offset| GenericState::new(event_reading_type_code, offset))
+                .collect(),
+        )
+    }
+
+    /// The asserted states of a sensor-specific sensor of
+    /// type `sensor_type`.
+    pub fn sensor_specific_states(
+        &self,
+        sensor_type: SensorType,
+    ) -> Option<Vec<SensorSpecificState>> {
+        let asserted = self.asserted?;
+
+        Some(
+            (0..15)
+                .filter(|offset| (asserted >> offset) & 0x1 == 0x1)
+                .filter_map(|offset| SensorSpecificState::new(sensor_type, offset))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,368 @@
+//! States of sensor-specific discrete sensors (event/reading type `0x6F`),
+//! whose meaning depends on the sensor type.
+
+use crate::storage::sdr::SensorType;
+
+discrete_states! {
+    /// States of physical security (chassis intrusion) sensors.
+    PhysicalSecurityState {
+        0x00 => GeneralChassisIntrusion: "General Chassis intrusion",
+        0x01 => DriveBayIntrusion: "Drive Bay intrusion",
+        0x02 => IoCardAreaIntrusion: "I/O Card area intrusion",
+        0x03 => ProcessorAreaIntrusion: "Processor area intrusion",
+        0x04 => LanLeashLost: "System unplugged from LAN",
+        0x05 => UnauthorizedDock: "Unauthorized dock",
+        0x06 => FanAreaIntrusion: "FAN area intrusion",
+    }
+
+    /// States of platform security violation attempt sensors.
+    PlatformSecurityState {
+        0x00 => FrontPanelLockoutViolation: "Front Panel Lockout violation attempted",
+        0x01 => UserPasswordViolation: "Pre-boot password violation - user password",
+        0x02 => SetupPasswordViolation: "Pre-boot password violation - setup password",
+        0x03 => NetworkBootPasswordViolation:
+            "Pre-boot password violation - network boot password",
+        0x04 => OtherPreBootPasswordViolation: "Other pre-boot password violation",
+        0x05 => OutOfBandPasswordViolation: "Out-of-band access password violation",
+    }
+
+    /// States of processor sensors.
+    ProcessorState {
+        0x00 => Ierr: "IERR",
+        0x01 => ThermalTrip: "Thermal Trip",
+        0x02 => Frb1BistFailure: "FRB1/BIST failure",
+        0x03 => Frb2HangInPost: "FRB2/Hang in POST failure",
+        0x04 => Frb3StartupFailure: "FRB3/Processor startup/init failure",
+        0x05 => ConfigurationError: "Configuration Error",
+        0x06 => SmBiosUncorrectableError: "SM BIOS Uncorrectable CPU-complex Error",
+        0x07 => PresenceDetected: "Presence detected",
+        0x08 => Disabled: "Disabled",
+        0x09 => TerminatorPresenceDetected: "Terminator presence detected",
+        0x0A => Throttled: "Throttled",
+        0x0B => UncorrectableMachineCheck: "Uncorrectable machine check exception",
+        0x0C => CorrectableMachineCheck: "Correctable machine check error",
+    }
+
+    /// States of power supply sensors.
+    PowerSupplyState {
+        0x00 => PresenceDetected: "Presence detected",
+        0x01 => FailureDetected: "Failure detected",
+        0x02 => PredictiveFailure: "Predictive failure",
+        0x03 => AcLost: "AC lost",
+        0x04 => AcLostOrOutOfRange: "AC lost or out-of-range",
+        0x05 => AcOutOfRange: "AC out-of-range, but present",
+        0x06 => ConfigurationError: "Configuration error",
+        0x07 => Inactive: "Inactive",
+    }
+
+    /// States of power unit sensors.
+    PowerUnitState {
+        0x00 => PowerOff: "Power off/down",
+        0x01 => PowerCycle: "Power cycle",
+        0x02 => PowerDown240Va: "240VA power down",
+        0x03 => InterlockPowerDown: "Interlock power down",
+        0x04 => AcLost: "AC lost",
+        0x05 => SoftPowerControlFailure: "Soft-power control failure",
+        0x06 => FailureDetected: "Failure detected",
+        0x07 => PredictiveFailure: "Predictive failure",
+    }
+
+    /// States of memory sensors.
+    MemoryState {
+        0x00 => CorrectableEcc: "Correctable ECC",
+        0x01 => UncorrectableEcc: "Uncorrectable ECC",
+        0x02 => Parity: "Parity",
+        0x03 => ScrubFailed: "Memory Scrub Failed",
+        0x04 => DeviceDisabled: "Memory Device Disabled",
+        0x05 => CorrectableEccLoggingLimit: "Correctable ECC logging limit reached",
+        0x06 => PresenceDetected: "Presence Detected",
+        0x07 => ConfigurationError: "Configuration Error",
+        0x08 => Spare: "Spare",
+        0x09 => Throttled: "Throttled",
+        0x0A => CriticalOvertemperature: "Critical Overtemperature",
+    }
+
+    /// States of drive slot (bay) sensors.
+    DriveSlotState {
+        0x00 => DrivePresent: "Drive Present",
+        0x01 => DriveFault: "Drive Fault",
+        0x02 => PredictiveFailure: "Predictive Failure",
+        0x03 => HotSpare: "Hot Spare",
+        0x04 => ParityCheckInProgress: "Parity Check In Progress",
+        0x05 => InCriticalArray: "In Critical Array",
+        0x06 => InFailedArray: "In Failed Array",
+        0x07 => RebuildInProgress: "Rebuild In Progress",
+        0x08 => RebuildAborted: "Rebuild Aborted",
+    }
+
+    /// States of system firmware progress sensors.
+    FirmwareProgressState {
+        0x00 => Error: "System Firmware Error",
+        0x01 => Hang: "System Firmware Hang",
+        0x02 => Progress: "System Firmware Progress",
+    }
+
+    /// States of event logging disabled sensors.
+    EventLoggingState {
+        0x00 => CorrectableMemoryLoggingDisabled: "Correctable memory error logging disabled",
+        0x01 => TypeLoggingDisabled: "Event logging disabled",
+        0x02 => LogCleared: "Log area reset/cleared",
+        0x03 => AllLoggingDisabled: "All event logging disabled",
+        0x04 => LogFull: "Log full",
+        0x05 => LogAlmostFull: "Log almost full",
+        0x06 => CorrectableMachineCheckLoggingDisabled:
+            "Correctable machine check error logging disabled",
+    }
+
+    /// States of watchdog 1 sensors.
+    Watchdog1State {
+        0x00 => BiosReset: "BIOS Reset",
+        0x01 => OsReset: "OS Reset",
+        0x02 => OsShutDown: "OS Shut Down",
+        0x03 => OsPowerDown: "OS Power Down",
+        0x04 => OsPowerCycle: "OS Power Cycle",
+        0x05 => OsNmiDiagInterrupt: "OS NMI/Diag Interrupt",
+        0x06 => OsExpired: "OS Expired",
+        0x07 => OsPreTimeoutInterrupt: "OS pre-timeout Interrupt",
+    }
+
+    /// States of system event sensors.
+    SystemEventState {
+        0x00 => Reconfigured: "System Reconfigured",
+        0x01 => OemBootEvent: "OEM System boot event",
+        0x02 => UndeterminedHardwareFailure: "Undetermined system hardware failure",
+        0x03 => AuxiliaryLogEntry: "Entry added to auxiliary log",
+        0x04 => PefAction: "PEF Action",
+        0x05 => TimestampClockSync: "Timestamp Clock Sync",
+    }
+
+    /// States of critical interrupt sensors.
+    CriticalInterruptState {
+        0x00 => FrontPanelNmi: "Front Panel NMI/Diagnostic Interrupt",
+        0x01 => BusTimeout: "Bus Timeout",
+        0x02 => IoChannelCheckNmi: "I/O channel check NMI",
+        0x03 => SoftwareNmi: "Software NMI",
+        0x04 => PciPerr: "PCI PERR",
+        0x05 => PciSerr: "PCI SERR",
+        0x06 => EisaFailsafeTimeout: "EISA failsafe timeout",
+        0x07 => BusCorrectableError: "Bus Correctable error",
+        0x08 => BusUncorrectableError: "Bus Uncorrectable error",
+        0x09 => FatalNmi: "Fatal NMI",
+        0x0A => BusFatalError: "Bus Fatal Error",
+        0x0B => BusDegraded: "Bus Degraded",
+    }
+
+    /// States of button or switch sensors.
+    ButtonState {
+        0x00 => PowerButtonPressed: "Power Button pressed",
+        0x01 => SleepButtonPressed: "Sleep Button pressed",
+        0x02 => ResetButtonPressed: "Reset Button pressed",
+        0x03 => FruLatchOpen: "FRU latch open",
+        0x04 => FruServiceRequest: "FRU service request button",
+    }
+
+    /// States of chip set sensors.
+    ChipSetState {
+        0x00 => SoftPowerControlFailure: "Soft Power Control Failure",
+        0x01 => ThermalTrip: "Thermal Trip",
+    }
+
+    /// States of cable or interconnect sensors.
+    CableState {
+        0x00 => Connected: "Connected",
+        0x01 => ConfigError: "Config Error",
+    }
+
+    /// States of system boot or restart initiated sensors.
+    BootInitiatedState {
+        0x00 => PowerUp: "Initiated by power up",
+        0x01 => HardReset: "Initiated by hard reset",
+        0x02 => WarmReset: "Initiated by warm reset",
+        0x03 => PxeBootRequested: "User requested PXE boot",
+        0x04 => DiagnosticBoot: "Automatic boot to diagnostic",
+        0x05 => OsHardReset: "OS initiated hard reset",
+        0x06 => OsWarmReset: "OS initiated warm reset",
+        0x07 => SystemRestart: "System Restart",
+    }
+
+    /// States of boot error sensors.
+    BootErrorState {
+        0x00 => NoBootableMedia: "No bootable media",
+        0x01 => NonBootableDisk: "Non-bootable disk in drive",
+        0x02 => PxeServerNotFound: "PXE server not found",
+        0x03 => InvalidBootSector: "Invalid boot sector",
+        0x04 => SelectionTimeout: "Timeout waiting for selection",
+    }
+
+    /// States of base OS boot or installation status sensors.
+    OsBootState {
+        0x00 => ABootCompleted: "A: boot completed",
+        0x01 => CBootCompleted: "C: boot completed",
+        0x02 => PxeBootCompleted: "PXE boot completed",
+        0x03 => DiagnosticBootCompleted: "Diagnostic boot completed",
+        0x04 => CdRomBootCompleted: "CD-ROM boot completed",
+        0x05 => RomBootCompleted: "ROM boot completed",
+        0x06 => BootCompleted: "Boot completed - device not specified",
+        0x07 => InstallationStarted: "Installation started",
+        0x08 => InstallationCompleted: "Installation completed",
+        0x09 => InstallationAborted: "Installation aborted",
+        0x0A => InstallationFailed: "Installation failed",
+    }
+
+    /// States of OS stop or shutdown sensors.
+    OsStopState {
+        0x00 => StartupError: "Error during system startup",
+        0x01 => RunTimeCriticalStop: "Run-time critical stop",
+        0x02 => GracefulStop: "OS graceful stop",
+        0x03 => GracefulShutdown: "OS graceful shutdown",
+        0x04 => PefSoftShutdown: "PEF initiated soft shutdown",
+        0x05 => AgentNotResponding: "Agent not responding",
+    }
+
+    /// States of slot or connector sensors.
+    SlotState {
+        0x00 => FaultStatus: "Fault Status",
+        0x01 => IdentifyStatus: "Identify Status",
+        0x02 => DeviceInstalled: "Device Installed",
+        0x03 => ReadyForInstallation: "Ready for Device Installation",
+        0x04 => ReadyForRemoval: "Ready for Device Removal",
+        0x05 => PowerOff: "Slot Power is Off",
+        0x06 => RemovalRequest: "Device Removal Request",
+        0x07 => Interlock: "Interlock",
+        0x08 => Disabled: "Slot is Disabled",
+        0x09 => SpareDevice: "Spare Device",
+    }
+
+    /// States of system ACPI power state sensors.
+    AcpiPowerState {
+        0x00 => S0G0Working: "S0/G0: working",
+        0x01 => S1Sleeping: "S1: sleeping with system hw & processor context maintained",
+        0x02 => S2Sleeping: "S2: sleeping, processor context lost",
+        0x03 => S3Sleeping: "S3: sleeping, processor & hw context lost, memory retained",
+        0x04 => S4Suspended: "S4: non-volatile sleep/suspend-to-disk",
+        0x05 => S5G2SoftOff: "S5/G2: soft-off",
+        0x06 => S4S5SoftOff: "S4/S5: soft-off",
+        0x07 => G3MechanicalOff: "G3: mechanical off",
+        0x08 => Sleeping: "Sleeping in S1/S2/S3 state",
+        0x09 => G1Sleeping: "G1: sleeping",
+        0x0A => S5Override: "S5: entered by override",
+        0x0B => LegacyOn: "Legacy ON state",
+        0x0C => LegacyOff: "Legacy OFF state",
+        0x0E => Unknown: "Unknown",
+    }
+
+    /// States of watchdog 2 sensors.
+    Watchdog2State {
+        0x00 => TimerExpired: "Timer expired",
+        0x01 => HardReset: "Hard reset",
+        0x02 => PowerDown: "Power down",
+        0x03 => PowerCycle: "Power cycle",
+        0x08 => TimerInterrupt: "Timer interrupt",
+    }
+
+    /// States of platform alert sensors.
+    PlatformAlertState {
+        0x00 => Page: "Platform generated page",
+        0x01 => LanAlert: "Platform generated LAN alert",
+        0x02 => EventTrap: "Platform Event Trap generated",
+        0x03 => SnmpTrap: "Platform generated SNMP trap",
+    }
+
+    /// States of entity presence sensors.
+    EntityPresenceState {
+        0x00 => Present: "Present",
+        0x01 => Absent: "Absent",
+        0x02 => Disabled: "Disabled",
+    }
+
+    /// States of LAN sensors.
+    LanState {
+        0x00 => HeartbeatLost: "Heartbeat Lost",
+        0x01 => Heartbeat: "Heartbeat",
+    }
+
+    /// States of management subsystem health sensors.
+    ManagementHealthState {
+        0x00 => SensorAccessDegraded: "Sensor access degraded or unavailable",
+        0x01 => ControllerAccessDegraded: "Controller access degraded or unavailable",
+        0x02 => ControllerOffLine: "Management controller off-line",
+        0x03 => ControllerUnavailable: "Management controller unavailable",
+        0x04 => SensorFailure: "Sensor failure",
+        0x05 => FruFailure: "FRU failure",
+    }
+
+    /// States of battery sensors.
+    BatteryState {
+        0x00 => Low: "Low",
+        0x01 => Failed: "Failed",
+        0x02 => PresenceDetected: "Presence Detected",
+    }
+
+    /// States of session audit sensors.
+    SessionAuditState {
+        0x00 => Activated: "Session Activated",
+        0x01 => Deactivated: "Session Deactivated",
+        0x02 => InvalidCredentials: "Invalid Username or Password",
+        0x03 => InvalidPasswordDisable: "Invalid password disable",
+    }
+
+    /// States of version change sensors.
+    VersionChangeState {
+        0x00 => HardwareChange: "Hardware change detected",
+        0x01 => FirmwareChange: "Firmware or software change detected",
+        0x02 => HardwareIncompatibility: "Hardware incompatibility detected",
+        0x03 => FirmwareIncompatibility: "Firmware or software incompatibility detected",
+        0x04 => InvalidHardwareVersion: "Invalid or unsupported hardware version",
+        0x05 => InvalidFirmwareVersion: "Invalid or unsupported firmware or software version",
+        0x06 => HardwareChangeSuccess: "Hardware change success",
+        0x07 => FirmwareChangeSuccess: "Firmware or software change success",
+    }
+
+    /// States of FRU state sensors.
+    FruState {
+        0x00 => NotInstalled: "Not Installed",
+        0x01 => Inactive: "Inactive",
+        0x02 => ActivationRequested: "Activation Requested",
+        0x03 => ActivationInProgress: "Activation in Progress",
+        0x04 => Active: "Active",
+        0x05 => DeactivationRequested: "Deactivation Requested",
+        0x06 => DeactivationInProgress: "Deactivation in Progress",
+        0x07 => CommunicationLost: "Communication lost",
+    }
+}
+
+state_kinds! {
+    /// A state of a sensor-specific sensor, selected by its sensor type.
+    SensorSpecificState(SensorType) {
+        SensorType::ChassisIntrusion => PhysicalSecurity(PhysicalSecurityState),
+        SensorType::PlatformSecurityViolationAttempt => PlatformSecurity(PlatformSecurityState),
+        SensorType::Processor => Processor(ProcessorState),
+        SensorType::PowerSupply => PowerSupply(PowerSupplyState),
+        SensorType::PowerUnit => PowerUnit(PowerUnitState),
+        SensorType::Memory => Memory(MemoryState),
+        SensorType::DriveSlotBay => DriveSlot(DriveSlotState),
+        SensorType::SystemFirmwareProgress => FirmwareProgress(FirmwareProgressState),
+        SensorType::EventLoggingDisabled => EventLogging(EventLoggingState),
+        SensorType::Watchdog1 => Watchdog1(Watchdog1State),
+        SensorType::SystemEvent => SystemEvent(SystemEventState),
+        SensorType::CriticalInterrupt => CriticalInterrupt(CriticalInterruptState),
+        SensorType::ButtonOrSwitch => Button(ButtonState),
+        SensorType::ChipSet => ChipSet(ChipSetState),
+        SensorType::CableOrInterconnect => Cable(CableState),
+        SensorType::SystemBootOrRestartInitiated => BootInitiated(BootInitiatedState),
+        SensorType::BootError => BootError(BootErrorState),
+        SensorType::BaseOsBootOrInstallationStatus => OsBoot(OsBootState),
+        SensorType::OsStopOrShutdown => OsStop(OsStopState),
+        SensorType::SlotOrConnector => Slot(SlotState),
+        SensorType::SystemACPIPowerState => AcpiPower(AcpiPowerState),
+        SensorType::Watchdog2 => Watchdog2(Watchdog2State),
+        SensorType::PlatformAlert => PlatformAlert(PlatformAlertState),
+        SensorType::EntityPresence => EntityPresence(EntityPresenceState),
+        SensorType::LAN => Lan(LanState),
+        SensorType::ManagementSubSysHealth => ManagementHealth(ManagementHealthState),
+        SensorType::Battery => Battery(BatteryState),
+        SensorType::SessionAudit => SessionAudit(SessionAuditState),
+        SensorType::VersionChange => VersionChange(VersionChangeState),
+        SensorType::FRUState => Fru(FruState),
+    }
+}
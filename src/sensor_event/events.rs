@@ -0,0 +1,392 @@
+use crate::{
+    connection::{
+        Address, Channel, CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError,
+    },
+    storage::sdr::record::{SensorKey, SensorNumber, ThresholdAssertEventMask},
+};
+
+use super::{DiscreteState, DiscreteStates};
+
+/// The assertion and deassertion event masks of a sensor.
+///
+/// For threshold sensors, the bits correspond to the bits of
+/// [`ThresholdAssertEventMask`]. For discrete sensors, bit N corresponds
+/// to the state at offset N.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SensorEventMasks {
+    pub assertion: u16,
+    pub deassertion: u16,
+}
+
+impl SensorEventMasks {
+    /// Create event masks for a threshold sensor.
+    pub fn threshold(
+        assertion: ThresholdAssertEventMask,
+        deassertion: ThresholdAssertEventMask,
+    ) -> Self {
+        Self {
+            assertion: assertion.bits(),
+            deassertion: deassertion.bits(),
+        }
+    }
+
+    /// Create event masks for a discrete sensor.
+    pub fn discrete<S>(assertion: DiscreteStates<S>, deassertion: DiscreteStates<S>) -> Self
+    where
+        S: DiscreteState,
+    {
+        Self {
+            assertion: assertion.bits(),
+            deassertion: deassertion.bits(),
+        }
+    }
+
+    /// The assertion events of a threshold sensor.
+    pub fn threshold_assertion(&self) -> ThresholdAssertEventMask {
+        ThresholdAssertEventMask::from_bits_truncate(self.assertion)
+    }
+
+    /// The deassertion events of a threshold sensor.
+    pub fn threshold_deassertion(&self) -> ThresholdAssertEventMask {
+        ThresholdAssertEventMask::from_bits_truncate(self.deassertion)
+    }
+
+    /// The assertion events of a discrete sensor.
+    pub fn discrete_assertion<S>(&self) -> DiscreteStates<S>
+    where
+        S: DiscreteState,
+    {
+        DiscreteStates::from_bits(self.assertion)
+    }
+
+    /// The deassertion events of a discrete sensor.
+    pub fn discrete_deassertion<S>(&self) -> DiscreteStates<S>
+    where
+        S: DiscreteState,
+    {
+        DiscreteStates::from_bits(self.deassertion)
+    }
+
+    /// Parse the masks, which are optional in responses.
+    /// Missing bytes are treated as zero.
+    fn parse(data: &[u8]) -> Self {
+        let byte = |idx: usize| data.get(idx).copied().unwrap_or(0);
+
+        Self {
+            assertion: u16::from_le_bytes([byte(0), byte(1)]),
+            deassertion: u16::from_le_bytes([byte(2), byte(3)]),
+        }
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.assertion.to_le_bytes());
+        buffer.extend_from_slice(&self.deassertion.to_le_bytes());
+    }
+}
+
+/// The event enables of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorEventEnable {
+    pub all_event_messages_enabled: bool,
+    pub scanning_enabled: bool,
+    /// The enabled assertion and deassertion events.
+    pub events: SensorEventMasks,
+}
+
+/// Get the event enables of a sensor.
+pub struct GetSensorEventEnable {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+}
+
+impl GetSensorEventEnable {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+        }
+    }
+}
+
+impl From<GetSensorEventEnable> for Message {
+    fn from(value: GetSensorEventEnable) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x29, vec![value.sensor_number.get()])
+    }
+}
+
+impl IpmiCommand for GetSensorEventEnable {
+    type Output = SensorEventEnable;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(SensorEventEnable {
+            all_event_messages_enabled: (data[0] & 0x80) == 0x80,
+            scanning_enabled: (data[0] & 0x40) == 0x40,
+            events: SensorEventMasks::parse(&data[1..]),
+        })
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// The change to make to the individual event enables of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventEnableChange {
+    /// Do not change the individual event enables.
+    Keep,
+    /// Enable the selected events.
+    Enable(SensorEventMasks),
+    /// Disable the selected events.
+    Disable(SensorEventMasks),
+}
+
+/// Set the event enables of a sensor.
+///
+/// Note that all event messages from the sensor are disabled if
+/// `all_event_messages_enabled` is `false`, regardless of `change`.
+pub struct SetSensorEventEnable {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    all_event_messages_enabled: bool,
+    scanning_enabled: bool,
+    change: EventEnableChange,
+}
+
+impl SetSensorEventEnable {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        all_event_messages_enabled: bool,
+        scanning_enabled: bool,
+        change: EventEnableChange,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            all_event_messages_enabled,
+            scanning_enabled,
+            change,
+        }
+    }
+
+    pub fn for_sensor_key(
+        value: &SensorKey,
+        all_event_messages_enabled: bool,
+        scanning_enabled: bool,
+        change: EventEnableChange,
+    ) -> Self {
+        Self::new(
+            value.sensor_number,
+            Address(value.owner_id.into()),
+            Channel(value.owner_channel),
+            all_event_messages_enabled,
+            scanning_enabled,
+            change,
+        )
+    }
+}
+
+impl From<SetSensorEventEnable> for Message {
+    fn from(value: SetSensorEventEnable) -> Self {
+        let (action, masks) = match value.change {
+            EventEnableChange::Keep => (0b00, None),
+            EventEnableChange::Enable(masks) => (0b01, Some(masks)),
+            EventEnableChange::Disable(masks) => (0b10, Some(masks)),
+        };
+
+        let mut data = vec![
+            value.sensor_number.get(),
+            (value.all_event_messages_enabled as u8) << 7
+                | (value.scanning_enabled as u8) << 6
+                | action << 4,
+        ];
+
+        if let Some(masks) = masks {
+            masks.write_data(&mut data);
+        }
+
+        Message::new_request(NetFn::SensorEvent, 0x28, data)
+    }
+}
+
+impl IpmiCommand for SetSensorEventEnable {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// Re-arm the events of a sensor, so that it re-evaluates
+/// its event status and generates events for asserted states.
+pub struct RearmSensorEvents {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    events: Option<SensorEventMasks>,
+}
+
+impl RearmSensorEvents {
+    /// Re-arm the events in `events`, or all events if `events`
+    /// is `None`.
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        events: Option<SensorEventMasks>,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            events,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, events: Option<SensorEventMasks>) -> Self {
+        Self::new(
+            value.sensor_number,
+            Address(value.owner_id.into()),
+            Channel(value.owner_channel),
+            events,
+        )
+    }
+}
+
+impl From<RearmSensorEvents> for Message {
+    fn from(value: RearmSensorEvents) -> Self {
+        let mut data = vec![value.sensor_number.get()];
+
+        match value.events {
+            None => data.push(0x00),
+            Some(events) => {
+                data.push(0x80);
+                events.write_data(&mut data);
+            }
+        }
+
+        Message::new_request(NetFn::SensorEvent, 0x2A, data)
+    }
+}
+
+impl IpmiCommand for RearmSensorEvents {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// The event status of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorEventStatus {
+    pub all_event_messages_enabled: bool,
+    pub scanning_enabled: bool,
+    pub reading_unavailable: bool,
+    /// The events that are currently asserted and deasserted.
+    pub events: SensorEventMasks,
+}
+
+/// Get the event status of a sensor.
+pub struct GetSensorEventStatus {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+}
+
+impl GetSensorEventStatus {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+        }
+    }
+}
+
+impl From<GetSensorEventStatus> for Message {
+    fn from(value: GetSensorEventStatus) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x2B, vec![value.sensor_number.get()])
+    }
+}
+
+impl IpmiCommand for GetSensorEventStatus {
+    type Output = SensorEventStatus;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(SensorEventStatus {
+            all_event_messages_enabled: (data[0] & 0x80) == 0x80,
+            scanning_enabled: (data[0] & 0x40) == 0x40,
+            reading_unavailable: (data[0] & 0x20) == 0x20,
+            events: SensorEventMasks::parse(&data[1..]),
+        })
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
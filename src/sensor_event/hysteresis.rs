@@ -0,0 +1,141 @@
+use crate::{
+    connection::{
+        Address, Channel, CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError,
+    },
+    storage::sdr::record::{SensorKey, SensorNumber},
+};
+
+/// The raw hysteresis values of a threshold sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SensorHysteresis {
+    pub positive_going: u8,
+    pub negative_going: u8,
+}
+
+/// Get the hysteresis of a threshold sensor.
+pub struct GetSensorHysteresis {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+}
+
+impl GetSensorHysteresis {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+        }
+    }
+}
+
+impl From<GetSensorHysteresis> for Message {
+    fn from(value: GetSensorHysteresis) -> Self {
+        // The hysteresis mask is reserved and must be 0xFF.
+        Message::new_request(
+            NetFn::SensorEvent,
+            0x25,
+            vec![value.sensor_number.get(), 0xFF],
+        )
+    }
+}
+
+impl IpmiCommand for GetSensorHysteresis {
+    type Output = SensorHysteresis;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(SensorHysteresis {
+            positive_going: data[0],
+            negative_going: data[1],
+        })
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// Set the hysteresis of a threshold sensor.
+pub struct SetSensorHysteresis {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    hysteresis: SensorHysteresis,
+}
+
+impl SetSensorHysteresis {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        hysteresis: SensorHysteresis,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            hysteresis,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, hysteresis: SensorHysteresis) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+            hysteresis,
+        }
+    }
+}
+
+impl From<SetSensorHysteresis> for Message {
+    fn from(value: SetSensorHysteresis) -> Self {
+        Message::new_request(
+            NetFn::SensorEvent,
+            0x24,
+            vec![
+                value.sensor_number.get(),
+                0xFF,
+                value.hysteresis.positive_going,
+                value.hysteresis.negative_going,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for SetSensorHysteresis {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
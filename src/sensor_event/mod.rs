@@ -1,2 +1,27 @@
 mod sensor_reading;
 pub use sensor_reading::*;
+
+pub mod discrete;
+pub use discrete::{
+    DiscreteReading, DiscreteState, DiscreteStates, GenericState, SensorSpecificState,
+};
+
+mod thresholds;
+pub use thresholds::{GetSensorThresholds, RawSensorThresholds, SetSensorThresholds};
+
+mod reading_factors;
+pub use reading_factors::{GetSensorReadingFactors, ReadingConverter, SensorReadingFactors};
+
+mod hysteresis;
+pub use hysteresis::{GetSensorHysteresis, SensorHysteresis, SetSensorHysteresis};
+
+mod events;
+pub use events::{
+    EventEnableChange, GetSensorEventEnable, GetSensorEventStatus, RearmSensorEvents,
+    SensorEventEnable, SensorEventMasks, SensorEventStatus, SetSensorEventEnable,
+};
+
+mod platform_event;
+pub use platform_event::PlatformEvent;
+
+pub mod pef;
@@ -0,0 +1,52 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+use super::PefActions;
+
+/// The PEF implementation of the BMC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PefCapabilities {
+    /// The major version of the PEF specification.
+    pub major_version: u8,
+    /// The minor version of the PEF specification.
+    pub minor_version: u8,
+    /// Whether PEF supports OEM event record filtering.
+    pub oem_event_record_filtering: bool,
+    /// The actions that PEF supports.
+    pub actions: PefActions,
+    /// The number of entries in the event filter table.
+    pub event_filter_entries: u8,
+}
+
+/// Get the PEF capabilities of the BMC.
+pub struct GetPefCapabilities;
+
+impl From<GetPefCapabilities> for Message {
+    fn from(_: GetPefCapabilities) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x10, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetPefCapabilities {
+    type Output = PefCapabilities;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 3 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(PefCapabilities {
+            major_version: data[0] & 0xF,
+            minor_version: data[0] >> 4,
+            oem_event_record_filtering: (data[1] & 0x80) == 0x80,
+            actions: PefActions::from_bits_truncate(data[1]),
+            event_filter_entries: data[2],
+        })
+    }
+}
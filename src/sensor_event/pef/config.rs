@@ -0,0 +1,72 @@
+use crate::connection::{
+    ConfigurationParameter, GetConfigurationParameter, NetFn, RawParameter,
+    SetConfigurationParameter,
+};
+
+use super::{PefParameter, PefParameterSelector};
+
+impl ConfigurationParameter for PefParameter {
+    type Selector = PefParameterSelector;
+
+    const NETFN: NetFn = NetFn::SensorEvent;
+    const GET_CMD: u8 = 0x13;
+    const SET_CMD: u8 = 0x12;
+
+    fn selector(&self) -> Self::Selector {
+        PefParameter::selector(self)
+    }
+
+    fn parse(selector: Self::Selector, data: &[u8]) -> Option<Self> {
+        PefParameter::parse(selector, data)
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        PefParameter::write_data(self, buffer)
+    }
+}
+
+/// The data of a PEF configuration parameter.
+pub type RawPefParameter = RawParameter<PefParameter>;
+
+/// Get a PEF configuration parameter.
+pub type GetPefConfigurationParameter = GetConfigurationParameter<PefParameter>;
+
+impl GetConfigurationParameter<PefParameter> {
+    /// Get the parameter selected by `selector`.
+    ///
+    /// `set_selector` selects the entry of parameters that are
+    /// tables, and `block_selector` selects the block of alert strings.
+    /// Both should be `0` for other parameters.
+    pub fn new(selector: PefParameterSelector, set_selector: u8, block_selector: u8) -> Self {
+        Self::request(None, selector, set_selector, block_selector)
+    }
+
+    /// Get event filter `number`.
+    pub fn event_filter(number: u8) -> Self {
+        Self::new(PefParameterSelector::EventFilter, number, 0)
+    }
+
+    /// Get entry `entry` of the alert policy table.
+    pub fn alert_policy(entry: u8) -> Self {
+        Self::new(PefParameterSelector::AlertPolicy, entry, 0)
+    }
+
+    /// Get the keys of alert string `selector`.
+    pub fn alert_string_keys(selector: u8) -> Self {
+        Self::new(PefParameterSelector::AlertStringKeys, selector, 0)
+    }
+
+    /// Get block `block` of alert string `selector`.
+    pub fn alert_string(selector: u8, block: u8) -> Self {
+        Self::new(PefParameterSelector::AlertString, selector, block)
+    }
+}
+
+/// Set a PEF configuration parameter.
+pub type SetPefConfigurationParameter = SetConfigurationParameter<PefParameter>;
+
+impl SetConfigurationParameter<PefParameter> {
+    pub fn new(parameter: PefParameter) -> Self {
+        Self::request(None, parameter)
+    }
+}
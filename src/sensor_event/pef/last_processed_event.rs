@@ -0,0 +1,112 @@
+use crate::{
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+    storage::{sel::RecordId, Timestamp},
+};
+
+/// The processor of SEL events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProcessedBy {
+    /// System software.
+    Software,
+    /// The BMC.
+    Bmc,
+}
+
+/// The SEL records that were last processed by software and the BMC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LastProcessedEventId {
+    /// The timestamp of the most recent addition to the SEL.
+    pub most_recent_addition: Timestamp,
+    /// The ID of the last record in the SEL, or `None` if the SEL is empty.
+    pub last_record: Option<RecordId>,
+    /// The ID of the last record that was processed by software.
+    pub software_processed: RecordId,
+    /// The ID of the last record that was processed by the BMC.
+    ///
+    /// This is `None` if the last event was processed, but not logged because
+    /// the SEL is full or logging is disabled.
+    pub bmc_processed: Option<RecordId>,
+}
+
+/// Get the IDs of the SEL records that were last processed by PEF and
+/// by software.
+pub struct GetLastProcessedEventId;
+
+impl From<GetLastProcessedEventId> for Message {
+    fn from(_: GetLastProcessedEventId) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x15, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetLastProcessedEventId {
+    type Output = LastProcessedEventId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 10 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let timestamp = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+        let last_record = u16::from_le_bytes([data[4], data[5]]);
+        let software_processed = u16::from_le_bytes([data[6], data[7]]);
+        let bmc_processed = u16::from_le_bytes([data[8], data[9]]);
+
+        Ok(LastProcessedEventId {
+            most_recent_addition: Timestamp::from(timestamp),
+            last_record: (last_record != 0xFFFF).then(|| RecordId::new_raw(last_record)),
+            software_processed: RecordId::new_raw(software_processed),
+            bmc_processed: (bmc_processed != 0x0000).then(|| RecordId::new_raw(bmc_processed)),
+        })
+    }
+}
+
+/// Set the ID of the SEL record that was last processed by software
+/// or the BMC.
+pub struct SetLastProcessedEventId {
+    processed_by: ProcessedBy,
+    record_id: RecordId,
+}
+
+impl SetLastProcessedEventId {
+    pub fn new(processed_by: ProcessedBy, record_id: RecordId) -> Self {
+        Self {
+            processed_by,
+            record_id,
+        }
+    }
+}
+
+impl From<SetLastProcessedEventId> for Message {
+    fn from(value: SetLastProcessedEventId) -> Self {
+        let processed_by = match value.processed_by {
+            ProcessedBy::Software => 0x00,
+            ProcessedBy::Bmc => 0x01,
+        };
+
+        let mut data = vec![processed_by];
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+
+        Message::new_request(NetFn::SensorEvent, 0x14, data)
+    }
+}
+
+impl IpmiCommand for SetLastProcessedEventId {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! Platform Event Filtering (PEF).
+//!
+//! PEF compares every event that the BMC receives or generates against
+//! a table of event filters, and performs the actions of the filters that
+//! match it. Alerts are sent to the destinations selected by the alert
+//! policy table.
+
+mod capabilities;
+pub use capabilities::{GetPefCapabilities, PefCapabilities};
+
+mod postpone_timer;
+pub use postpone_timer::{ArmPefPostponeTimer, PostponeTimer};
+
+mod parameter;
+pub use parameter::{
+    AlertPolicy, AlertPolicyEntry, AlertStringBlock, AlertStringKeys, EventDataFilter, EventFilter,
+    EventFilterType, EventSeverity, PefControl, PefParameter, PefParameterSelector, SetInProgress,
+    SystemGuid,
+};
+
+mod config;
+pub use config::{GetPefConfigurationParameter, RawPefParameter, SetPefConfigurationParameter};
+
+mod last_processed_event;
+pub use last_processed_event::{
+    GetLastProcessedEventId, LastProcessedEventId, ProcessedBy, SetLastProcessedEventId,
+};
+
+bitflags::bitflags! {
+    /// The actions that PEF can perform.
+    ///
+    /// Used for the supported actions in [`PefCapabilities`], the global
+    /// action control in [`PefParameter::ActionGlobalControl`] and the
+    /// actions of an [`EventFilter`].
+    #[derive(Default)]
+    pub struct PefActions: u8 {
+        const DIAGNOSTIC_INTERRUPT = 1 << 5;
+        const OEM = 1 << 4;
+        const POWER_CYCLE = 1 << 3;
+        const RESET = 1 << 2;
+        const POWER_DOWN = 1 << 1;
+        const ALERT = 1 << 0;
+    }
+}
@@ -0,0 +1,860 @@
+use crate::connection::LogicalUnit;
+
+use super::PefActions;
+
+/// The selector of a PEF configuration parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PefParameterSelector {
+    SetInProgress,
+    Control,
+    ActionGlobalControl,
+    StartupDelay,
+    AlertStartupDelay,
+    EventFilterCount,
+    EventFilter,
+    EventFilterData1,
+    AlertPolicyCount,
+    AlertPolicy,
+    SystemGuid,
+    AlertStringCount,
+    AlertStringKeys,
+    AlertString,
+    /// A parameter that is not supported by this library, such as
+    /// the group control table or an OEM parameter.
+    Other(u8),
+}
+
+impl From<u8> for PefParameterSelector {
+    fn from(value: u8) -> Self {
+        match value & 0x7F {
+            0 => Self::SetInProgress,
+            1 => Self::Control,
+            2 => Self::ActionGlobalControl,
+            3 => Self::StartupDelay,
+            4 => Self::AlertStartupDelay,
+            5 => Self::EventFilterCount,
+            6 => Self::EventFilter,
+            7 => Self::EventFilterData1,
+            8 => Self::AlertPolicyCount,
+            9 => Self::AlertPolicy,
+            10 => Self::SystemGuid,
+            11 => Self::AlertStringCount,
+            12 => Self::AlertStringKeys,
+            13 => Self::AlertString,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<PefParameterSelector> for u8 {
+    fn from(value: PefParameterSelector) -> Self {
+        match value {
+            PefParameterSelector::SetInProgress => 0,
+            PefParameterSelector::Control => 1,
+            PefParameterSelector::ActionGlobalControl => 2,
+            PefParameterSelector::StartupDelay => 3,
+            PefParameterSelector::AlertStartupDelay => 4,
+            PefParameterSelector::EventFilterCount => 5,
+            PefParameterSelector::EventFilter => 6,
+            PefParameterSelector::EventFilterData1 => 7,
+            PefParameterSelector::AlertPolicyCount => 8,
+            PefParameterSelector::AlertPolicy => 9,
+            PefParameterSelector::SystemGuid => 10,
+            PefParameterSelector::AlertStringCount => 11,
+            PefParameterSelector::AlertStringKeys => 12,
+            PefParameterSelector::AlertString => 13,
+            PefParameterSelector::Other(v) => v & 0x7F,
+        }
+    }
+}
+
+/// The state of a set of configuration parameter changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetInProgress {
+    Complete,
+    InProgress,
+    CommitWrite,
+    Reserved,
+}
+
+impl From<u8> for SetInProgress {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0b00 => Self::Complete,
+            0b01 => Self::InProgress,
+            0b10 => Self::CommitWrite,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl From<SetInProgress> for u8 {
+    fn from(value: SetInProgress) -> Self {
+        match value {
+            SetInProgress::Complete => 0b00,
+            SetInProgress::InProgress => 0b01,
+            SetInProgress::CommitWrite => 0b10,
+            SetInProgress::Reserved => 0b11,
+        }
+    }
+}
+
+/// The global PEF control.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PefControl {
+    /// Delay alerts after a system power up or reset by the
+    /// alert startup delay.
+    pub alert_startup_delay: bool,
+    /// Delay PEF after a system power up or reset by the startup delay.
+    pub startup_delay: bool,
+    /// Generate event messages for the actions that PEF performs.
+    pub event_messages: bool,
+    /// Whether PEF is enabled.
+    pub enabled: bool,
+}
+
+impl From<u8> for PefControl {
+    fn from(value: u8) -> Self {
+        Self {
+            alert_startup_delay: (value & 0x08) == 0x08,
+            startup_delay: (value & 0x04) == 0x04,
+            event_messages: (value & 0x02) == 0x02,
+            enabled: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+impl From<PefControl> for u8 {
+    fn from(value: PefControl) -> Self {
+        (value.alert_startup_delay as u8) << 3
+            | (value.startup_delay as u8) << 2
+            | (value.event_messages as u8) << 1
+            | (value.enabled as u8)
+    }
+}
+
+/// The type of an event filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventFilterType {
+    /// A filter that can be configured by software.
+    Software,
+    /// A filter that is pre-configured by the manufacturer. Only the
+    /// enable bit of such filters can be changed.
+    ManufacturerPreconfigured,
+    Reserved(u8),
+}
+
+impl From<u8> for EventFilterType {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0b00 => Self::Software,
+            0b10 => Self::ManufacturerPreconfigured,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<EventFilterType> for u8 {
+    fn from(value: EventFilterType) -> Self {
+        match value {
+            EventFilterType::Software => 0b00,
+            EventFilterType::ManufacturerPreconfigured => 0b10,
+            EventFilterType::Reserved(v) => v & 0x3,
+        }
+    }
+}
+
+fn filter_configuration(enabled: bool, filter_type: EventFilterType) -> u8 {
+    (enabled as u8) << 7 | u8::from(filter_type) << 5
+}
+
+fn parse_filter_configuration(value: u8) -> (bool, EventFilterType) {
+    ((value & 0x80) == 0x80, EventFilterType::from(value >> 5))
+}
+
+/// The severity of the events that match an event filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventSeverity {
+    Unspecified,
+    Monitor,
+    Information,
+    Ok,
+    NonCritical,
+    Critical,
+    NonRecoverable,
+    Other(u8),
+}
+
+impl From<u8> for EventSeverity {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => Self::Unspecified,
+            0x01 => Self::Monitor,
+            0x02 => Self::Information,
+            0x04 => Self::Ok,
+            0x08 => Self::NonCritical,
+            0x10 => Self::Critical,
+            0x20 => Self::NonRecoverable,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<EventSeverity> for u8 {
+    fn from(value: EventSeverity) -> Self {
+        match value {
+            EventSeverity::Unspecified => 0x00,
+            EventSeverity::Monitor => 0x01,
+            EventSeverity::Information => 0x02,
+            EventSeverity::Ok => 0x04,
+            EventSeverity::NonCritical => 0x08,
+            EventSeverity::Critical => 0x10,
+            EventSeverity::NonRecoverable => 0x20,
+            EventSeverity::Other(v) => v,
+        }
+    }
+}
+
+/// A filter for one of the event data bytes of an event.
+///
+/// The event data byte matches if, after applying `and_mask`, all bits
+/// that are set in `compare_1` are equal to the corresponding bits in
+/// `compare_2`. If `compare_1` is zero, the byte matches if any bit that
+/// is set in `compare_2` is also set in the masked event data byte.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EventDataFilter {
+    pub and_mask: u8,
+    pub compare_1: u8,
+    pub compare_2: u8,
+}
+
+impl EventDataFilter {
+    fn parse(data: &[u8]) -> Self {
+        Self {
+            and_mask: data[0],
+            compare_1: data[1],
+            compare_2: data[2],
+        }
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[self.and_mask, self.compare_1, self.compare_2]);
+    }
+}
+
+/// An entry in the event filter table.
+///
+/// Fields that are `None` match any value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventFilter {
+    /// The number of the filter, starting at `1`.
+    pub number: u8,
+    pub enabled: bool,
+    pub filter_type: EventFilterType,
+    /// The actions to perform if an event matches the filter.
+    pub actions: PefActions,
+    /// Whether to perform a group control operation.
+    pub group_control: bool,
+    /// The alert policy number used for alerts.
+    pub alert_policy: u8,
+    /// The group control selector, used if `group_control` is set.
+    pub group_control_selector: u8,
+    pub severity: EventSeverity,
+    /// The slave address or software ID of the generator of the event, as
+    /// it is stored in byte 1 of the generator ID.
+    pub generator_id: Option<u8>,
+    /// The channel and LUN of the generator of the event.
+    pub generator_channel: Option<(u8, LogicalUnit)>,
+    pub sensor_type: Option<u8>,
+    pub sensor_number: Option<u8>,
+    /// The event/reading type code of the event.
+    pub event_trigger: Option<u8>,
+    /// The event offsets that match the filter. Bit N corresponds to offset N.
+    pub event_offset_mask: u16,
+    /// The filters for event data bytes 1 through 3.
+    pub event_data: [EventDataFilter; 3],
+}
+
+impl EventFilter {
+    /// Create a disabled software filter that matches any event, and
+    /// performs no actions.
+    pub fn new(number: u8) -> Self {
+        Self {
+            number,
+            enabled: false,
+            filter_type: EventFilterType::Software,
+            actions: PefActions::empty(),
+            group_control: false,
+            alert_policy: 0,
+            group_control_selector: 0,
+            severity: EventSeverity::Unspecified,
+            generator_id: None,
+            generator_channel: None,
+            sensor_type: None,
+            sensor_number: None,
+            event_trigger: None,
+            event_offset_mask: 0xFFFF,
+            event_data: [EventDataFilter::default(); 3],
+        }
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 21 {
+            return None;
+        }
+
+        let any = |v: u8| (v != 0xFF).then_some(v);
+        let (enabled, filter_type) = parse_filter_configuration(data[1]);
+
+        let generator_channel = any(data[6]).map(|v| {
+            let lun = LogicalUnit::try_from(v & 0x3).unwrap();
+            (v >> 4, lun)
+        });
+
+        Some(Self {
+            number: data[0] & 0x7F,
+            enabled,
+            filter_type,
+            actions: PefActions::from_bits_truncate(data[2]),
+            group_control: (data[2] & 0x40) == 0x40,
+            alert_policy: data[3] & 0xF,
+            group_control_selector: (data[3] >> 4) & 0x7,
+            severity: EventSeverity::from(data[4]),
+            generator_id: any(data[5]),
+            generator_channel,
+            sensor_type: any(data[7]),
+            sensor_number: any(data[8]),
+            event_trigger: any(data[9]),
+            event_offset_mask: u16::from_le_bytes([data[10], data[11]]),
+            event_data: [
+                EventDataFilter::parse(&data[12..15]),
+                EventDataFilter::parse(&data[15..18]),
+                EventDataFilter::parse(&data[18..21]),
+            ],
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        let any = |v: Option<u8>| v.unwrap_or(0xFF);
+
+        let generator_channel = self
+            .generator_channel
+            .map(|(channel, lun)| (channel << 4) | lun.value());
+
+        buffer.extend_from_slice(&[
+            self.number & 0x7F,
+            filter_configuration(self.enabled, self.filter_type),
+            (self.group_control as u8) << 6 | self.actions.bits(),
+            (self.group_control_selector & 0x7) << 4 | (self.alert_policy & 0xF),
+            self.severity.into(),
+            any(self.generator_id),
+            any(generator_channel),
+            any(self.sensor_type),
+            any(self.sensor_number),
+            any(self.event_trigger),
+        ]);
+        buffer.extend_from_slice(&self.event_offset_mask.to_le_bytes());
+
+        for filter in &self.event_data {
+            filter.write_data(buffer);
+        }
+    }
+}
+
+/// The policy of an entry in the alert policy table.
+///
+/// The conditions refer to the alert sent by the previous entry of the
+/// same policy set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertPolicy {
+    /// Always send an alert to this destination.
+    Always,
+    /// If the previous alert was successful, skip this entry and continue
+    /// with the next entry in the policy set.
+    NextIfSuccessful,
+    /// If the previous alert was successful, stop processing the policy set.
+    StopIfSuccessful,
+    /// If the previous alert was successful, continue with the next entry in
+    /// the policy set that uses a different channel.
+    NextChannelIfSuccessful,
+    /// If the previous alert was successful, continue with the next entry in
+    /// the policy set that uses a different destination type.
+    NextDestinationTypeIfSuccessful,
+    Reserved(u8),
+}
+
+impl From<u8> for AlertPolicy {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0 => Self::Always,
+            1 => Self::NextIfSuccessful,
+            2 => Self::StopIfSuccessful,
+            3 => Self::NextChannelIfSuccessful,
+            4 => Self::NextDestinationTypeIfSuccessful,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<AlertPolicy> for u8 {
+    fn from(value: AlertPolicy) -> Self {
+        match value {
+            AlertPolicy::Always => 0,
+            AlertPolicy::NextIfSuccessful => 1,
+            AlertPolicy::StopIfSuccessful => 2,
+            AlertPolicy::NextChannelIfSuccessful => 3,
+            AlertPolicy::NextDestinationTypeIfSuccessful => 4,
+            AlertPolicy::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+/// An entry in the alert policy table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertPolicyEntry {
+    /// The number of the entry, starting at `1`.
+    pub entry: u8,
+    /// The policy set that this entry belongs to, as selected by
+    /// [`EventFilter::alert_policy`].
+    pub policy_number: u8,
+    pub enabled: bool,
+    pub policy: AlertPolicy,
+    /// The channel to send the alert on.
+    pub channel: u8,
+    /// The destination selector of the alert in the configuration
+    /// parameters of `channel`.
+    pub destination: u8,
+    /// Whether the alert string is looked up using the number of the event
+    /// filter that triggered the alert, instead of using `alert_string_key`.
+    pub event_specific_alert_string: bool,
+    /// The alert string selector, or the alert string set if
+    /// `event_specific_alert_string` is set.
+    pub alert_string_key: u8,
+}
+
+impl AlertPolicyEntry {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 4 {
+            return None;
+        }
+
+        Some(Self {
+            entry: data[0] & 0x7F,
+            policy_number: data[1] >> 4,
+            enabled: (data[1] & 0x08) == 0x08,
+            policy: AlertPolicy::from(data[1]),
+            channel: data[2] >> 4,
+            destination: data[2] & 0xF,
+            event_specific_alert_string: (data[3] & 0x80) == 0x80,
+            alert_string_key: data[3] & 0x7F,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.entry & 0x7F,
+            self.policy_number << 4 | (self.enabled as u8) << 3 | u8::from(self.policy),
+            self.channel << 4 | (self.destination & 0xF),
+            (self.event_specific_alert_string as u8) << 7 | (self.alert_string_key & 0x7F),
+        ]);
+    }
+}
+
+/// The system GUID used in Platform Event Traps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemGuid {
+    /// Use `guid` in PETs. If this is `false`, the GUID returned by
+    /// Get System GUID is used instead.
+    pub use_guid: bool,
+    pub guid: [u8; 16],
+}
+
+impl SystemGuid {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 17 {
+            return None;
+        }
+
+        Some(Self {
+            use_guid: (data[0] & 0x01) == 0x01,
+            guid: data[1..17].try_into().unwrap(),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.use_guid as u8);
+        buffer.extend_from_slice(&self.guid);
+    }
+}
+
+/// The keys of an alert string, used to select the string for an event
+/// filter and alert string set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertStringKeys {
+    /// The alert string selector. Selector `0` is the volatile string.
+    pub selector: u8,
+    pub event_filter: u8,
+    pub alert_string_set: u8,
+}
+
+impl AlertStringKeys {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 3 {
+            return None;
+        }
+
+        Some(Self {
+            selector: data[0] & 0x7F,
+            event_filter: data[1] & 0x7F,
+            alert_string_set: data[2] & 0x7F,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.selector & 0x7F,
+            self.event_filter & 0x7F,
+            self.alert_string_set & 0x7F,
+        ]);
+    }
+}
+
+/// A block of an alert string.
+///
+/// Alert strings are transferred in blocks of up to 16 bytes, and
+/// terminated by a null byte.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertStringBlock {
+    /// The alert string selector. Selector `0` is the volatile string.
+    pub selector: u8,
+    /// The number of the block, starting at `1`.
+    pub block: u8,
+    pub data: Vec<u8>,
+}
+
+impl AlertStringBlock {
+    /// The maximum amount of data in a block.
+    pub const SIZE: usize = 16;
+
+    /// Split `string` into the null-terminated blocks that make up
+    /// alert string `selector`.
+    pub fn blocks(selector: u8, string: &str) -> Vec<Self> {
+        let mut data = string.as_bytes().to_vec();
+        data.push(0);
+
+        data.chunks(Self::SIZE)
+            .zip(1..)
+            .map(|(data, block)| Self {
+                selector,
+                block,
+                data: data.to_vec(),
+            })
+            .collect()
+    }
+
+    /// Join the data of `blocks` into a string, stopping at the
+    /// first null byte.
+    pub fn concat(blocks: &[Self]) -> String {
+        let data: Vec<_> = blocks
+            .iter()
+            .flat_map(|b| b.data.iter().copied())
+            .take_while(|v| *v != 0)
+            .collect();
+
+        String::from_utf8_lossy(&data).into_owned()
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        let len = (data.len() - 2).min(Self::SIZE);
+
+        Some(Self {
+            selector: data[0] & 0x7F,
+            block: data[1],
+            data: data[2..2 + len].to_vec(),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.selector & 0x7F);
+        buffer.push(self.block);
+        buffer.extend(self.data.iter().take(Self::SIZE));
+    }
+}
+
+/// A PEF configuration parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PefParameter {
+    SetInProgress(SetInProgress),
+    Control(PefControl),
+    /// The actions that PEF is allowed to perform.
+    ActionGlobalControl(PefActions),
+    /// The PEF startup delay, in seconds.
+    StartupDelay(u8),
+    /// The PEF alert startup delay, in seconds.
+    AlertStartupDelay(u8),
+    /// The number of event filters. Read only.
+    EventFilterCount(u8),
+    EventFilter(EventFilter),
+    /// The configuration byte of an event filter, which allows enabling
+    /// or disabling a filter without changing the rest of its data.
+    EventFilterData1 {
+        number: u8,
+        enabled: bool,
+        filter_type: EventFilterType,
+    },
+    /// The number of alert policy entries. Read only.
+    AlertPolicyCount(u8),
+    AlertPolicy(AlertPolicyEntry),
+    SystemGuid(SystemGuid),
+    /// The number of non-volatile alert strings. Read only.
+    AlertStringCount(u8),
+    AlertStringKeys(AlertStringKeys),
+    AlertString(AlertStringBlock),
+    /// A parameter that is not supported by this library.
+    ///
+    /// `selector` must not be the selector of one of the supported
+    /// parameters.
+    Other {
+        selector: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl PefParameter {
+    pub fn selector(&self) -> PefParameterSelector {
+        match self {
+            Self::SetInProgress(_) => PefParameterSelector::SetInProgress,
+            Self::Control(_) => PefParameterSelector::Control,
+            Self::ActionGlobalControl(_) => PefParameterSelector::ActionGlobalControl,
+            Self::StartupDelay(_) => PefParameterSelector::StartupDelay,
+            Self::AlertStartupDelay(_) => PefParameterSelector::AlertStartupDelay,
+            Self::EventFilterCount(_) => PefParameterSelector::EventFilterCount,
+            Self::EventFilter(_) => PefParameterSelector::EventFilter,
+            Self::EventFilterData1 { .. } => PefParameterSelector::EventFilterData1,
+            Self::AlertPolicyCount(_) => PefParameterSelector::AlertPolicyCount,
+            Self::AlertPolicy(_) => PefParameterSelector::AlertPolicy,
+            Self::SystemGuid(_) => PefParameterSelector::SystemGuid,
+            Self::AlertStringCount(_) => PefParameterSelector::AlertStringCount,
+            Self::AlertStringKeys(_) => PefParameterSelector::AlertStringKeys,
+            Self::AlertString(_) => PefParameterSelector::AlertString,
+            Self::Other { selector, .. } => PefParameterSelector::Other(*selector),
+        }
+    }
+
+    /// Parse the data of the parameter selected by `selector`.
+    ///
+    /// Returns `None` if `data` is too short.
+    pub fn parse(selector: PefParameterSelector, data: &[u8]) -> Option<Self> {
+        type S = PefParameterSelector;
+
+        let first = data.first().copied();
+
+        let value = match selector {
+            S::SetInProgress => Self::SetInProgress(first?.into()),
+            S::Control => Self::Control(first?.into()),
+            S::ActionGlobalControl => {
+                Self::ActionGlobalControl(PefActions::from_bits_truncate(first?))
+            }
+            S::StartupDelay => Self::StartupDelay(first?),
+            S::AlertStartupDelay => Self::AlertStartupDelay(first?),
+            S::EventFilterCount => Self::EventFilterCount(first? & 0x7F),
+            S::EventFilter => Self::EventFilter(EventFilter::parse(data)?),
+            S::EventFilterData1 => {
+                let (enabled, filter_type) = parse_filter_configuration(*data.get(1)?);
+                Self::EventFilterData1 {
+                    number: first? & 0x7F,
+                    enabled,
+                    filter_type,
+                }
+            }
+            S::AlertPolicyCount => Self::AlertPolicyCount(first? & 0x7F),
+            S::AlertPolicy => Self::AlertPolicy(AlertPolicyEntry::parse(data)?),
+            S::SystemGuid => Self::SystemGuid(SystemGuid::parse(data)?),
+            S::AlertStringCount => Self::AlertStringCount(first? & 0x7F),
+            S::AlertStringKeys => Self::AlertStringKeys(AlertStringKeys::parse(data)?),
+            S::AlertString => Self::AlertString(AlertStringBlock::parse(data)?),
+            S::Other(selector) => Self::Other {
+                selector,
+                data: data.to_vec(),
+            },
+        };
+
+        Some(value)
+    }
+
+    /// Write the data of the parameter, excluding the selector.
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::SetInProgress(v) => buffer.push((*v).into()),
+            Self::Control(v) => buffer.push((*v).into()),
+            Self::ActionGlobalControl(v) => buffer.push(v.bits()),
+            Self::StartupDelay(v) | Self::AlertStartupDelay(v) => buffer.push(*v),
+            Self::EventFilterCount(v) | Self::AlertPolicyCount(v) | Self::AlertStringCount(v) => {
+                buffer.push(v & 0x7F)
+            }
+            Self::EventFilter(v) => v.write_data(buffer),
+            Self::EventFilterData1 {
+                number,
+                enabled,
+                filter_type,
+            } => {
+                buffer.push(number & 0x7F);
+                buffer.push(filter_configuration(*enabled, *filter_type));
+            }
+            Self::AlertPolicy(v) => v.write_data(buffer),
+            Self::SystemGuid(v) => v.write_data(buffer),
+            Self::AlertStringKeys(v) => v.write_data(buffer),
+            Self::AlertString(v) => v.write_data(buffer),
+            Self::Other { data, .. } => buffer.extend_from_slice(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(parameter: PefParameter) {
+        let mut data = Vec::new();
+        parameter.write_data(&mut data);
+
+        let parsed = PefParameter::parse(parameter.selector(), &data);
+        assert_eq!(Some(parameter), parsed);
+    }
+
+    #[test]
+    fn simple_parameters() {
+        round_trip(PefParameter::SetInProgress(SetInProgress::CommitWrite));
+        round_trip(PefParameter::Control(PefControl {
+            alert_startup_delay: true,
+            startup_delay: false,
+            event_messages: true,
+            enabled: true,
+        }));
+        round_trip(PefParameter::ActionGlobalControl(
+            PefActions::ALERT | PefActions::POWER_CYCLE,
+        ));
+        round_trip(PefParameter::StartupDelay(60));
+        round_trip(PefParameter::AlertStartupDelay(30));
+        round_trip(PefParameter::EventFilterCount(40));
+        round_trip(PefParameter::AlertPolicyCount(20));
+        round_trip(PefParameter::AlertStringCount(10));
+        round_trip(PefParameter::Other {
+            selector: 96,
+            data: vec![0x12, 0x34],
+        });
+    }
+
+    #[test]
+    fn event_filter() {
+        let filter = EventFilter {
+            enabled: true,
+            actions: PefActions::ALERT | PefActions::RESET,
+            alert_policy: 3,
+            severity: EventSeverity::Critical,
+            generator_id: Some(0x20),
+            generator_channel: Some((0x0, LogicalUnit::One)),
+            sensor_type: Some(0x01),
+            event_trigger: Some(0x01),
+            event_offset_mask: 0x0A00,
+            event_data: [
+                EventDataFilter::default(),
+                EventDataFilter {
+                    and_mask: 0xFF,
+                    compare_1: 0xF0,
+                    compare_2: 0x50,
+                },
+                EventDataFilter::default(),
+            ],
+            ..EventFilter::new(5)
+        };
+
+        let mut data = Vec::new();
+        filter.write_data(&mut data);
+
+        assert_eq!(21, data.len());
+        assert_eq!(
+            &[0x05, 0x80, 0x05, 0x03, 0x10, 0x20, 0x01, 0x01, 0xFF, 0x01, 0x00, 0x0A],
+            &data[..12]
+        );
+
+        round_trip(PefParameter::EventFilter(filter));
+        round_trip(PefParameter::EventFilter(EventFilter::new(1)));
+        round_trip(PefParameter::EventFilterData1 {
+            number: 7,
+            enabled: true,
+            filter_type: EventFilterType::ManufacturerPreconfigured,
+        });
+    }
+
+    #[test]
+    fn alert_policy() {
+        let entry = AlertPolicyEntry {
+            entry: 2,
+            policy_number: 3,
+            enabled: true,
+            policy: AlertPolicy::NextIfSuccessful,
+            channel: 1,
+            destination: 4,
+            event_specific_alert_string: false,
+            alert_string_key: 5,
+        };
+
+        let mut data = Vec::new();
+        entry.write_data(&mut data);
+        assert_eq!(vec![0x02, 0x39, 0x14, 0x05], data);
+
+        round_trip(PefParameter::AlertPolicy(entry));
+    }
+
+    #[test]
+    fn system_guid() {
+        round_trip(PefParameter::SystemGuid(SystemGuid {
+            use_guid: true,
+            guid: core::array::from_fn(|i| i as u8),
+        }));
+    }
+
+    #[test]
+    fn alert_strings() {
+        round_trip(PefParameter::AlertStringKeys(AlertStringKeys {
+            selector: 1,
+            event_filter: 5,
+            alert_string_set: 2,
+        }));
+
+        let blocks = AlertStringBlock::blocks(1, "Critical temperature on CPU 1");
+
+        assert_eq!(2, blocks.len());
+        assert_eq!(16, blocks[0].data.len());
+        assert_eq!(Some(&0), blocks[1].data.last());
+        assert_eq!(
+            "Critical temperature on CPU 1",
+            AlertStringBlock::concat(&blocks)
+        );
+
+        for block in blocks {
+            round_trip(PefParameter::AlertString(block));
+        }
+    }
+
+    #[test]
+    fn short_data() {
+        assert_eq!(
+            None,
+            PefParameter::parse(PefParameterSelector::Control, &[])
+        );
+        assert_eq!(
+            None,
+            PefParameter::parse(PefParameterSelector::EventFilter, &[0x01; 20])
+        );
+        assert_eq!(
+            None,
+            PefParameter::parse(PefParameterSelector::SystemGuid, &[0x01; 16])
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// A setting of the PEF postpone timer.
+///
+/// While the postpone timer runs, PEF is disabled. This allows software
+/// to handle events itself, and to update the last processed event ID
+/// before PEF processes them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PostponeTimer {
+    /// The postpone timer is disabled.
+    Disabled,
+    /// The postpone timer counts down from the given amount of
+    /// seconds, `1` through `253`.
+    Armed(u8),
+    /// PEF is temporarily disabled until it is re-armed, or until the
+    /// postpone timer is disabled.
+    TemporaryDisable,
+}
+
+impl From<u8> for PostponeTimer {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => Self::Disabled,
+            0xFE => Self::TemporaryDisable,
+            // 0xFF is reserved in responses.
+            v => Self::Armed(v),
+        }
+    }
+}
+
+impl From<PostponeTimer> for u8 {
+    fn from(value: PostponeTimer) -> Self {
+        match value {
+            PostponeTimer::Disabled => 0x00,
+            PostponeTimer::Armed(v) => v.clamp(0x01, 0xFD),
+            PostponeTimer::TemporaryDisable => 0xFE,
+        }
+    }
+}
+
+/// Arm or disable the PEF postpone timer, or get its present countdown value.
+///
+/// The response contains the present countdown value of the timer.
+pub struct ArmPefPostponeTimer {
+    timer: Option<PostponeTimer>,
+}
+
+impl ArmPefPostponeTimer {
+    /// Set the postpone timer to `timer`.
+    pub fn new(timer: PostponeTimer) -> Self {
+        Self { timer: Some(timer) }
+    }
+
+    /// Only get the present countdown value of the postpone timer.
+    pub fn get() -> Self {
+        Self { timer: None }
+    }
+}
+
+impl From<ArmPefPostponeTimer> for Message {
+    fn from(value: ArmPefPostponeTimer) -> Self {
+        let timer = value.timer.map(u8::from).unwrap_or(0xFF);
+        Message::new_request(NetFn::SensorEvent, 0x11, vec![timer])
+    }
+}
+
+impl IpmiCommand for ArmPefPostponeTimer {
+    type Output = PostponeTimer;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(PostponeTimer::from(data[0]))
+    }
+}
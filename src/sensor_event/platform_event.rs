@@ -0,0 +1,93 @@
+use crate::{
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+    storage::sel::{Entry, EventDirection, EventGenerator, EventMessageRevision},
+};
+
+/// Send a Platform Event Message to the BMC, which logs it in the
+/// SEL and processes it using PEF.
+///
+/// The fields of the message mirror those of [`Entry::System`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlatformEvent {
+    /// The generator of the event.
+    ///
+    /// The generator ID must be present if the message is sent using the
+    /// system interface. Otherwise, the BMC uses the address of the requester
+    /// as the generator ID, and this must be `None`. Only the address or software
+    /// ID of the generator is sent: the BMC fills in the channel and LUN.
+    pub generator_id: Option<EventGenerator>,
+    pub event_message_format: EventMessageRevision,
+    pub sensor_type: u8,
+    pub sensor_number: u8,
+    pub event_direction: EventDirection,
+    pub event_type: u8,
+    pub event_data: [u8; 3],
+}
+
+impl PlatformEvent {
+    /// Create a platform event message that replays the system event
+    /// entry `entry`.
+    ///
+    /// Returns `None` if `entry` is not an [`Entry::System`].
+    pub fn from_entry(entry: &Entry) -> Option<Self> {
+        match entry {
+            Entry::System {
+                generator_id,
+                event_message_format,
+                sensor_type,
+                sensor_number,
+                event_direction,
+                event_type,
+                event_data,
+                ..
+            } => Some(Self {
+                generator_id: Some(*generator_id),
+                event_message_format: *event_message_format,
+                sensor_type: *sensor_type,
+                sensor_number: *sensor_number,
+                event_direction: *event_direction,
+                event_type: *event_type,
+                event_data: *event_data,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl From<PlatformEvent> for Message {
+    fn from(value: PlatformEvent) -> Self {
+        let mut data = Vec::with_capacity(8);
+
+        if let Some(generator_id) = value.generator_id {
+            let (generator_id, _) = generator_id.into();
+            data.push(generator_id);
+        }
+
+        let direction = match value.event_direction {
+            EventDirection::Assert => 0x00,
+            EventDirection::Deassert => 0x80,
+        };
+
+        data.push(value.event_message_format.into());
+        data.push(value.sensor_type);
+        data.push(value.sensor_number);
+        data.push(direction | (value.event_type & 0x7F));
+        data.extend_from_slice(&value.event_data);
+
+        Message::new_request(NetFn::SensorEvent, 0x02, data)
+    }
+}
+
+impl IpmiCommand for PlatformEvent {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    connection::{
+        Address, Channel, CompletionCode, IpmiCommand, IpmiConnection, Message, NetFn,
+        ParseResponseError,
+    },
+    storage::sdr::record::{
+        FullSensorRecord, ReadingFactors, SensorKey, SensorNumber, SensorRecord, Value,
+    },
+    Ipmi, IpmiCommandError,
+};
+
+/// The reading factors of a sensor for a specific reading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorReadingFactors {
+    /// The next reading for which a different set of
+    /// reading factors is defined.
+    pub next_reading: u8,
+    pub factors: ReadingFactors,
+}
+
+/// Get the reading factors of a sensor for a specific raw reading.
+///
+/// This is required to convert the readings of non-linear sensors.
+pub struct GetSensorReadingFactors {
+    sensor_number: SensorNumber,
+    reading: u8,
+    address: Address,
+    channel: Channel,
+}
+
+impl GetSensorReadingFactors {
+    pub fn new(
+        sensor_number: SensorNumber,
+        reading: u8,
+        address: Address,
+        channel: Channel,
+    ) -> Self {
+        Self {
+            sensor_number,
+            reading,
+            address,
+            channel,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, reading: u8) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            reading,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+        }
+    }
+}
+
+impl From<GetSensorReadingFactors> for Message {
+    fn from(value: GetSensorReadingFactors) -> Self {
+        Message::new_request(
+            NetFn::SensorEvent,
+            0x23,
+            vec![value.sensor_number.get(), value.reading],
+        )
+    }
+}
+
+impl IpmiCommand for GetSensorReadingFactors {
+    type Output = SensorReadingFactors;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 7 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let factors =
+            ReadingFactors::parse(&data[1..7]).ok_or(ParseResponseError::NotEnoughData)?;
+
+        Ok(SensorReadingFactors {
+            next_reading: data[0],
+            factors,
+        })
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// Converts raw readings of full sensor records into values.
+///
+/// The reading factors of non-linear sensors are retrieved using
+/// `Get Sensor Reading Factors`, and cached per sensor for the range of
+/// readings from the requested reading up to the next reading for which
+/// different factors are defined.
+#[derive(Debug, Clone, Default)]
+pub struct ReadingConverter {
+    /// The reading factors of every sensor, keyed by the first reading
+    /// of the range that they apply to, and including the last reading.
+    factors: HashMap<SensorKey, BTreeMap<u8, (u8, ReadingFactors)>>,
+}
+
+impl ReadingConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert the raw value `reading` of the sensor described by `record`.
+    ///
+    /// Returns `Ok(None)` if the reading cannot be converted, for
+    /// instance because the sensor does not return analog readings.
+    pub fn convert<CON>(
+        &mut self,
+        ipmi: &mut Ipmi<CON>,
+        record: &FullSensorRecord,
+        reading: u8,
+    ) -> Result<Option<Value>, IpmiCommandError<CON::Error, ()>>
+    where
+        CON: IpmiConnection,
+    {
+        if !record.linearization.is_non_linear() {
+            return Ok(record.convert(reading));
+        }
+
+        let key = *record.key_data();
+        let ranges = self.factors.entry(key).or_default();
+
+        let cached = ranges
+            .range(..=reading)
+            .next_back()
+            .filter(|(_, (last, _))| reading <= *last)
+            .map(|(_, (_, factors))| *factors);
+
+        let factors = match cached {
+            Some(factors) => factors,
+            None => {
+                let SensorReadingFactors {
+                    next_reading,
+                    factors,
+                } = ipmi.send_recv(GetSensorReadingFactors::for_sensor_key(&key, reading))?;
+
+                // Only the requested reading is known to use these
+                // factors if the next reading is not above it.
+                let last = next_reading.checked_sub(1).filter(|last| *last >= reading);
+                ranges.insert(reading, (last.unwrap_or(reading), factors));
+                factors
+            }
+        };
+
+        Ok(record.convert_with(&factors, reading))
+    }
+
+    /// Remove all cached reading factors.
+    pub fn clear(&mut self) {
+        self.factors.clear();
+    }
+}
@@ -12,12 +12,11 @@ pub trait FromSensorReading {
 #[derive(Debug, Clone, Copy)]
 pub struct RawSensorReading {
     reading: u8,
-    all_event_messages_disabled: bool,
-    scanning_disabled: bool,
-    reading_or_state_unavailable: bool,
-    offset_data_1: Option<u8>,
-    #[allow(unused)]
-    offset_data_2: Option<u8>,
+    pub(crate) all_event_messages_disabled: bool,
+    pub(crate) scanning_disabled: bool,
+    pub(crate) reading_or_state_unavailable: bool,
+    pub(crate) offset_data_1: Option<u8>,
+    pub(crate) offset_data_2: Option<u8>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -48,7 +47,7 @@ impl From<&RawSensorReading> for ThresholdReading {
                 at_or_above_upper_critical: (d & 0x10 == 0x10),
                 at_or_above_upper_non_critical: (d & 0x08) == 0x08,
                 at_or_below_lower_non_recoverable: (d & 0x04) == 0x04,
-                at_or_below_lower_critical: (d & 0x20) == 0x20,
+                at_or_below_lower_critical: (d & 0x02) == 0x02,
                 at_or_below_lower_non_critical: (d & 0x01) == 0x01,
             })
         };
@@ -75,3 +74,25 @@ impl FromSensorReading for ThresholdReading {
         in_reading.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_status() {
+        let status = |d: u8| {
+            let raw = RawSensorReading::parse(&[0x20, 0xC0, d]).unwrap();
+            let reading: ThresholdReading = (&raw).into();
+            reading.threshold_status.unwrap()
+        };
+
+        let lower_critical = status(0x02);
+        assert!(lower_critical.at_or_below_lower_critical);
+        assert!(!lower_critical.at_or_above_non_recoverable);
+
+        let non_recoverable = status(0x20);
+        assert!(non_recoverable.at_or_above_non_recoverable);
+        assert!(!non_recoverable.at_or_below_lower_critical);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    connection::{
+        Address, Channel, CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError,
+    },
+    storage::sdr::record::{SensorKey, SensorNumber, ThresholdKind, Thresholds},
+};
+
+/// The raw values of the thresholds of a sensor.
+///
+/// Thresholds that are not readable, or that should not be set,
+/// have no value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RawSensorThresholds {
+    pub lower_non_critical: Option<u8>,
+    pub lower_critical: Option<u8>,
+    pub lower_non_recoverable: Option<u8>,
+    pub upper_non_critical: Option<u8>,
+    pub upper_critical: Option<u8>,
+    pub upper_non_recoverable: Option<u8>,
+}
+
+impl RawSensorThresholds {
+    /// Get the raw value of the threshold of kind `kind`.
+    pub fn get(&self, kind: ThresholdKind) -> Option<u8> {
+        match kind {
+            ThresholdKind::LowerNonCritical => self.lower_non_critical,
+            ThresholdKind::LowerCritical => self.lower_critical,
+            ThresholdKind::LowerNonRecoverable => self.lower_non_recoverable,
+            ThresholdKind::UpperNonCritical => self.upper_non_critical,
+            ThresholdKind::UpperCritical => self.upper_critical,
+            ThresholdKind::UpperNonRecoverable => self.upper_non_recoverable,
+        }
+    }
+
+    /// Set the raw value of the threshold of kind `kind`.
+    pub fn set(&mut self, kind: ThresholdKind, value: Option<u8>) {
+        let threshold = match kind {
+            ThresholdKind::LowerNonCritical => &mut self.lower_non_critical,
+            ThresholdKind::LowerCritical => &mut self.lower_critical,
+            ThresholdKind::LowerNonRecoverable => &mut self.lower_non_recoverable,
+            ThresholdKind::UpperNonCritical => &mut self.upper_non_critical,
+            ThresholdKind::UpperCritical => &mut self.upper_critical,
+            ThresholdKind::UpperNonRecoverable => &mut self.upper_non_recoverable,
+        };
+
+        *threshold = value;
+    }
+
+    /// The thresholds that have a value.
+    pub fn mask(&self) -> Thresholds {
+        Thresholds {
+            lower_non_recoverable: self.lower_non_recoverable.is_some(),
+            lower_critical: self.lower_critical.is_some(),
+            lower_non_critical: self.lower_non_critical.is_some(),
+            upper_non_recoverable: self.upper_non_recoverable.is_some(),
+            upper_critical: self.upper_critical.is_some(),
+            upper_non_critical: self.upper_non_critical.is_some(),
+        }
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 7 {
+            return None;
+        }
+
+        let mask = Thresholds::from_bits(data[0]);
+        let mut thresholds = Self::default();
+
+        for (kind, value) in ThresholdKind::variants().zip(&data[1..7]) {
+            thresholds.set(kind, Some(*value).filter(|_| mask.for_kind(kind)));
+        }
+
+        Some(thresholds)
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.mask().bits());
+        buffer.extend(ThresholdKind::variants().map(|kind| self.get(kind).unwrap_or(0)));
+    }
+}
+
+/// Get the thresholds of a sensor.
+pub struct GetSensorThresholds {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+}
+
+impl GetSensorThresholds {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+        }
+    }
+}
+
+impl From<GetSensorThresholds> for Message {
+    fn from(value: GetSensorThresholds) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x27, vec![value.sensor_number.get()])
+    }
+}
+
+impl IpmiCommand for GetSensorThresholds {
+    type Output = RawSensorThresholds;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        RawSensorThresholds::parse(data).ok_or(ParseResponseError::NotEnoughData)
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
+
+/// Set the thresholds of a sensor.
+///
+/// Only the thresholds that have a value in `thresholds` are set.
+pub struct SetSensorThresholds {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    thresholds: RawSensorThresholds,
+}
+
+impl SetSensorThresholds {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        thresholds: RawSensorThresholds,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            thresholds,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, thresholds: RawSensorThresholds) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: Channel(value.owner_channel),
+            thresholds,
+        }
+    }
+}
+
+impl From<SetSensorThresholds> for Message {
+    fn from(value: SetSensorThresholds) -> Self {
+        let mut data = vec![value.sensor_number.get()];
+        value.thresholds.write_data(&mut data);
+
+        Message::new_request(NetFn::SensorEvent, 0x26, data)
+    }
+}
+
+impl IpmiCommand for SetSensorThresholds {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+}
@@ -1,39 +1,95 @@
 pub mod sel;
 
-use std::num::NonZeroU16;
+use std::{
+    num::NonZeroU16,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub mod sdr;
 
 use crate::{fmt::LogItem, log_vec, Loggable};
 
+/// A SEL or SDR timestamp.
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Timestamp(u32);
+pub enum Timestamp {
+    /// The timestamp is invalid or unspecified (`0xFFFFFFFF`).
+    Unspecified,
+    /// Seconds since the initialization of the BMC (`0x00000000` up to
+    /// and including `0x20000000`).
+    ///
+    /// BMCs use these timestamps until their clock is set, so they
+    /// do not correspond to a wall-clock time.
+    SinceInit(u32),
+    /// Seconds since 1970-01-01T00:00:00Z.
+    Absolute(u32),
+}
+
+impl Timestamp {
+    const UNSPECIFIED: u32 = 0xFFFF_FFFF;
+    const MAX_SINCE_INIT: u32 = 0x2000_0000;
+
+    /// Get the absolute time of this timestamp, in seconds since
+    /// 1970-01-01T00:00:00Z.
+    ///
+    /// Timestamps that are relative to the initialization of the BMC are
+    /// converted on a best-effort basis: this is only possible if the current
+    /// SEL time of the BMC, `sel_time`, is also relative to its initialization.
+    /// In that case, the BMC was initialized `sel_time` seconds before `now`.
+    pub fn to_absolute(&self, sel_time: Timestamp, now: SystemTime) -> Option<u32> {
+        match (*self, sel_time) {
+            (Self::Absolute(seconds), _) => Some(seconds),
+            (Self::SinceInit(seconds), Self::SinceInit(uptime)) => {
+                let now = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+                let init = u32::try_from(now).ok()?.checked_sub(uptime)?;
+                init.checked_add(seconds)
+            }
+            _ => None,
+        }
+    }
+}
 
 impl core::fmt::Display for Timestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.0 == 0 {
-            write!(f, "Unknown")
-        } else {
-            #[cfg(feature = "time")]
-            {
-                let timestamp = time::OffsetDateTime::from_unix_timestamp(self.0 as i64).unwrap();
-
-                let time = timestamp
-                    .format(&time::format_description::well_known::Rfc3339)
-                    .unwrap();
-
-                write!(f, "{}", time)
+        match *self {
+            Self::Unspecified => write!(f, "Unspecified"),
+            Self::SinceInit(seconds) => write!(f, "{seconds} s after BMC initialization"),
+            Self::Absolute(seconds) => {
+                #[cfg(feature = "time")]
+                {
+                    let time = time::OffsetDateTime::from_unix_timestamp(seconds as i64)
+                        .ok()
+                        .and_then(|t| {
+                            t.format(&time::format_description::well_known::Rfc3339)
+                                .ok()
+                        });
+
+                    if let Some(time) = time {
+                        return write!(f, "{}", time);
+                    }
+                }
+
+                write!(f, "{}", seconds)
             }
-
-            #[cfg(not(feature = "time"))]
-            write!(f, "{}", self.0)
         }
     }
 }
 
 impl From<u32> for Timestamp {
     fn from(value: u32) -> Self {
-        Self(value)
+        match value {
+            Self::UNSPECIFIED => Self::Unspecified,
+            0..=Self::MAX_SINCE_INIT => Self::SinceInit(value),
+            _ => Self::Absolute(value),
+        }
+    }
+}
+
+impl From<Timestamp> for u32 {
+    fn from(value: Timestamp) -> Self {
+        match value {
+            Timestamp::Unspecified => Timestamp::UNSPECIFIED,
+            Timestamp::SinceInit(seconds) | Timestamp::Absolute(seconds) => seconds,
+        }
     }
 }
 
@@ -90,3 +146,64 @@ impl Loggable for AllocInfo {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn timestamp_ranges() {
+        assert_eq!(Timestamp::from(0), Timestamp::SinceInit(0));
+        assert_eq!(
+            Timestamp::from(0x2000_0000),
+            Timestamp::SinceInit(0x2000_0000)
+        );
+        assert_eq!(
+            Timestamp::from(0x2000_0001),
+            Timestamp::Absolute(0x2000_0001)
+        );
+        assert_eq!(Timestamp::from(0xFFFF_FFFF), Timestamp::Unspecified);
+        assert_eq!(
+            Timestamp::from(0xFFFF_FFFE),
+            Timestamp::Absolute(0xFFFF_FFFE)
+        );
+
+        for value in [0, 0x2000_0000, 0x2000_0001, 0xFFFF_FFFE, 0xFFFF_FFFF] {
+            assert_eq!(u32::from(Timestamp::from(value)), value);
+            // Must not panic
+            let _ = Timestamp::from(value).to_string();
+        }
+    }
+
+    #[test]
+    fn timestamp_to_absolute() {
+        let now = UNIX_EPOCH + Duration::from_secs(0x6000_0000);
+
+        let absolute = Timestamp::Absolute(0x5000_0000);
+        assert_eq!(
+            absolute.to_absolute(Timestamp::Unspecified, now),
+            Some(0x5000_0000)
+        );
+
+        let since_init = Timestamp::SinceInit(100);
+        assert_eq!(
+            since_init.to_absolute(Timestamp::SinceInit(1000), now),
+            Some(0x6000_0000 - 900)
+        );
+        assert_eq!(
+            since_init.to_absolute(Timestamp::Absolute(0x6000_0000), now),
+            None
+        );
+        assert_eq!(
+            since_init.to_absolute(Timestamp::SinceInit(0x7000_0000), now),
+            None
+        );
+
+        assert_eq!(
+            Timestamp::Unspecified.to_absolute(Timestamp::SinceInit(1000), now),
+            None
+        );
+    }
+}
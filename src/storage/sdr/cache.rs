@@ -0,0 +1,207 @@
+use std::{fs, io, path::PathBuf};
+
+use crate::{
+    app::{GetDeviceGuid, GetSystemGuid},
+    connection::IpmiConnection,
+    Ipmi, IpmiCommandError, SdrIterError,
+};
+
+use super::{GetSdrRepositoryInfo, SdrDump, SdrRepositoryInfo};
+
+/// A cache of SDR repository dumps, stored as files in a directory.
+///
+/// Dumps are keyed by an identity of the BMC: by default, its device
+/// GUID, or its system GUID if it does not have one. The device ID,
+/// manufacturer and product ID are not used, as they identify a model of
+/// BMC rather than a specific BMC, and BMCs of the same model can have
+/// different SDR repositories. A cached dump is
+/// only used if the most recent addition and erase timestamps of the
+/// repository are the same as when the dump was stored. BMCs that do not
+/// keep track of these timestamps report them as unspecified, which means
+/// that changes to their repository are only detected if the amount of
+/// records changes.
+///
+/// The cache files are compatible with `ipmitool sdr dump`. Only the most
+/// recent dump is kept for each identity.
+#[derive(Debug, Clone)]
+pub struct SdrCache {
+    dir: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum SdrCacheError<CON> {
+    Io(io::Error),
+    Command(IpmiCommandError<CON, ()>),
+    Iter(SdrIterError<CON>),
+    /// The BMC has neither a device GUID nor a system GUID, so it
+    /// must be identified using [`SdrCache::load_as`].
+    NoIdentity,
+}
+
+impl<CON> From<io::Error> for SdrCacheError<CON> {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl SdrCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The identity used for cache files, with all bytes other than
+    /// ASCII alphanumerics, `-` and `_` percent-encoded, so that it cannot
+    /// escape the cache directory or collide with another identity.
+    fn file_identity(identity: &str) -> String {
+        identity
+            .bytes()
+            .map(|b| match b {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' => char::from(b).to_string(),
+                _ => format!("%{b:02X}"),
+            })
+            .collect()
+    }
+
+    /// The identity of the BMC that a cache file belongs to.
+    fn identity_of(file_name: &str) -> Option<&str> {
+        let (identity, _state) = file_name.strip_suffix(".sdr")?.rsplit_once('.')?;
+        Some(identity)
+    }
+
+    /// The path of the cache file for the repository described by `info`,
+    /// of the BMC identified by `identity`.
+    pub fn path(&self, identity: &str, info: &SdrRepositoryInfo) -> PathBuf {
+        let addition = u32::from(info.most_recent_addition);
+        let erase = u32::from(info.most_recent_erase);
+
+        self.dir.join(format!(
+            "{}.{addition:08X}-{erase:08X}-{:04X}.sdr",
+            Self::file_identity(identity),
+            info.record_count,
+        ))
+    }
+
+    /// Get the cached dump of the repository described by `info`.
+    ///
+    /// Returns `None` if no dump is cached for the current state of
+    /// the repository, or if the cached dump is corrupt.
+    pub fn get(&self, identity: &str, info: &SdrRepositoryInfo) -> io::Result<Option<SdrDump>> {
+        let path = self.path(identity, info);
+
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let dump = SdrDump::from_bytes(&data);
+
+        if dump.is_none() {
+            log::warn!("Ignoring corrupt SDR cache file {}", path.display());
+        }
+
+        Ok(dump)
+    }
+
+    /// Store `dump` as the dump of the repository described by `info`,
+    /// replacing any previously stored dump of the same BMC.
+    pub fn store(
+        &self,
+        identity: &str,
+        info: &SdrRepositoryInfo,
+        dump: &SdrDump,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let file_identity = Self::file_identity(identity);
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+
+            if Self::identity_of(&name.to_string_lossy()) == Some(file_identity.as_str()) {
+                fs::remove_file(entry.path())?;
+            }
+        }
+
+        // Write to a temporary file first, so that an interrupted
+        // write does not leave a truncated dump behind.
+        let path = self.path(identity, info);
+        let tmp_path = path.with_extension("tmp");
+        let result =
+            fs::write(&tmp_path, dump.to_bytes()).and_then(|_| fs::rename(&tmp_path, path));
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+
+        result
+    }
+
+    /// The identity of the BMC of `ipmi`: its device GUID, or its
+    /// system GUID if it does not have one.
+    fn identity<CON>(ipmi: &mut Ipmi<CON>) -> Result<String, SdrCacheError<CON::Error>>
+    where
+        CON: IpmiConnection,
+    {
+        let guid = match ipmi.send_recv(GetDeviceGuid) {
+            Ok(guid) => guid,
+            Err(e) if e.completion_code().is_some() => match ipmi.send_recv(GetSystemGuid) {
+                Ok(guid) => guid,
+                Err(e) if e.completion_code().is_some() => return Err(SdrCacheError::NoIdentity),
+                Err(e) => return Err(SdrCacheError::Command(e)),
+            },
+            Err(e) => return Err(SdrCacheError::Command(e)),
+        };
+
+        Ok(guid.to_string())
+    }
+
+    /// Load the SDR repository of `ipmi`, using the cached dump if the
+    /// repository has not changed since it was stored.
+    ///
+    /// If the repository did change, all records are read and the new
+    /// dump is stored in the cache.
+    pub fn load<CON>(&self, ipmi: &mut Ipmi<CON>) -> Result<SdrDump, SdrCacheError<CON::Error>>
+    where
+        CON: IpmiConnection,
+    {
+        let identity = Self::identity(ipmi)?;
+        self.load_as(ipmi, &identity)
+    }
+
+    /// Like [`SdrCache::load`], but identify the BMC by `identity`, such as
+    /// its host name, instead of its GUID.
+    pub fn load_as<CON>(
+        &self,
+        ipmi: &mut Ipmi<CON>,
+        identity: &str,
+    ) -> Result<SdrDump, SdrCacheError<CON::Error>>
+    where
+        CON: IpmiConnection,
+    {
+        let info = ipmi
+            .send_recv(GetSdrRepositoryInfo)
+            .map_err(SdrCacheError::Command)?;
+
+        if let Some(dump) = self.get(identity, &info)? {
+            log::debug!("Using cached SDR dump for {identity}");
+            return Ok(dump);
+        }
+
+        let dump = ipmi.sdr_dump().map_err(SdrCacheError::Iter)?;
+
+        // Only store the dump if the repository did not change while
+        // it was being read.
+        let info_after = ipmi
+            .send_recv(GetSdrRepositoryInfo)
+            .map_err(SdrCacheError::Command)?;
+
+        if self.path(identity, &info) == self.path(identity, &info_after) {
+            self.store(identity, &info, &dump)?;
+        } else {
+            log::debug!("SDR repository changed while it was read, not caching it.");
+        }
+
+        Ok(dump)
+    }
+}
@@ -0,0 +1,75 @@
+use std::num::NonZeroU16;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClearAction {
+    /// Start erasing the repository.
+    Initiate,
+    /// Get the status of an erase that was started earlier.
+    GetStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErasureProgress {
+    InProgress,
+    Completed,
+}
+
+/// Clear the SDR repository.
+///
+/// Erasing the repository may take some time. After initiating
+/// the erase, its progress can be polled using [`ClearAction::GetStatus`]
+/// until [`ErasureProgress::Completed`] is returned.
+#[derive(Debug, Clone, Copy)]
+pub struct ClearRepository {
+    reservation_id: NonZeroU16,
+    action: ClearAction,
+}
+
+impl ClearRepository {
+    pub fn new(reservation_id: NonZeroU16, action: ClearAction) -> Self {
+        Self {
+            reservation_id,
+            action,
+        }
+    }
+}
+
+impl From<ClearRepository> for Message {
+    fn from(value: ClearRepository) -> Self {
+        let mut data = Vec::with_capacity(6);
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(b"CLR");
+        data.push(match value.action {
+            ClearAction::Initiate => 0xAA,
+            ClearAction::GetStatus => 0x00,
+        });
+
+        Message::new_request(NetFn::Storage, 0x27, data)
+    }
+}
+
+impl IpmiCommand for ClearRepository {
+    type Output = ErasureProgress;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.is_empty() {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        match data[0] & 0x0F {
+            0x0 => Ok(ErasureProgress::InProgress),
+            0x1 => Ok(ErasureProgress::Completed),
+            _ => Err(ParseResponseError::Parse(())),
+        }
+    }
+}
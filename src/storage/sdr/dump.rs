@@ -0,0 +1,87 @@
+use super::{record::Record, RecordId};
+
+/// The raw contents of an SDR repository.
+///
+/// The binary representation of a dump is compatible with the files
+/// written by `ipmitool sdr dump`: all records, including their 5-byte
+/// headers, concatenated without any additional framing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SdrDump {
+    records: Vec<Vec<u8>>,
+}
+
+impl SdrDump {
+    const HEADER_LEN: usize = 5;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a dump from its binary representation.
+    ///
+    /// Returns `None` if the last record in `data` is truncated.
+    pub fn from_bytes(mut data: &[u8]) -> Option<Self> {
+        let mut records = Vec::new();
+
+        while !data.is_empty() {
+            if data.len() < Self::HEADER_LEN {
+                return None;
+            }
+
+            let record_len = Self::HEADER_LEN + data[4] as usize;
+
+            if data.len() < record_len {
+                return None;
+            }
+
+            let (record, rest) = data.split_at(record_len);
+            records.push(record.to_vec());
+            data = rest;
+        }
+
+        Some(Self { records })
+    }
+
+    /// Get the binary representation of this dump.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.records.concat()
+    }
+
+    /// Add the raw data of a record, including its header.
+    ///
+    /// Returns `false` and does not add the record if the length
+    /// of `record` does not match the length in its header.
+    pub fn push(&mut self, record: Vec<u8>) -> bool {
+        if record.len() < Self::HEADER_LEN || record.len() != Self::HEADER_LEN + record[4] as usize
+        {
+            return false;
+        }
+
+        self.records.push(record);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The raw data of all records, including their headers.
+    pub fn raw_records(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.records.iter().map(Vec::as_slice)
+    }
+
+    /// Parse all records in this dump.
+    ///
+    /// Records that cannot be parsed are returned as an error
+    /// containing their record ID.
+    pub fn records(&self) -> impl Iterator<Item = Result<Record, RecordId>> + '_ {
+        self.raw_records().map(|data| {
+            Record::parse(data)
+                .ok_or_else(|| RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])))
+        })
+    }
+}
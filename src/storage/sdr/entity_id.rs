@@ -0,0 +1,119 @@
+macro_rules! entity_id {
+    {
+        pub enum EntityId {
+            $($name:ident = $value:literal,)*
+            [$chassis_specific_range:pat],
+            [$board_set_specific_range:pat],
+            [$oem_range:pat],
+        }
+    } => {
+        /// The kind of physical or logical entity that a record refers to.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum EntityId {
+            $($name,)*
+            ChassisSpecific(u8),
+            BoardSetSpecific(u8),
+            Oem(u8),
+            Reserved(u8),
+        }
+
+        impl From<u8> for EntityId {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($value => Self::$name,)*
+                    $chassis_specific_range => Self::ChassisSpecific(value),
+                    $board_set_specific_range => Self::BoardSetSpecific(value),
+                    $oem_range => Self::Oem(value),
+                    v => Self::Reserved(v),
+                }
+            }
+        }
+
+        impl From<EntityId> for u8 {
+            fn from(value: EntityId) -> u8 {
+                match value {
+                    $(EntityId::$name => $value,)*
+                    EntityId::ChassisSpecific(v) => v,
+                    EntityId::BoardSetSpecific(v) => v,
+                    EntityId::Oem(v) => v,
+                    EntityId::Reserved(v) => v,
+                }
+            }
+        }
+    }
+}
+
+entity_id! {
+    pub enum EntityId {
+        Unspecified = 0x00,
+        Other = 0x01,
+        Unknown = 0x02,
+        Processor = 0x03,
+        Disk = 0x04,
+        PeripheralBay = 0x05,
+        SystemManagementModule = 0x06,
+        SystemBoard = 0x07,
+        MemoryModule = 0x08,
+        ProcessorModule = 0x09,
+        PowerSupply = 0x0A,
+        AddInCard = 0x0B,
+        FrontPanelBoard = 0x0C,
+        BackPanelBoard = 0x0D,
+        PowerSystemBoard = 0x0E,
+        DriveBackplane = 0x0F,
+        SystemInternalExpansionBoard = 0x10,
+        OtherSystemBoard = 0x11,
+        ProcessorBoard = 0x12,
+        PowerUnit = 0x13,
+        PowerModule = 0x14,
+        PowerManagement = 0x15,
+        ChassisBackPanelBoard = 0x16,
+        SystemChassis = 0x17,
+        SubChassis = 0x18,
+        OtherChassisBoard = 0x19,
+        DiskDriveBay = 0x1A,
+        PeripheralBay2 = 0x1B,
+        DeviceBay = 0x1C,
+        Fan = 0x1D,
+        CoolingUnit = 0x1E,
+        Cable = 0x1F,
+        MemoryDevice = 0x20,
+        SystemManagementSoftware = 0x21,
+        SystemFirmware = 0x22,
+        OperatingSystem = 0x23,
+        SystemBus = 0x24,
+        Group = 0x25,
+        RemoteManagementCommunicationDevice = 0x26,
+        ExternalEnvironment = 0x27,
+        Battery = 0x28,
+        ProcessingBlade = 0x29,
+        ConnectivitySwitch = 0x2A,
+        ProcessorMemoryModule = 0x2B,
+        IoModule = 0x2C,
+        ProcessorIoModule = 0x2D,
+        ManagementControllerFirmware = 0x2E,
+        IpmiChannel = 0x2F,
+        PciBus = 0x30,
+        PciExpressBus = 0x31,
+        ScsiBus = 0x32,
+        SataSasBus = 0x33,
+        ProcessorFrontSideBus = 0x34,
+        RealTimeClock = 0x35,
+        AirInlet = 0x37,
+        DcmiAirInlet = 0x40,
+        DcmiProcessor = 0x41,
+        DcmiBaseboard = 0x42,
+        [0x90..=0xAF],
+        [0xB0..=0xCF],
+        [0xD0..=0xFF],
+    }
+}
+
+#[test]
+fn entity_id_round_trip() {
+    for x in 0u8..=255u8 {
+        let id = EntityId::from(x);
+        let value: u8 = id.into();
+        assert_eq!(x, value);
+    }
+}
@@ -0,0 +1,227 @@
+use super::{
+    record::{Entity, Record, RecordContents},
+    EntityId,
+};
+
+/// A tree of entities, built from the Entity Association and Device-relative
+/// Entity Association records of an SDR repository.
+///
+/// Device-relative entities are identified by their Entity ID and instance only,
+/// so device-relative instances of entities on different devices are not told
+/// apart.
+#[derive(Debug, Clone, Default)]
+pub struct EntityTree {
+    /// Pairs of (container, contained entity).
+    associations: Vec<(Entity, Entity)>,
+}
+
+impl EntityTree {
+    pub fn new<'a>(records: impl IntoIterator<Item = &'a Record>) -> Self {
+        let mut associations = Vec::new();
+
+        let mut add = |container: Entity, contained: Entity| {
+            if !associations.contains(&(container, contained)) {
+                associations.push((container, contained));
+            }
+        };
+
+        for record in records {
+            match &record.contents {
+                RecordContents::EntityAssociation(association) => association
+                    .contained_entities
+                    .entities()
+                    .into_iter()
+                    .for_each(|contained| add(association.container, contained)),
+                RecordContents::DeviceRelativeEntityAssociation(association) => association
+                    .contained_entities
+                    .entities()
+                    .into_iter()
+                    .for_each(|contained| add(association.container.entity, contained.entity)),
+                _ => {}
+            }
+        }
+
+        Self { associations }
+    }
+
+    /// Get the entities that are directly contained by `container`.
+    pub fn children<'a>(&'a self, container: &'a Entity) -> impl Iterator<Item = &'a Entity> {
+        self.associations
+            .iter()
+            .filter(move |(c, _)| c == container)
+            .map(|(_, contained)| contained)
+    }
+
+    /// Get the entity that directly contains `entity`, if any.
+    pub fn parent(&self, entity: &Entity) -> Option<&Entity> {
+        self.associations
+            .iter()
+            .find(|(_, contained)| contained == entity)
+            .map(|(container, _)| container)
+    }
+
+    /// Get all entities that are contained by `container`, directly
+    /// or indirectly.
+    pub fn descendants(&self, container: &Entity) -> Vec<Entity> {
+        let mut descendants: Vec<Entity> = Vec::new();
+        let mut idx = 0;
+
+        descendants.extend(self.children(container));
+
+        // Guard against association records that form a cycle.
+        while idx < descendants.len() {
+            let current = descendants[idx];
+
+            for child in self.children(&current) {
+                if child != container && !descendants.contains(child) {
+                    descendants.push(*child);
+                }
+            }
+
+            idx += 1;
+        }
+
+        descendants
+    }
+
+    /// Get all entities that contain `entity`, starting with
+    /// its direct parent.
+    pub fn ancestors(&self, entity: &Entity) -> Vec<Entity> {
+        let mut ancestors: Vec<Entity> = Vec::new();
+        let mut current = entity;
+
+        while let Some(parent) = self.parent(current) {
+            // Guard against association records that form a cycle.
+            if parent == entity || ancestors.contains(parent) {
+                break;
+            }
+
+            ancestors.push(*parent);
+            current = parent;
+        }
+
+        ancestors
+    }
+
+    /// Determine whether `entity` is contained by `container`, directly
+    /// or indirectly.
+    pub fn is_contained_by(&self, entity: &Entity, container: &Entity) -> bool {
+        self.ancestors(entity).contains(container)
+    }
+
+    /// Find the entity with Entity ID `entity_id` that `entity` belongs to.
+    ///
+    /// This is `entity` itself if it has the requested Entity ID, or else
+    /// its closest ancestor with that Entity ID. This can be used to
+    /// determine, for instance, which DIMM a sensor belongs to.
+    pub fn find_ancestor(&self, entity: &Entity, entity_id: EntityId) -> Option<Entity> {
+        if entity.entity_id == entity_id {
+            return Some(*entity);
+        }
+
+        self.ancestors(entity)
+            .into_iter()
+            .find(|ancestor| ancestor.entity_id == entity_id)
+    }
+
+    /// Get all records in `records` that belong to `container`, or to an
+    /// entity that it contains.
+    pub fn records_under<'a>(
+        &self,
+        container: &Entity,
+        records: impl IntoIterator<Item = &'a Record>,
+    ) -> impl Iterator<Item = &'a Record> {
+        let mut entities = self.descendants(container);
+        entities.push(*container);
+
+        records.into_iter().filter(move |record| {
+            record
+                .entity()
+                .map(|entity| entities.contains(&entity))
+                .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(ty: u8, body: &[u8]) -> Record {
+        let mut data = vec![0x00, 0x00, 0x51, ty, body.len() as u8];
+        data.extend_from_slice(body);
+        Record::parse(&data).unwrap()
+    }
+
+    #[test]
+    fn sensors_under_entity() {
+        let chassis = Entity::new(EntityId::SystemChassis, 1);
+        let board = Entity::new(EntityId::SystemBoard, 1);
+        let psu_1 = Entity::new(EntityId::PowerSupply, 1);
+        let psu_2 = Entity::new(EntityId::PowerSupply, 2);
+        let dimm_3 = Entity::new(EntityId::MemoryDevice, 3);
+
+        let records = [
+            // Chassis contains system board and PSU 1 and 2 (as a range)
+            record(0x08, &[0x17, 0x01, 0x00, 0x07, 0x01, 0, 0, 0, 0, 0, 0]),
+            record(
+                0x08,
+                &[0x17, 0x01, 0xC0, 0x0A, 0x01, 0x0A, 0x02, 0, 0, 0, 0],
+            ),
+            // System board contains DIMMs 1 through 4
+            record(
+                0x08,
+                &[0x07, 0x01, 0x80, 0x20, 0x01, 0x20, 0x04, 0, 0, 0, 0],
+            ),
+            // Event-only sensors for PSU 2 and DIMM 3
+            record(
+                0x03,
+                &[
+                    0x20, 0x00, 0x10, 0x0A, 0x02, 0x08, 0x6F, 0, 0, 0, 0, 0xC3, b'P', b'S', b'2',
+                ],
+            ),
+            record(
+                0x03,
+                &[
+                    0x20, 0x00, 0x11, 0x20, 0x03, 0x0C, 0x6F, 0, 0, 0, 0, 0xC3, b'D', b'I', b'3',
+                ],
+            ),
+        ];
+
+        let tree = EntityTree::new(&records);
+
+        assert_eq!(tree.parent(&psu_2), Some(&chassis));
+        assert_eq!(tree.children(&board).count(), 4);
+        assert_eq!(tree.ancestors(&dimm_3), vec![board, chassis]);
+        assert!(tree.is_contained_by(&dimm_3, &chassis));
+        assert!(!tree.is_contained_by(&psu_1, &board));
+        assert_eq!(
+            tree.find_ancestor(&dimm_3, EntityId::SystemBoard),
+            Some(board)
+        );
+
+        let under_psu_2: Vec<_> = tree
+            .records_under(&psu_2, &records)
+            .filter_map(|r| r.sensor_number())
+            .map(|n| n.get())
+            .collect();
+        assert_eq!(under_psu_2, vec![0x10]);
+
+        let under_chassis = tree.records_under(&chassis, &records).count();
+        assert_eq!(under_chassis, 2);
+    }
+
+    #[test]
+    fn cyclic_associations_terminate() {
+        let records = [
+            record(0x08, &[0x07, 0x01, 0x00, 0x0F, 0x01, 0, 0, 0, 0, 0, 0]),
+            record(0x08, &[0x0F, 0x01, 0x00, 0x07, 0x01, 0, 0, 0, 0, 0, 0]),
+        ];
+
+        let tree = EntityTree::new(&records);
+        let board = Entity::new(EntityId::SystemBoard, 1);
+
+        assert_eq!(tree.descendants(&board).len(), 1);
+        assert_eq!(tree.ancestors(&board).len(), 1);
+    }
+}
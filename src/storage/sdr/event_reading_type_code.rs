@@ -88,6 +88,19 @@ macro_rules! event_reading_code {
             }
         }
 
+        impl From<EventReadingTypeCodes> for u8 {
+            fn from(value: EventReadingTypeCodes) -> Self {
+                match value {
+                    EventReadingTypeCodes::Unspecified => 0x00,
+                    EventReadingTypeCodes::Threshold => 0x01,
+                    EventReadingTypeCodes::DiscreteGeneric(v) => v,
+                    EventReadingTypeCodes::SensorSpecific => 0x6F,
+                    EventReadingTypeCodes::Oem(v) => v,
+                    EventReadingTypeCodes::Reserved(v) => v,
+                }
+            }
+        }
+
         pub enum EventReading {
             Threshold(Threshold),
             UsageState(UsageState),
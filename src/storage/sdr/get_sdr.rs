@@ -53,6 +53,65 @@ impl From<GetDeviceSdr> for Message {
     }
 }
 
+/// Get part of a device SDR.
+///
+/// This command is used to read SDRs that are too large to be returned
+/// by the BMC in a single response. A valid reservation, obtained using
+/// [`ReserveSdrRepository`], is required.
+///
+/// [`ReserveSdrRepository`]: super::ReserveSdrRepository
+#[derive(Debug, Clone, Copy)]
+pub struct GetDeviceSdrPartial {
+    inner: GetDeviceSdr,
+}
+
+impl GetDeviceSdrPartial {
+    pub fn new(
+        reservation_id: NonZeroU16,
+        record_id: RecordId,
+        offset: u8,
+        bytes_to_read: NonMaxU8,
+    ) -> Self {
+        Self {
+            inner: GetDeviceSdr {
+                reservation_id: Some(reservation_id),
+                record_id,
+                offset,
+                bytes_to_read: Some(bytes_to_read),
+            },
+        }
+    }
+
+    /// Read an entire record without a reservation, without
+    /// parsing its contents.
+    pub(crate) fn whole_record(record_id: RecordId) -> Self {
+        Self {
+            inner: GetDeviceSdr::new(None, record_id),
+        }
+    }
+}
+
+impl From<GetDeviceSdrPartial> for Message {
+    fn from(value: GetDeviceSdrPartial) -> Self {
+        value.inner.into()
+    }
+}
+
+impl IpmiCommand for GetDeviceSdrPartial {
+    type Output = PartialRecordInfo;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: crate::connection::CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        PartialRecordInfo::parse(data).ok_or(ParseResponseError::NotEnoughData)
+    }
+}
+
 impl IpmiCommand for GetDeviceSdr {
     type Output = RecordInfo;
 
@@ -86,3 +145,24 @@ impl RecordInfo {
         Record::parse(data).map(|record| Self { next_entry, record })
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct PartialRecordInfo {
+    pub next_entry: RecordId,
+    pub data: Vec<u8>,
+}
+
+impl PartialRecordInfo {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        let next_entry = RecordId::new_raw(u16::from_le_bytes([data[0], data[1]]));
+
+        Some(Self {
+            next_entry,
+            data: data[2..].to_vec(),
+        })
+    }
+}
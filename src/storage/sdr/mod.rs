@@ -2,7 +2,32 @@ mod get_dev_sdr_info;
 pub use get_dev_sdr_info::*;
 
 mod get_sdr;
-pub use get_sdr::{GetDeviceSdr, RecordInfo as SdrRecordInfo, *};
+pub use get_sdr::{
+    GetDeviceSdr, GetDeviceSdrPartial, PartialRecordInfo as PartialSdrRecordInfo,
+    RecordInfo as SdrRecordInfo, *,
+};
+
+mod reserve;
+pub use reserve::ReserveRepository as ReserveSdrRepository;
+
+mod modify;
+pub use modify::{
+    AddRecord as AddSdr, DeleteRecord as DeleteSdr, PartialAddRecord as PartialAddSdr,
+};
+
+mod clear;
+pub use clear::{
+    ClearAction as ClearSdrAction, ClearRepository as ClearSdrRepository,
+    ErasureProgress as SdrErasureProgress,
+};
+
+mod update_mode;
+pub use update_mode::{
+    EnterUpdateMode as EnterSdrRepositoryUpdateMode, ExitUpdateMode as ExitSdrRepositoryUpdateMode,
+};
+
+mod writer;
+pub use writer::{SdrWriteError, SdrWriter};
 
 pub mod record;
 pub use record::Record;
@@ -21,10 +46,22 @@ pub mod event_reading_type_code;
 mod sensor_type;
 pub use sensor_type::SensorType;
 
+mod entity_id;
+pub use entity_id::EntityId;
+
+mod entity_tree;
+pub use entity_tree::EntityTree;
+
+mod dump;
+pub use dump::SdrDump;
+
+mod cache;
+pub use cache::{SdrCache, SdrCacheError};
+
 mod units;
 pub use units::Unit;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct RecordId(u16);
 
 impl RecordId {
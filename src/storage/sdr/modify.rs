@@ -0,0 +1,156 @@
+use std::num::NonZeroU16;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+use super::RecordId;
+
+fn parse_record_id<E>(data: &[u8]) -> Result<RecordId, ParseResponseError<E>> {
+    if data.len() < 2 {
+        return Err(ParseResponseError::NotEnoughData);
+    }
+
+    Ok(RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])))
+}
+
+/// Add a record to the SDR repository.
+///
+/// `record` must contain the complete record, including its header. The
+/// record ID in the header is ignored, and the ID assigned to the record
+/// by the BMC is returned.
+#[derive(Debug, Clone)]
+pub struct AddRecord {
+    record: Vec<u8>,
+}
+
+impl AddRecord {
+    pub fn new(record: Vec<u8>) -> Self {
+        Self { record }
+    }
+}
+
+impl From<AddRecord> for Message {
+    fn from(value: AddRecord) -> Self {
+        Message::new_request(NetFn::Storage, 0x24, value.record)
+    }
+}
+
+impl IpmiCommand for AddRecord {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
+
+/// Add part of a record to the SDR repository.
+///
+/// Records are added by sending their data in order, starting at offset 0.
+/// The first part must be sent with [`RecordId::FIRST`], and subsequent
+/// parts must use the record ID returned for the first part. The record is
+/// only added to the repository once the part with `last` set is sent.
+#[derive(Debug, Clone)]
+pub struct PartialAddRecord {
+    reservation_id: NonZeroU16,
+    record_id: RecordId,
+    offset: u8,
+    last: bool,
+    data: Vec<u8>,
+}
+
+impl PartialAddRecord {
+    pub fn new(
+        reservation_id: NonZeroU16,
+        record_id: RecordId,
+        offset: u8,
+        last: bool,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+            offset,
+            last,
+            data,
+        }
+    }
+}
+
+impl From<PartialAddRecord> for Message {
+    fn from(value: PartialAddRecord) -> Self {
+        let mut data = Vec::with_capacity(6 + value.data.len());
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+        data.push(value.offset);
+        data.push(if value.last { 0x01 } else { 0x00 });
+        data.extend_from_slice(&value.data);
+
+        Message::new_request(NetFn::Storage, 0x25, data)
+    }
+}
+
+impl IpmiCommand for PartialAddRecord {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
+
+/// Delete a record from the SDR repository.
+///
+/// Returns the ID of the deleted record.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteRecord {
+    reservation_id: NonZeroU16,
+    record_id: RecordId,
+}
+
+impl DeleteRecord {
+    pub fn new(reservation_id: NonZeroU16, record_id: RecordId) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+        }
+    }
+}
+
+impl From<DeleteRecord> for Message {
+    fn from(value: DeleteRecord) -> Self {
+        let mut data = Vec::with_capacity(4);
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+
+        Message::new_request(NetFn::Storage, 0x26, data)
+    }
+}
+
+impl IpmiCommand for DeleteRecord {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
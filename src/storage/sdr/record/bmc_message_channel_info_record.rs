@@ -0,0 +1,145 @@
+use crate::{log_vec, Loggable};
+
+use super::ReservedBits;
+
+/// The interrupt used by the BMC for a specific purpose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterruptType {
+    Irq(u8),
+    /// PCI interrupt A through D, where 0 is A.
+    Pci(u8),
+    Smi,
+    Sci,
+    SystemInterrupt(u8),
+    AssignedByAcpiOrPnpBios,
+    None,
+    Reserved(u8),
+}
+
+impl From<u8> for InterruptType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00..=0x0F => Self::Irq(value),
+            0x10..=0x13 => Self::Pci(value - 0x10),
+            0x14 => Self::Smi,
+            0x15 => Self::Sci,
+            0x20..=0x5F => Self::SystemInterrupt(value - 0x20),
+            0x60 => Self::AssignedByAcpiOrPnpBios,
+            0xFF => Self::None,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<InterruptType> for u8 {
+    fn from(value: InterruptType) -> Self {
+        match value {
+            InterruptType::Irq(v) => v,
+            InterruptType::Pci(v) => 0x10 + v,
+            InterruptType::Smi => 0x14,
+            InterruptType::Sci => 0x15,
+            InterruptType::SystemInterrupt(v) => 0x20 + v,
+            InterruptType::AssignedByAcpiOrPnpBios => 0x60,
+            InterruptType::None => 0xFF,
+            InterruptType::Reserved(v) => v,
+        }
+    }
+}
+
+/// Information about a single BMC message channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MessageChannelInfo {
+    pub transmit_supported: bool,
+    pub message_receive_lun: u8,
+    pub channel_protocol: u8,
+}
+
+impl From<u8> for MessageChannelInfo {
+    fn from(value: u8) -> Self {
+        Self {
+            transmit_supported: (value & 0x80) == 0x80,
+            message_receive_lun: (value >> 4) & 0x7,
+            channel_protocol: value & 0xF,
+        }
+    }
+}
+
+impl From<MessageChannelInfo> for u8 {
+    fn from(value: MessageChannelInfo) -> Self {
+        (value.transmit_supported as u8) << 7
+            | (value.message_receive_lun & 0x7) << 4
+            | (value.channel_protocol & 0xF)
+    }
+}
+
+/// A BMC Message Channel Info record.
+///
+/// Describes the message channels (0 through 7) of the BMC, and the
+/// interrupts it uses for messaging and the event message buffer.
+#[derive(Debug, Clone)]
+pub struct BmcMessageChannelInfoRecord {
+    pub channels: [MessageChannelInfo; 8],
+    pub messaging_interrupt: InterruptType,
+    pub event_message_buffer_interrupt: InterruptType,
+    pub reserved: ReservedBits,
+}
+
+impl BmcMessageChannelInfoRecord {
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 10 {
+            return None;
+        }
+
+        let mut channels = [MessageChannelInfo::from(0); 8];
+        channels
+            .iter_mut()
+            .zip(record_data[..8].iter())
+            .for_each(|(channel, value)| *channel = MessageChannelInfo::from(*value));
+
+        Some(Self {
+            channels,
+            messaging_interrupt: InterruptType::from(record_data[8]),
+            event_message_buffer_interrupt: InterruptType::from(record_data[9]),
+            // The record data ends with a reserved byte.
+            reserved: ReservedBits::parse(record_data, &[0; 10]),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        buffer.extend(self.channels.iter().map(|c| u8::from(*c)));
+        buffer.push(self.messaging_interrupt.into());
+        buffer.push(self.event_message_buffer_interrupt.into());
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for BmcMessageChannelInfoRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let mut log = log_vec![
+            (0, "SDR Record (BMC Message Channel Info)"),
+            (
+                1,
+                "Messaging interrupt",
+                format!("{:?}", self.messaging_interrupt)
+            ),
+            (
+                1,
+                "Event msg. buffer int.",
+                format!("{:?}", self.event_message_buffer_interrupt)
+            ),
+        ];
+
+        self.channels.iter().enumerate().for_each(|(idx, info)| {
+            let protocol = info.channel_protocol;
+            let value = format!(
+                "Protocol: 0x{protocol:01X}, receive LUN: {}, transmit supported: {}",
+                info.message_receive_lun, info.transmit_supported
+            );
+            log.push((1, format!("Channel {idx}"), value).into());
+        });
+
+        log
+    }
+}
@@ -14,6 +14,19 @@ pub struct RecordSharing {
     pub modifier_offset: u8,
 }
 
+impl RecordSharing {
+    /// Write the two sensor direction and record sharing bytes.
+    pub(crate) fn write_data(&self, direction: Direction, buffer: &mut Vec<u8>) {
+        let id_string_modifier = match self.id_string_modifier {
+            IdStringModifier::Numeric => 0b00,
+            IdStringModifier::Alpha => 0b01,
+        };
+
+        buffer.push(u8::from(direction) << 6 | id_string_modifier << 4 | (self.share_count & 0xF));
+        buffer.push((self.entity_instance_increments as u8) << 7 | (self.modifier_offset & 0x7F));
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CompactSensorRecord {
     common: SensorRecordCommon,
@@ -22,6 +35,7 @@ pub struct CompactSensorRecord {
     pub positive_going_threshold_hysteresis_value: u8,
     pub negative_going_threshold_hysteresis_value: u8,
     pub oem_data: u8,
+    reserved: ReservedBits,
 }
 
 impl SensorRecord for CompactSensorRecord {
@@ -35,26 +49,37 @@ impl SensorRecord for CompactSensorRecord {
 }
 
 impl CompactSensorRecord {
+    /// The reserved bits of the record data that follows the common
+    /// sensor record data, up to and including the ID string type/length byte.
+    const RESERVED: [u8; 9] = [0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x20];
+
     pub fn parse(record_data: &[u8]) -> Option<Self> {
-        if record_data.len() < 26 {
+        if record_data.len() < 27 {
             return None;
         }
 
+        let mut reserved_mask = SensorRecordCommon::reserved_mask(record_data).to_vec();
+        // Compact sensors do not have an analog data format.
+        reserved_mask[15] |= 0xC0;
+        reserved_mask.extend_from_slice(&Self::RESERVED);
+        let reserved = ReservedBits::parse(&record_data[..reserved_mask.len()], &reserved_mask);
+
         let (mut common, record_data) = SensorRecordCommon::parse_without_id(record_data)?;
 
         let direction_sharing_1 = record_data[0];
         let direction_sharing_2 = record_data[1];
 
-        let direction = Direction::try_from((direction_sharing_1 & 0xC) >> 6).unwrap();
+        let direction = Direction::try_from((direction_sharing_1 & 0xC0) >> 6)
+            .unwrap_or(Direction::UnspecifiedNotApplicable);
         let id_string_instance_modifier = match (direction_sharing_1 & 0x30) >> 4 {
             0b00 => IdStringModifier::Numeric,
             0b01 => IdStringModifier::Alpha,
-            _ => panic!("Invalid ID string modifier, no fallback available."),
+            _ => return None,
         };
 
         let share_count = direction_sharing_1 & 0xF;
         let entity_instance_increments = (direction_sharing_2 & 0x80) == 0x80;
-        let modifier_offset = direction_sharing_2 & 0x3F;
+        let modifier_offset = direction_sharing_2 & 0x7F;
 
         let record_sharing = RecordSharing {
             id_string_modifier: id_string_instance_modifier,
@@ -83,6 +108,126 @@ impl CompactSensorRecord {
             positive_going_threshold_hysteresis_value,
             negative_going_threshold_hysteresis_value,
             oem_data,
+            reserved,
         })
     }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        self.common.write_data(buffer);
+        self.record_sharing.write_data(self.direction, buffer);
+        buffer.push(self.positive_going_threshold_hysteresis_value);
+        buffer.push(self.negative_going_threshold_hysteresis_value);
+        // Three reserved bytes
+        buffer.extend_from_slice(&[0, 0, 0]);
+        buffer.push(self.oem_data);
+        self.common.sensor_id.write_data(buffer);
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+/// A builder for [`CompactSensorRecord`]s.
+#[derive(Debug, Clone)]
+pub struct CompactSensorRecordBuilder {
+    record: CompactSensorRecord,
+}
+
+impl CompactSensorRecordBuilder {
+    /// Create a new builder for a single, threshold-based sensor.
+    pub fn new(key: SensorKey, ty: SensorType, sensor_units: SensorUnits) -> Self {
+        let common = SensorRecordCommon {
+            key,
+            entity_id: EntityId::Unspecified,
+            entity_instance: EntityInstance::from(0),
+            initialization: Default::default(),
+            capabilities: Default::default(),
+            ty,
+            event_reading_type_code: EventReadingTypeCodes::Threshold,
+            sensor_units,
+            sensor_id: Default::default(),
+        };
+
+        Self {
+            record: CompactSensorRecord {
+                common,
+                direction: Direction::UnspecifiedNotApplicable,
+                record_sharing: RecordSharing {
+                    id_string_modifier: IdStringModifier::Numeric,
+                    share_count: 1,
+                    entity_instance_increments: false,
+                    modifier_offset: 0,
+                },
+                positive_going_threshold_hysteresis_value: 0,
+                negative_going_threshold_hysteresis_value: 0,
+                oem_data: 0,
+                reserved: Default::default(),
+            },
+        }
+    }
+
+    pub fn entity(mut self, entity: Entity) -> Self {
+        self.record.common.entity_id = entity.entity_id;
+        self.record.common.entity_instance = entity.entity_instance;
+        self
+    }
+
+    pub fn event_reading_type_code(mut self, code: EventReadingTypeCodes) -> Self {
+        self.record.common.event_reading_type_code = code;
+        self
+    }
+
+    pub fn initialization(mut self, initialization: SensorInitialization) -> Self {
+        self.record.common.initialization = initialization;
+        self
+    }
+
+    pub fn capabilities(mut self, capabilities: SensorCapabilities) -> Self {
+        self.record.common.capabilities = capabilities;
+        self
+    }
+
+    /// Set the ID string, which may be at most 16 bytes long.
+    pub fn id_string(mut self, id_string: SensorId) -> Self {
+        self.record.common.sensor_id = id_string;
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.record.direction = direction;
+        self
+    }
+
+    /// Set the record sharing information. The share count may be at
+    /// most 15, and the modifier offset may be at most 127.
+    pub fn record_sharing(mut self, record_sharing: RecordSharing) -> Self {
+        self.record.record_sharing = record_sharing;
+        self
+    }
+
+    /// Set the raw positive- and negative-going threshold hysteresis values.
+    pub fn hysteresis(mut self, positive_going: u8, negative_going: u8) -> Self {
+        self.record.positive_going_threshold_hysteresis_value = positive_going;
+        self.record.negative_going_threshold_hysteresis_value = negative_going;
+        self
+    }
+
+    pub fn oem_data(mut self, oem_data: u8) -> Self {
+        self.record.oem_data = oem_data;
+        self
+    }
+
+    /// Build the record.
+    ///
+    /// Returns `None` if any of the values do not fit in their field.
+    pub fn build(self) -> Option<CompactSensorRecord> {
+        let record = self.record;
+        let sharing = &record.record_sharing;
+
+        let valid = sharing.share_count <= 0xF
+            && sharing.modifier_offset <= 0x7F
+            && record.common.sensor_id.encoded_len() <= 16;
+
+        valid.then_some(record)
+    }
 }
@@ -0,0 +1,429 @@
+use crate::{log_vec, Loggable};
+
+use super::*;
+
+/// An entity, identified by its Entity ID and Entity Instance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entity {
+    pub entity_id: EntityId,
+    pub entity_instance: EntityInstance,
+}
+
+impl Entity {
+    pub fn new(entity_id: EntityId, entity_instance: u8) -> Self {
+        Self {
+            entity_id,
+            entity_instance: EntityInstance::from(entity_instance),
+        }
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        let entity_id = EntityId::from(data[0]);
+
+        // An unspecified Entity ID indicates that the slot is unused.
+        if entity_id == EntityId::Unspecified {
+            None
+        } else {
+            Some(Self {
+                entity_id,
+                entity_instance: EntityInstance::from(data[1]),
+            })
+        }
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.entity_id.into());
+        buffer.push(self.entity_instance.into());
+    }
+
+    fn instances_up_to(self, last: Entity) -> impl Iterator<Item = Entity> {
+        let first_instance = u8::from(self.entity_instance);
+        let last_instance = u8::from(last.entity_instance);
+
+        (first_instance..=last_instance).map(move |instance| Entity {
+            entity_id: self.entity_id,
+            entity_instance: EntityInstance::from(instance),
+        })
+    }
+}
+
+/// A range of entities, from `first` up to and including `last`.
+///
+/// The Entity ID of `first` and `last` is the same, only the
+/// instance numbers differ.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityRange<T> {
+    pub first: T,
+    pub last: T,
+}
+
+/// The entities contained in an entity association.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainedEntities<T> {
+    List(Vec<T>),
+    Ranges(Vec<EntityRange<T>>),
+}
+
+impl<T: Copy> ContainedEntities<T> {
+    fn from_slots(is_range: bool, slots: impl Iterator<Item = Option<T>>) -> Self {
+        let slots: Vec<_> = slots.collect();
+
+        if is_range {
+            let ranges = slots
+                .chunks(2)
+                .filter_map(|range| match range {
+                    [Some(first), Some(last)] => Some(EntityRange {
+                        first: *first,
+                        last: *last,
+                    }),
+                    _ => None,
+                })
+                .collect();
+
+            Self::Ranges(ranges)
+        } else {
+            Self::List(slots.into_iter().flatten().collect())
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> ContainedEntities<T> {
+    /// Write the entity slots of a record, where every slot is `slot_len` bytes long.
+    ///
+    /// If these are the contained entities that were parsed from `raw_slots`, the
+    /// raw slots are written instead, so that the contents of unused slots are kept.
+    fn write_slots(
+        &self,
+        is_range: bool,
+        raw_slots: &[u8],
+        slot_len: usize,
+        parse: fn(&[u8]) -> Option<T>,
+        write: fn(&T, &mut Vec<u8>),
+        buffer: &mut Vec<u8>,
+    ) {
+        let parsed = Self::from_slots(is_range, raw_slots.chunks(slot_len).map(parse));
+
+        if !raw_slots.is_empty() && &parsed == self {
+            buffer.extend_from_slice(raw_slots);
+            return;
+        }
+
+        for slot in self.slots(4) {
+            match slot {
+                Some(entity) => write(&entity, buffer),
+                None => buffer.resize(buffer.len() + slot_len, 0),
+            }
+        }
+    }
+
+    /// Get the contents of the `count` entity slots of a record, where unused
+    /// slots are `None`. Entities that do not fit in the slots are dropped.
+    fn slots(&self, count: usize) -> Vec<Option<T>> {
+        let mut slots: Vec<_> = match self {
+            ContainedEntities::List(list) => list.iter().copied().map(Some).collect(),
+            ContainedEntities::Ranges(ranges) => ranges
+                .iter()
+                .flat_map(|r| [Some(r.first), Some(r.last)])
+                .collect(),
+        };
+
+        slots.resize(count, None);
+        slots
+    }
+}
+
+impl ContainedEntities<Entity> {
+    /// Get all contained entities, expanding ranges into
+    /// the individual entities that they cover.
+    pub fn entities(&self) -> Vec<Entity> {
+        match self {
+            ContainedEntities::List(list) => list.clone(),
+            ContainedEntities::Ranges(ranges) => ranges
+                .iter()
+                .flat_map(|r| r.first.instances_up_to(r.last))
+                .collect(),
+        }
+    }
+}
+
+impl ContainedEntities<DeviceRelativeEntity> {
+    /// Get all contained entities, expanding ranges into
+    /// the individual entities that they cover.
+    pub fn entities(&self) -> Vec<DeviceRelativeEntity> {
+        match self {
+            ContainedEntities::List(list) => list.clone(),
+            ContainedEntities::Ranges(ranges) => ranges
+                .iter()
+                .flat_map(|r| {
+                    let EntityRange { first, last } = *r;
+                    first
+                        .entity
+                        .instances_up_to(last.entity)
+                        .map(move |entity| DeviceRelativeEntity { entity, ..first })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> ContainedEntities<T> {
+    fn log_into(&self, level: usize, log: &mut Vec<crate::fmt::LogItem>) {
+        match self {
+            ContainedEntities::List(list) => {
+                log.push((level, "Contained entities").into());
+                list.iter()
+                    .for_each(|e| log.push((level + 1, format!("{e:?}")).into()));
+            }
+            ContainedEntities::Ranges(ranges) => {
+                log.push((level, "Contained entity ranges").into());
+                ranges.iter().for_each(|r| {
+                    log.push((level + 1, format!("{:?}..={:?}", r.first, r.last)).into())
+                });
+            }
+        }
+    }
+}
+
+/// Flags shared by both kinds of entity association records.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityAssociationFlags {
+    /// The contained entities are specified as ranges, instead of as a list.
+    pub range: bool,
+    /// Other association records with the same container entity exist.
+    pub linked: bool,
+    /// The presence sensor for the container entity should always be accessible.
+    ///
+    /// If this is `false`, the container entity and the contained entities can be
+    /// assumed absent if the presence sensor of the container entity cannot be
+    /// accessed.
+    pub presence_sensor_always_accessible: bool,
+}
+
+impl From<u8> for EntityAssociationFlags {
+    fn from(value: u8) -> Self {
+        Self {
+            range: (value & 0x80) == 0x80,
+            linked: (value & 0x40) == 0x40,
+            presence_sensor_always_accessible: (value & 0x20) == 0x20,
+        }
+    }
+}
+
+impl From<EntityAssociationFlags> for u8 {
+    fn from(value: EntityAssociationFlags) -> Self {
+        (value.range as u8) << 7
+            | (value.linked as u8) << 6
+            | (value.presence_sensor_always_accessible as u8) << 5
+    }
+}
+
+/// An Entity Association record.
+///
+/// Describes which entities are contained by the container entity.
+#[derive(Debug, Clone)]
+pub struct EntityAssociationRecord {
+    pub container: Entity,
+    pub flags: EntityAssociationFlags,
+    pub contained_entities: ContainedEntities<Entity>,
+    pub reserved: ReservedBits,
+    /// The raw entity slots of a parsed record.
+    slots: Vec<u8>,
+}
+
+impl EntityAssociationRecord {
+    /// The reserved bits of the record data, excluding the entity slots.
+    const RESERVED: [u8; 11] = [0x00, 0x00, 0x1F, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    pub fn new(
+        container: Entity,
+        flags: EntityAssociationFlags,
+        contained_entities: ContainedEntities<Entity>,
+    ) -> Self {
+        Self {
+            container,
+            flags,
+            contained_entities,
+            reserved: Default::default(),
+            slots: Vec::new(),
+        }
+    }
+
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 11 {
+            return None;
+        }
+
+        let container = Entity::parse(&record_data[0..2])?;
+        let flags = EntityAssociationFlags::from(record_data[2]);
+
+        let slots = record_data[3..11].chunks(2).map(Entity::parse);
+        let contained_entities = ContainedEntities::from_slots(flags.range, slots);
+
+        Some(Self {
+            container,
+            flags,
+            contained_entities,
+            reserved: ReservedBits::parse(record_data, &Self::RESERVED),
+            slots: record_data[3..11].to_vec(),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    ///
+    /// Unused entity slots are written as zeroes, unless they
+    /// were parsed together with the contained entities.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        self.container.write_data(buffer);
+        buffer.push(self.flags.into());
+
+        self.contained_entities.write_slots(
+            self.flags.range,
+            &self.slots,
+            2,
+            Entity::parse,
+            Entity::write_data,
+            buffer,
+        );
+
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for EntityAssociationRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let mut log = log_vec![
+            (0, "SDR Record (Entity Association)"),
+            (1, "Container entity", format!("{:?}", self.container)),
+            (1, "Linked", self.flags.linked),
+        ];
+
+        self.contained_entities.log_into(1, &mut log);
+
+        log
+    }
+}
+
+/// An entity that is located on a specific device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceRelativeEntity {
+    pub address: Address,
+    pub channel: u8,
+    pub entity: Entity,
+}
+
+impl DeviceRelativeEntity {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let entity = Entity::parse(&data[2..4])?;
+
+        Some(Self {
+            address: Address(data[0] & 0xFE),
+            channel: (data[1] >> 4) & 0xF,
+            entity,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.address.0 & 0xFE);
+        buffer.push((self.channel & 0xF) << 4);
+        self.entity.write_data(buffer);
+    }
+}
+
+/// A Device-relative Entity Association record.
+///
+/// Describes which device-relative entities are contained by the
+/// container entity.
+#[derive(Debug, Clone)]
+pub struct DeviceRelativeEntityAssociationRecord {
+    pub container: DeviceRelativeEntity,
+    pub flags: EntityAssociationFlags,
+    pub contained_entities: ContainedEntities<DeviceRelativeEntity>,
+    pub reserved: ReservedBits,
+    /// The raw entity slots of a parsed record.
+    slots: Vec<u8>,
+}
+
+impl DeviceRelativeEntityAssociationRecord {
+    /// The reserved bits of the record data, excluding the entity slots.
+    const RESERVED: [u8; 21] = [
+        0x00, 0x00, 0x01, 0x0F, 0x1F, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    pub fn new(
+        container: DeviceRelativeEntity,
+        flags: EntityAssociationFlags,
+        contained_entities: ContainedEntities<DeviceRelativeEntity>,
+    ) -> Self {
+        Self {
+            container,
+            flags,
+            contained_entities,
+            reserved: Default::default(),
+            slots: Vec::new(),
+        }
+    }
+
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 21 {
+            return None;
+        }
+
+        let container = DeviceRelativeEntity {
+            address: Address(record_data[2] & 0xFE),
+            channel: (record_data[3] >> 4) & 0xF,
+            entity: Entity::parse(&record_data[0..2])?,
+        };
+        let flags = EntityAssociationFlags::from(record_data[4]);
+
+        let slots = record_data[5..21]
+            .chunks(4)
+            .map(DeviceRelativeEntity::parse);
+        let contained_entities = ContainedEntities::from_slots(flags.range, slots);
+
+        Some(Self {
+            container,
+            flags,
+            contained_entities,
+            reserved: ReservedBits::parse(record_data, &Self::RESERVED),
+            slots: record_data[5..21].to_vec(),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    ///
+    /// Unused entity slots are written as zeroes, unless they
+    /// were parsed together with the contained entities.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        self.container.entity.write_data(buffer);
+        buffer.push(self.container.address.0 & 0xFE);
+        buffer.push((self.container.channel & 0xF) << 4);
+        buffer.push(self.flags.into());
+
+        self.contained_entities.write_slots(
+            self.flags.range,
+            &self.slots,
+            4,
+            DeviceRelativeEntity::parse,
+            DeviceRelativeEntity::write_data,
+            buffer,
+        );
+
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for DeviceRelativeEntityAssociationRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let mut log = log_vec![
+            (0, "SDR Record (Device-relative Entity Association)"),
+            (1, "Container entity", format!("{:?}", self.container)),
+            (1, "Linked", self.flags.linked),
+        ];
+
+        self.contained_entities.log_into(1, &mut log);
+
+        log
+    }
+}
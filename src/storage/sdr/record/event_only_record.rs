@@ -0,0 +1,118 @@
+use crate::{log_vec, Loggable};
+
+use super::*;
+
+/// An Event-Only sensor record.
+///
+/// These records describe sensors that only generate events, and
+/// can therefore not be read using `Get Sensor Reading`.
+#[derive(Debug, Clone)]
+pub struct EventOnlyRecord {
+    pub key: SensorKey,
+    pub entity_id: EntityId,
+    pub entity_instance: EntityInstance,
+    pub ty: SensorType,
+    pub event_reading_type_code: EventReadingTypeCodes,
+    pub direction: Direction,
+    pub record_sharing: RecordSharing,
+    pub oem_data: u8,
+    pub id_string: SensorId,
+    pub reserved: ReservedBits,
+}
+
+impl EventOnlyRecord {
+    /// The reserved bits of the record data, up to and
+    /// including the ID string type/length byte.
+    const RESERVED: [u8; 12] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x20,
+    ];
+
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 12 {
+            return None;
+        }
+
+        let key = SensorKey::parse(&record_data[..3])?;
+        let entity_id = record_data[3].into();
+        let entity_instance = EntityInstance::from(record_data[4]);
+        let ty = record_data[5].into();
+        let event_reading_type_code = record_data[6].into();
+
+        let direction_sharing_1 = record_data[7];
+        let direction_sharing_2 = record_data[8];
+
+        let direction = Direction::try_from((direction_sharing_1 & 0xC0) >> 6)
+            .unwrap_or(Direction::UnspecifiedNotApplicable);
+
+        let id_string_modifier = match (direction_sharing_1 & 0x30) >> 4 {
+            0b00 => IdStringModifier::Numeric,
+            0b01 => IdStringModifier::Alpha,
+            _ => return None,
+        };
+
+        let record_sharing = RecordSharing {
+            id_string_modifier,
+            share_count: direction_sharing_1 & 0xF,
+            entity_instance_increments: (direction_sharing_2 & 0x80) == 0x80,
+            modifier_offset: direction_sharing_2 & 0x7F,
+        };
+
+        // One reserved byte
+
+        let oem_data = record_data[10];
+
+        let id_string_type_len = record_data[11];
+        let id_string_bytes = &record_data[12..];
+        let id_string = TypeLengthRaw::new(id_string_type_len, id_string_bytes).into();
+
+        Some(Self {
+            key,
+            entity_id,
+            entity_instance,
+            ty,
+            event_reading_type_code,
+            direction,
+            record_sharing,
+            oem_data,
+            id_string,
+            reserved: ReservedBits::parse(&record_data[..12], &Self::RESERVED),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        self.key.write_data(buffer);
+        buffer.push(self.entity_id.into());
+        buffer.push(self.entity_instance.into());
+        buffer.push(self.ty.into());
+        buffer.push(self.event_reading_type_code.into());
+        self.record_sharing.write_data(self.direction, buffer);
+        // One reserved byte
+        buffer.push(0);
+        buffer.push(self.oem_data);
+        self.id_string.write_data(buffer);
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for EventOnlyRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let mut log = log_vec![
+            (0, "SDR Record (Event-Only)"),
+            (1, "Sensor ID", &self.id_string),
+            (1, "Sensor Type", format!("{:?}", self.ty)),
+            (
+                1,
+                "Event/Reading type",
+                format!("{:?}", self.event_reading_type_code)
+            ),
+            (1, "Entity ID", format!("{:?}", self.entity_id)),
+            (1, "Entity instance", format!("{:?}", self.entity_instance)),
+        ];
+
+        self.key.log_into(1, &mut log);
+
+        log
+    }
+}
@@ -16,9 +16,10 @@ pub struct FullSensorRecord {
     pub direction: Direction,
     pub result_exponent: i8,
     pub b_exponent: i8,
-    nominal_reading: Option<u8>,
-    normal_maximum: Option<u8>,
-    normal_minimum: Option<u8>,
+    analog_characteristics: u8,
+    nominal_reading: u8,
+    normal_maximum: u8,
+    normal_minimum: u8,
     max_reading: u8,
     min_reading: u8,
     upper_non_recoverable_threshold: u8,
@@ -30,6 +31,85 @@ pub struct FullSensorRecord {
     pub positive_going_threshold_hysteresis_value: Option<NonZeroU8>,
     pub negative_going_threshold_hysteresis_value: Option<NonZeroU8>,
     pub oem_data: u8,
+    reserved: ReservedBits,
+}
+
+/// The factors that are used to convert raw readings of a sensor into
+/// values, and to determine their tolerance and accuracy.
+///
+/// Raw readings are converted to `L((m * x + b * 10^b_exponent) * 10^result_exponent)`,
+/// where `L` is the linearization function of the sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingFactors {
+    pub m: i16,
+    /// The tolerance, in +/- half raw counts.
+    pub tolerance: u8,
+    pub b: i16,
+    /// The accuracy, in units of 0.01 %, scaled by `10^accuracy_exponent`.
+    pub accuracy: u16,
+    pub accuracy_exponent: u8,
+    pub result_exponent: i8,
+    pub b_exponent: i8,
+}
+
+impl ReadingFactors {
+    /// Parse reading factors from the 6 byte format that is used in full
+    /// sensor records and by `Get Sensor Reading Factors`.
+    ///
+    /// The sensor direction bits of full sensor records are ignored.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 6 {
+            return None;
+        }
+
+        // Sign-extend the top `bits` bits of `value`.
+        let sign_extend = |value: u16, bits: u32| ((value << (16 - bits)) as i16) >> (16 - bits);
+
+        let m = sign_extend(data[0] as u16 | ((data[1] >> 6) as u16) << 8, 10);
+        let tolerance = data[1] & 0x3F;
+
+        let b = sign_extend(data[2] as u16 | ((data[3] >> 6) as u16) << 8, 10);
+
+        let accuracy = (data[3] & 0x3F) as u16 | (((data[4] >> 4) & 0xF) as u16) << 6;
+        let accuracy_exponent = (data[4] >> 2) & 0x3;
+
+        let result_exponent = sign_extend((data[5] >> 4) as u16, 4) as i8;
+        let b_exponent = sign_extend((data[5] & 0xF) as u16, 4) as i8;
+
+        Some(Self {
+            m,
+            tolerance,
+            b,
+            accuracy,
+            accuracy_exponent,
+            result_exponent,
+            b_exponent,
+        })
+    }
+
+    /// The accuracy, in percent, or `None` if it is not specified.
+    pub fn accuracy_percentage(&self) -> Option<f32> {
+        if self.accuracy == 0 {
+            return None;
+        }
+
+        Some(self.accuracy as f32 * 10f32.powi(self.accuracy_exponent as i32) / 100.0)
+    }
+
+    /// Write the 6 byte representation of these reading factors, in the format
+    /// accepted by [`ReadingFactors::parse`].
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        let [m_lsb, m_msb] = self.m.to_le_bytes();
+        let [b_lsb, b_msb] = self.b.to_le_bytes();
+
+        buffer.push(m_lsb);
+        buffer.push((m_msb & 0x3) << 6 | (self.tolerance & 0x3F));
+        buffer.push(b_lsb);
+        buffer.push((b_msb & 0x3) << 6 | (self.accuracy & 0x3F) as u8);
+        buffer
+            .push((((self.accuracy >> 6) & 0xF) as u8) << 4 | (self.accuracy_exponent & 0x3) << 2);
+        buffer.push(((self.result_exponent as u8) & 0xF) << 4 | (self.b_exponent as u8) & 0xF);
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -50,105 +130,55 @@ impl SensorRecord for FullSensorRecord {
 }
 
 impl FullSensorRecord {
+    const NOMINAL_READING_SPECIFIED: u8 = 0x01;
+    const NORMAL_MAXIMUM_SPECIFIED: u8 = 0x02;
+    const NORMAL_MINIMUM_SPECIFIED: u8 = 0x04;
+
+    /// The reserved bits of the record data that follows the common
+    /// sensor record data, up to and including the ID string type/length byte.
+    const RESERVED: [u8; 25] = [
+        0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x20,
+    ];
+
     pub fn parse(record_data: &[u8]) -> Result<Self, ParseFullSensorRecordError> {
         use ParseFullSensorRecordError::*;
 
-        if record_data.len() < 15 {
+        if record_data.len() < 18 {
             return Err(NotEnoughData);
         }
 
-        let sensor_units_1 = record_data[15];
-
-        let analog_data_format = match (sensor_units_1 >> 6) & 0x03 {
-            0b00 => Some(DataFormat::Unsigned),
-            0b01 => Some(DataFormat::OnesComplement),
-            0b10 => Some(DataFormat::TwosComplement),
-            0b11 => None,
-            _ => unreachable!(),
-        };
+        let analog_data_format = DataFormat::from_units_1(record_data[15]);
 
+        let full_record_data = record_data;
         let (mut common, record_data) =
             SensorRecordCommon::parse_without_id(record_data).ok_or(CouldNotParseCommon)?;
 
-        if record_data.len() < 24 {
+        if record_data.len() < 25 {
             return Err(NotEnoughDataAfterCommon);
         }
 
         let linearization = record_data[0];
         let linearization = Linearization::from(linearization & 0x7F);
 
-        let m_lsb = record_data[1];
-        let m_msb_tolerance = record_data[2];
-        let m_sign = if m_msb_tolerance & 0x80 == 0x80 {
-            0b11111100
-        } else {
-            0
-        };
-
-        let m = i16::from_le_bytes([m_lsb, m_sign | (m_msb_tolerance >> 6) & 0x1]);
-
-        let tolerance = m_msb_tolerance & 0x3F;
-
-        let b_lsb = record_data[3];
-        let b_msb_accuracy_lsb = record_data[4];
-
-        let b_sign = if b_msb_accuracy_lsb & 0x80 == 0x80 {
-            0b11111100
-        } else {
-            0
-        };
-        let b = i16::from_le_bytes([b_lsb, b_sign | (b_msb_accuracy_lsb >> 6)]);
-
-        let accuracy_msb_accuracy_exp_sensor_dir = record_data[5];
-
-        let accuracy = u16::from_le_bytes([
-            (accuracy_msb_accuracy_exp_sensor_dir >> 4) & 0xF,
-            (b_msb_accuracy_lsb & 0x3F),
-        ]);
-
-        let accuracy_exponent = (accuracy_msb_accuracy_exp_sensor_dir >> 2) & 0x3;
+        let ReadingFactors {
+            m,
+            tolerance,
+            b,
+            accuracy,
+            accuracy_exponent,
+            result_exponent,
+            b_exponent,
+        } = ReadingFactors::parse(&record_data[1..7]).ok_or(NotEnoughDataAfterCommon)?;
 
-        let direction = Direction::try_from(accuracy_msb_accuracy_exp_sensor_dir & 0b11)
+        let direction = Direction::try_from(record_data[5] & 0b11)
             .unwrap_or(Direction::UnspecifiedNotApplicable);
 
-        let r_exp_b_exp = record_data[6];
-
-        let r_sign = if r_exp_b_exp & 0x80 == 0x80 {
-            0b11111000
-        } else {
-            0
-        };
-        let result_exponent = (r_sign | ((r_exp_b_exp >> 4) & 0x7)) as i8;
-
-        let b_sign = if r_exp_b_exp & 0x8 == 0x8 {
-            0b11111000
-        } else {
-            0
-        };
-        let b_exponent = (b_sign | (r_exp_b_exp & 0x7)) as i8;
-
         let analog_characteristics = record_data[7];
 
         let nominal_reading = record_data[8];
-        let nominal_reading = if (analog_characteristics & 0x1) == 0x1 {
-            Some(nominal_reading)
-        } else {
-            None
-        };
-
         let normal_maximum = record_data[9];
-        let normal_maximum = if (analog_characteristics & 0x2) == 0x2 {
-            Some(normal_maximum)
-        } else {
-            None
-        };
-
         let normal_minimum = record_data[10];
-        let normal_minimum = if (analog_characteristics & 0x4) == 0x4 {
-            Some(normal_minimum)
-        } else {
-            None
-        };
 
         let max_reading = record_data[11];
         let min_reading = record_data[12];
@@ -173,6 +203,11 @@ impl FullSensorRecord {
 
         common.set_id(id_string);
 
+        let mut reserved_mask = SensorRecordCommon::reserved_mask(full_record_data).to_vec();
+        reserved_mask.extend_from_slice(&Self::RESERVED);
+        let reserved =
+            ReservedBits::parse(&full_record_data[..reserved_mask.len()], &reserved_mask);
+
         Ok(Self {
             common,
             analog_data_format,
@@ -185,6 +220,7 @@ impl FullSensorRecord {
             direction,
             result_exponent,
             b_exponent,
+            analog_characteristics,
             nominal_reading,
             normal_maximum,
             normal_minimum,
@@ -199,9 +235,63 @@ impl FullSensorRecord {
             positive_going_threshold_hysteresis_value,
             negative_going_threshold_hysteresis_value,
             oem_data,
+            reserved,
         })
     }
 
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let record_start = buffer.len();
+        self.common.write_data(buffer);
+        buffer[record_start + 15] |= DataFormat::units_1_bits(self.analog_data_format);
+
+        buffer.push(self.linearization.into());
+
+        let start = buffer.len();
+        self.reading_factors().write_data(buffer);
+        buffer[start + 4] |= u8::from(self.direction);
+
+        buffer.push(self.analog_characteristics);
+        buffer.push(self.nominal_reading);
+        buffer.push(self.normal_maximum);
+        buffer.push(self.normal_minimum);
+        buffer.push(self.max_reading);
+        buffer.push(self.min_reading);
+        buffer.push(self.upper_non_recoverable_threshold);
+        buffer.push(self.upper_critical_threshold);
+        buffer.push(self.upper_non_critical_threshold);
+        buffer.push(self.lower_non_recoverable_threshold);
+        buffer.push(self.lower_critical_threshold);
+        buffer.push(self.lower_non_critical_threshold);
+        buffer.push(
+            self.positive_going_threshold_hysteresis_value
+                .map(NonZeroU8::get)
+                .unwrap_or(0),
+        );
+        buffer.push(
+            self.negative_going_threshold_hysteresis_value
+                .map(NonZeroU8::get)
+                .unwrap_or(0),
+        );
+        // Two reserved bytes
+        buffer.extend_from_slice(&[0, 0]);
+        buffer.push(self.oem_data);
+        self.common.sensor_id.write_data(buffer);
+        self.reserved.write_data(buffer, record_start);
+    }
+
+    /// The raw value of the threshold of kind `kind`.
+    pub fn raw_threshold(&self, kind: ThresholdKind) -> u8 {
+        match kind {
+            ThresholdKind::LowerNonCritical => self.lower_non_critical_threshold,
+            ThresholdKind::LowerCritical => self.lower_critical_threshold,
+            ThresholdKind::LowerNonRecoverable => self.lower_non_recoverable_threshold,
+            ThresholdKind::UpperNonCritical => self.upper_non_critical_threshold,
+            ThresholdKind::UpperCritical => self.upper_critical_threshold,
+            ThresholdKind::UpperNonRecoverable => self.upper_non_recoverable_threshold,
+        }
+    }
+
     pub fn threshold(&self, kind: ThresholdKind) -> Threshold {
         let readable = self.capabilities().threshold_access.readable(kind);
         let settable = self.capabilities().threshold_access.settable(kind);
@@ -226,37 +316,132 @@ impl FullSensorRecord {
         }
     }
 
-    fn convert(&self, value: u8) -> Option<Value> {
-        let m = self.m as f32;
-        let b = self.b as f32 * 10f32.powf(self.b_exponent as f32);
-        let result_mul = 10f32.powf(self.result_exponent as f32);
+    /// The reading factors of this sensor.
+    ///
+    /// The reading factors of non-linear sensors depend on the reading,
+    /// and must be retrieved using `GetSensorReadingFactors` instead.
+    pub fn reading_factors(&self) -> ReadingFactors {
+        ReadingFactors {
+            m: self.m,
+            tolerance: self.tolerance,
+            b: self.b,
+            accuracy: self.accuracy,
+            accuracy_exponent: self.accuracy_exponent,
+            result_exponent: self.result_exponent,
+            b_exponent: self.b_exponent,
+        }
+    }
+
+    /// Convert a raw reading, threshold or event data value of this sensor
+    /// into a value in the units of the sensor.
+    ///
+    /// Returns `None` if the sensor does not return analog readings, if it
+    /// is a non-linear sensor, or if its linearization is unknown. Readings of
+    /// non-linear sensors can be converted with [`FullSensorRecord::convert_with`].
+    pub fn convert(&self, value: u8) -> Option<Value> {
+        if self.linearization.is_non_linear() {
+            return None;
+        }
+
+        self.convert_with(&self.reading_factors(), value)
+    }
+
+    /// Convert a raw reading, threshold or event data value of this sensor
+    /// into a value in the units of the sensor, using `factors` instead of
+    /// the reading factors in this record.
+    pub fn convert_with(&self, factors: &ReadingFactors, value: u8) -> Option<Value> {
+        let m = factors.m as f32;
+        let b = factors.b as f32 * 10f32.powf(factors.b_exponent as f32);
+        let result_mul = 10f32.powf(factors.result_exponent as f32);
         let format = self.analog_data_format?;
 
-        let value = match format {
+        let raw = match format {
             DataFormat::Unsigned => value as f32,
-            DataFormat::OnesComplement => !value as i8 as f32,
+            DataFormat::OnesComplement if value & 0x80 == 0x80 => (value as i8 + 1) as f32,
+            DataFormat::OnesComplement => value as f32,
             DataFormat::TwosComplement => value as i8 as f32,
         };
 
-        let value = (m * value + b) * result_mul;
+        let convert = |raw: f32| self.linearization.apply((m * raw + b) * result_mul);
+        let value = convert(raw)?;
+
+        // The tolerance is specified in +/- half raw counts, so determine
+        // how far the value moves if the raw value is off by that much.
+        let half_counts = factors.tolerance as f32 / 2.0;
+        let tolerance = [raw - half_counts, raw + half_counts]
+            .into_iter()
+            .filter_map(convert)
+            .map(|v| (v - value).abs())
+            .fold(0f32, f32::max);
 
-        Some(Value::new(self.common().sensor_units, value))
+        let mut value = Value::new(self.common().sensor_units, value);
+
+        if factors.tolerance != 0 && tolerance.is_finite() {
+            value = value.with_tolerance(tolerance);
+        }
+
+        if let Some(accuracy) = factors.accuracy_percentage() {
+            value = value.with_accuracy(accuracy);
+        }
+
+        Some(value)
+    }
+
+    /// Get the raw value whose converted value is nearest to `value`, which
+    /// is expressed in the units of the sensor.
+    ///
+    /// This is the inverse of [`FullSensorRecord::convert`], and can be used to
+    /// calculate raw threshold values.
+    ///
+    /// Raw values that cannot be converted are skipped. Returns `None` if
+    /// no raw value can be converted, for example if the sensor does not
+    /// return analog readings.
+    pub fn to_raw(&self, value: f32) -> Option<u8> {
+        let distance = |raw: u8| Some((self.convert(raw)?.value - value).abs());
+
+        (0..=u8::MAX)
+            .filter_map(|raw| Some((raw, distance(raw)?)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(raw, _)| raw)
     }
 
     pub fn display_reading(&self, value: u8) -> Option<String> {
         self.convert(value).map(|v| v.display(true))
     }
 
+    /// The raw nominal reading, if it is specified.
+    pub fn nominal_reading(&self) -> Option<u8> {
+        self.analog_characteristic(Self::NOMINAL_READING_SPECIFIED, self.nominal_reading)
+    }
+
+    /// The raw normal maximum, if it is specified.
+    pub fn normal_maximum(&self) -> Option<u8> {
+        self.analog_characteristic(Self::NORMAL_MAXIMUM_SPECIFIED, self.normal_maximum)
+    }
+
+    /// The raw normal minimum, if it is specified.
+    pub fn normal_minimum(&self) -> Option<u8> {
+        self.analog_characteristic(Self::NORMAL_MINIMUM_SPECIFIED, self.normal_minimum)
+    }
+
+    fn analog_characteristic(&self, flag: u8, value: u8) -> Option<u8> {
+        if (self.analog_characteristics & flag) == flag {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     pub fn nominal_value(&self) -> Option<Value> {
-        self.convert(self.nominal_reading?)
+        self.convert(self.nominal_reading()?)
     }
 
     pub fn normal_max(&self) -> Option<Value> {
-        self.convert(self.normal_maximum?)
+        self.convert(self.normal_maximum()?)
     }
 
     pub fn normal_min(&self) -> Option<Value> {
-        self.convert(self.normal_minimum?)
+        self.convert(self.normal_minimum()?)
     }
 
     pub fn max_reading(&self) -> Option<Value> {
@@ -301,3 +486,203 @@ impl FullSensorRecord {
         self.convert(value.get())
     }
 }
+
+/// A builder for [`FullSensorRecord`]s.
+///
+/// All readings, thresholds and hysteresis values are raw values,
+/// which are converted using the M, B and exponent values of the record.
+#[derive(Debug, Clone)]
+pub struct FullSensorRecordBuilder {
+    record: FullSensorRecord,
+}
+
+impl FullSensorRecordBuilder {
+    /// Create a new builder for a linear, threshold-based sensor with
+    /// unsigned readings, `M = 1` and no other conversion factors.
+    pub fn new(key: SensorKey, ty: SensorType, sensor_units: SensorUnits) -> Self {
+        let common = SensorRecordCommon {
+            key,
+            entity_id: EntityId::Unspecified,
+            entity_instance: EntityInstance::from(0),
+            initialization: Default::default(),
+            capabilities: Default::default(),
+            ty,
+            event_reading_type_code: EventReadingTypeCodes::Threshold,
+            sensor_units,
+            sensor_id: Default::default(),
+        };
+
+        Self {
+            record: FullSensorRecord {
+                common,
+                analog_data_format: Some(DataFormat::Unsigned),
+                linearization: Linearization::Linear,
+                m: 1,
+                tolerance: 0,
+                b: 0,
+                accuracy: 0,
+                accuracy_exponent: 0,
+                direction: Direction::UnspecifiedNotApplicable,
+                result_exponent: 0,
+                b_exponent: 0,
+                analog_characteristics: 0,
+                nominal_reading: 0,
+                normal_maximum: 0,
+                normal_minimum: 0,
+                max_reading: 0xFF,
+                min_reading: 0x00,
+                upper_non_recoverable_threshold: 0,
+                upper_critical_threshold: 0,
+                upper_non_critical_threshold: 0,
+                lower_non_recoverable_threshold: 0,
+                lower_critical_threshold: 0,
+                lower_non_critical_threshold: 0,
+                positive_going_threshold_hysteresis_value: None,
+                negative_going_threshold_hysteresis_value: None,
+                oem_data: 0,
+                reserved: Default::default(),
+            },
+        }
+    }
+
+    pub fn entity(mut self, entity: Entity) -> Self {
+        self.record.common.entity_id = entity.entity_id;
+        self.record.common.entity_instance = entity.entity_instance;
+        self
+    }
+
+    pub fn event_reading_type_code(mut self, code: EventReadingTypeCodes) -> Self {
+        self.record.common.event_reading_type_code = code;
+        self
+    }
+
+    pub fn initialization(mut self, initialization: SensorInitialization) -> Self {
+        self.record.common.initialization = initialization;
+        self
+    }
+
+    pub fn capabilities(mut self, capabilities: SensorCapabilities) -> Self {
+        self.record.common.capabilities = capabilities;
+        self
+    }
+
+    /// Set the ID string, which may be at most 16 bytes long.
+    pub fn id_string(mut self, id_string: SensorId) -> Self {
+        self.record.common.sensor_id = id_string;
+        self
+    }
+
+    pub fn analog_data_format(mut self, format: Option<DataFormat>) -> Self {
+        self.record.analog_data_format = format;
+        self
+    }
+
+    pub fn linearization(mut self, linearization: Linearization) -> Self {
+        self.record.linearization = linearization;
+        self
+    }
+
+    /// Set the conversion factors, such that a raw reading `x` is
+    /// converted to `(m * x + b * 10^b_exponent) * 10^result_exponent`.
+    ///
+    /// `m` and `b` are 10 bit signed values, and `b_exponent` and
+    /// `result_exponent` are 4 bit signed values.
+    pub fn conversion(mut self, m: i16, b: i16, b_exponent: i8, result_exponent: i8) -> Self {
+        self.record.m = m;
+        self.record.b = b;
+        self.record.b_exponent = b_exponent;
+        self.record.result_exponent = result_exponent;
+        self
+    }
+
+    /// Set the tolerance, in +/- half raw counts. At most 63.
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.record.tolerance = tolerance;
+        self
+    }
+
+    /// Set the accuracy, in 1/100 percent scaled up by `10^exponent`.
+    ///
+    /// `accuracy` is a 10 bit value, and `exponent` is at most 3.
+    pub fn accuracy(mut self, accuracy: u16, exponent: u8) -> Self {
+        self.record.accuracy = accuracy;
+        self.record.accuracy_exponent = exponent;
+        self
+    }
+
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.record.direction = direction;
+        self
+    }
+
+    pub fn nominal_reading(mut self, value: u8) -> Self {
+        self.record.analog_characteristics |= FullSensorRecord::NOMINAL_READING_SPECIFIED;
+        self.record.nominal_reading = value;
+        self
+    }
+
+    pub fn normal_maximum(mut self, value: u8) -> Self {
+        self.record.analog_characteristics |= FullSensorRecord::NORMAL_MAXIMUM_SPECIFIED;
+        self.record.normal_maximum = value;
+        self
+    }
+
+    pub fn normal_minimum(mut self, value: u8) -> Self {
+        self.record.analog_characteristics |= FullSensorRecord::NORMAL_MINIMUM_SPECIFIED;
+        self.record.normal_minimum = value;
+        self
+    }
+
+    /// Set the minimum and maximum raw readings of the sensor.
+    pub fn reading_range(mut self, min: u8, max: u8) -> Self {
+        self.record.min_reading = min;
+        self.record.max_reading = max;
+        self
+    }
+
+    pub fn threshold(mut self, kind: ThresholdKind, value: u8) -> Self {
+        let threshold = match kind {
+            ThresholdKind::LowerNonCritical => &mut self.record.lower_non_critical_threshold,
+            ThresholdKind::LowerCritical => &mut self.record.lower_critical_threshold,
+            ThresholdKind::LowerNonRecoverable => &mut self.record.lower_non_recoverable_threshold,
+            ThresholdKind::UpperNonCritical => &mut self.record.upper_non_critical_threshold,
+            ThresholdKind::UpperCritical => &mut self.record.upper_critical_threshold,
+            ThresholdKind::UpperNonRecoverable => &mut self.record.upper_non_recoverable_threshold,
+        };
+
+        *threshold = value;
+        self
+    }
+
+    /// Set the positive- and negative-going threshold hysteresis,
+    /// where a value of 0 indicates that there is no hysteresis.
+    pub fn hysteresis(mut self, positive_going: u8, negative_going: u8) -> Self {
+        self.record.positive_going_threshold_hysteresis_value = NonZeroU8::new(positive_going);
+        self.record.negative_going_threshold_hysteresis_value = NonZeroU8::new(negative_going);
+        self
+    }
+
+    pub fn oem_data(mut self, oem_data: u8) -> Self {
+        self.record.oem_data = oem_data;
+        self
+    }
+
+    /// Build the record.
+    ///
+    /// Returns `None` if any of the values do not fit in their field.
+    pub fn build(self) -> Option<FullSensorRecord> {
+        let record = self.record;
+
+        let valid = (-512..=511).contains(&record.m)
+            && (-512..=511).contains(&record.b)
+            && (-8..=7).contains(&record.b_exponent)
+            && (-8..=7).contains(&record.result_exponent)
+            && record.tolerance <= 0x3F
+            && record.accuracy <= 0x3FF
+            && record.accuracy_exponent <= 0x3
+            && u8::from(record.linearization) <= 0x7F
+            && record.common.sensor_id.encoded_len() <= 16;
+
+        valid.then_some(record)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{log_vec, Loggable};
+
+use super::*;
+
+/// A Generic Device Locator record.
+///
+/// Describes the location of a device that is not an IPMI controller,
+/// or that is otherwise not covered by one of the other locator records.
+#[derive(Debug, Clone)]
+pub struct GenericDeviceLocatorRecord {
+    pub direct_access_address: Address,
+    pub device_slave_address: Address,
+    pub channel_number: u8,
+    pub access_lun: LogicalUnit,
+    pub private_bus_id: u8,
+    pub address_span: u8,
+    pub device_type: u8,
+    pub device_type_modifier: u8,
+    pub entity_id: EntityId,
+    pub entity_instance: EntityInstance,
+    pub oem_data: u8,
+    pub id_string: SensorId,
+    pub reserved: ReservedBits,
+}
+
+impl GenericDeviceLocatorRecord {
+    /// The reserved bits of the record data, up to and
+    /// including the ID string type/length byte.
+    const RESERVED: [u8; 11] = [
+        0x01, 0x01, 0x00, 0xF8, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+    ];
+
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 11 {
+            return None;
+        }
+
+        let direct_access_address = Address(record_data[0] & 0xFE);
+        let device_slave_address = Address(record_data[1] & 0xFE);
+
+        let channel_lun_bus = record_data[2];
+        let channel_number = (channel_lun_bus >> 5) & 0x7;
+        let access_lun = LogicalUnit::try_from((channel_lun_bus >> 3) & 0x3).unwrap();
+        let private_bus_id = channel_lun_bus & 0x7;
+
+        let address_span = record_data[3] & 0x7;
+
+        // One reserved byte
+
+        let device_type = record_data[5];
+        let device_type_modifier = record_data[6];
+        let entity_id = record_data[7].into();
+        let entity_instance = EntityInstance::from(record_data[8]);
+        let oem_data = record_data[9];
+
+        let id_string_type_len = record_data[10];
+        let id_string_bytes = &record_data[11..];
+        let id_string = TypeLengthRaw::new(id_string_type_len, id_string_bytes).into();
+
+        Some(Self {
+            direct_access_address,
+            device_slave_address,
+            channel_number,
+            access_lun,
+            private_bus_id,
+            address_span,
+            device_type,
+            device_type_modifier,
+            entity_id,
+            entity_instance,
+            oem_data,
+            id_string,
+            reserved: ReservedBits::parse(&record_data[..11], &Self::RESERVED),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        buffer.push(self.direct_access_address.0 & 0xFE);
+        buffer.push(self.device_slave_address.0 & 0xFE);
+        buffer.push(
+            (self.channel_number & 0x7) << 5
+                | self.access_lun.value() << 3
+                | (self.private_bus_id & 0x7),
+        );
+        buffer.push(self.address_span & 0x7);
+        // One reserved byte
+        buffer.push(0);
+        buffer.push(self.device_type);
+        buffer.push(self.device_type_modifier);
+        buffer.push(self.entity_id.into());
+        buffer.push(self.entity_instance.into());
+        buffer.push(self.oem_data);
+        self.id_string.write_data(buffer);
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for GenericDeviceLocatorRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let (dev_ty, dev_ty_mod) = (self.device_type, self.device_type_modifier);
+
+        log_vec![
+            (0, "SDR Record (Generic Device Locator)"),
+            (1, "Device ID", &self.id_string),
+            (
+                1,
+                "Access address",
+                format!("0x{:02X}", self.direct_access_address.0)
+            ),
+            (
+                1,
+                "Slave address",
+                format!("0x{:02X}", self.device_slave_address.0)
+            ),
+            (1, "Channel", self.channel_number),
+            (1, "Access LUN", self.access_lun.value()),
+            (1, "Private bus ID", self.private_bus_id),
+            (1, "Address span", self.address_span),
+            (1, "Device type", format!("0x{dev_ty:02X}")),
+            (1, "Device type modifier", format!("0x{dev_ty_mod:02X}")),
+            (1, "Entity ID", format!("{:?}", self.entity_id)),
+            (1, "Entity instance", format!("{:?}", self.entity_instance)),
+        ]
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{log_vec, Loggable};
+
+use super::*;
+
+/// A Management Controller Confirmation record.
+///
+/// Records the identity of a management controller that was discovered
+/// on the IPMB, so that a change in the set of controllers can be detected.
+#[derive(Debug, Clone)]
+pub struct ManagementControllerConfirmationRecord {
+    pub device_slave_address: Address,
+    pub device_id: u8,
+    pub channel_number: u8,
+    pub device_revision: u8,
+    pub major_fw_revision: u8,
+    /// The minor firmware revision, which is encoded as two BCD digits
+    /// and can therefore be at most 99.
+    pub minor_fw_revision: u8,
+    pub major_version: u8,
+    pub minor_version: u8,
+    pub manufacturer_id: u32,
+    pub product_id: u16,
+    pub device_guid: [u8; 16],
+    pub reserved: ReservedBits,
+}
+
+impl ManagementControllerConfirmationRecord {
+    /// The reserved bits of the record data.
+    const RESERVED: [u8; 27] = [
+        0x01, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 27 {
+            return None;
+        }
+
+        let fw_min = {
+            let min_nib_low = record_data[4] & 0xF;
+            let min_nib_high = (record_data[4] >> 4) & 0xF;
+
+            min_nib_low + min_nib_high * 10
+        };
+
+        Some(Self {
+            device_slave_address: Address(record_data[0] & 0xFE),
+            device_id: record_data[1],
+            channel_number: (record_data[2] >> 4) & 0xF,
+            device_revision: record_data[2] & 0xF,
+            major_fw_revision: record_data[3] & 0x7F,
+            minor_fw_revision: fw_min,
+            major_version: record_data[5] & 0xF,
+            minor_version: (record_data[5] >> 4) & 0xF,
+            manufacturer_id: u32::from_le_bytes([
+                record_data[6],
+                record_data[7],
+                record_data[8] & 0xF,
+                0,
+            ]),
+            product_id: u16::from_le_bytes([record_data[9], record_data[10]]),
+            device_guid: record_data[11..27].try_into().unwrap(),
+            reserved: ReservedBits::parse(record_data, &Self::RESERVED),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        let fw_min = (self.minor_fw_revision / 10) << 4 | (self.minor_fw_revision % 10);
+        let [manf_0, manf_1, manf_2, _] = self.manufacturer_id.to_le_bytes();
+
+        buffer.push(self.device_slave_address.0 & 0xFE);
+        buffer.push(self.device_id);
+        buffer.push((self.channel_number & 0xF) << 4 | (self.device_revision & 0xF));
+        buffer.push(self.major_fw_revision & 0x7F);
+        buffer.push(fw_min);
+        buffer.push((self.minor_version & 0xF) << 4 | (self.major_version & 0xF));
+        buffer.extend_from_slice(&[manf_0, manf_1, manf_2 & 0xF]);
+        buffer.extend_from_slice(&self.product_id.to_le_bytes());
+        buffer.extend_from_slice(&self.device_guid);
+        self.reserved.write_data(buffer, start);
+    }
+}
+
+impl Loggable for ManagementControllerConfirmationRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let (dev_id, dev_rev) = (self.device_id, self.device_revision);
+        let (fw_maj, fw_min) = (self.major_fw_revision, self.minor_fw_revision);
+        let (v_maj, v_min) = (self.major_version, self.minor_version);
+        let manf_id = self.manufacturer_id;
+
+        log_vec![
+            (0, "SDR Record (Management Controller Confirmation)"),
+            (
+                1,
+                "Slave address",
+                format!("0x{:02X}", self.device_slave_address.0)
+            ),
+            (1, "Channel", self.channel_number),
+            (1, "Device ID", format!("0x{dev_id:02X}")),
+            (1, "Device revision", format!("0x{dev_rev:02X}")),
+            (1, "Manufacturer ID", format!("0x{manf_id:02X}")),
+            (1, "Product ID", format!("0x{:02X}", self.product_id)),
+            (1, "IPMI Version", format!("{v_maj}.{v_min}")),
+            (1, "FW revision", format!("{fw_maj}.{fw_min}")),
+            (1, "Device GUID", format!("{:02X?}", self.device_guid)),
+        ]
+    }
+}
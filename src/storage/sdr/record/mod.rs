@@ -1,14 +1,44 @@
 mod full_sensor_record;
-pub use full_sensor_record::FullSensorRecord;
+pub use full_sensor_record::{FullSensorRecord, FullSensorRecordBuilder, ReadingFactors};
 
 mod compact_sensor_record;
-pub use compact_sensor_record::CompactSensorRecord;
+pub use compact_sensor_record::{
+    CompactSensorRecord, CompactSensorRecordBuilder, IdStringModifier, RecordSharing,
+};
+
+mod event_only_record;
+pub use event_only_record::EventOnlyRecord;
+
+mod entity_association_record;
+pub use entity_association_record::{
+    ContainedEntities, DeviceRelativeEntity, DeviceRelativeEntityAssociationRecord, Entity,
+    EntityAssociationFlags, EntityAssociationRecord, EntityRange,
+};
+
+mod generic_device_locator_record;
+pub use generic_device_locator_record::GenericDeviceLocatorRecord;
+
+mod management_controller_confirmation_record;
+pub use management_controller_confirmation_record::ManagementControllerConfirmationRecord;
+
+mod bmc_message_channel_info_record;
+pub use bmc_message_channel_info_record::{
+    BmcMessageChannelInfoRecord, InterruptType, MessageChannelInfo,
+};
+
+mod oem_record;
+pub use oem_record::OemRecord;
+
+use core::ops::RangeInclusive;
 
 use nonmax::NonMaxU8;
 
-use crate::{connection::LogicalUnit, Loggable};
+use crate::{
+    connection::{Address, LogicalUnit},
+    Loggable,
+};
 
-use super::{event_reading_type_code::EventReadingTypeCodes, RecordId, SensorType, Unit};
+use super::{event_reading_type_code::EventReadingTypeCodes, EntityId, RecordId, SensorType, Unit};
 
 pub trait SensorRecord {
     fn common(&self) -> &SensorRecordCommon;
@@ -27,10 +57,17 @@ pub trait SensorRecord {
         self.common().key.sensor_number
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         self.common().entity_id
     }
 
+    fn entity(&self) -> Entity {
+        Entity {
+            entity_id: self.common().entity_id,
+            entity_instance: self.common().entity_instance,
+        }
+    }
+
     fn key_data(&self) -> &SensorKey {
         &self.common().key
     }
@@ -40,11 +77,58 @@ pub trait SensorRecord {
 pub struct Value {
     units: SensorUnits,
     value: f32,
+    tolerance: Option<f32>,
+    accuracy: Option<f32>,
 }
 
 impl Value {
     pub fn new(units: SensorUnits, value: f32) -> Self {
-        Self { units, value }
+        Self {
+            units,
+            value,
+            tolerance: None,
+            accuracy: None,
+        }
+    }
+
+    /// Set the tolerance of this value, in the units of the sensor.
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = Some(tolerance.abs());
+        self
+    }
+
+    /// Set the accuracy of this value, in percent.
+    pub fn with_accuracy(mut self, accuracy: f32) -> Self {
+        self.accuracy = Some(accuracy.abs());
+        self
+    }
+
+    /// The value, in the units of the sensor.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// The tolerance of this value: the value is accurate to
+    /// +/- this amount, in the units of the sensor.
+    pub fn tolerance(&self) -> Option<f32> {
+        self.tolerance
+    }
+
+    /// The accuracy of this value, in +/- percent.
+    pub fn accuracy(&self) -> Option<f32> {
+        self.accuracy
+    }
+
+    /// The total measurement error of this value, in +/- the units of the
+    /// sensor, combining its tolerance and accuracy.
+    pub fn error(&self) -> f32 {
+        let accuracy = self.accuracy.unwrap_or(0.0) / 100.0 * self.value.abs();
+        self.tolerance.unwrap_or(0.0) + accuracy
+    }
+
+    /// Whether `other` is within the measurement error of this value.
+    pub fn within_error(&self, other: f32) -> bool {
+        (self.value - other).abs() <= self.error()
     }
 
     pub fn display(&self, short: bool) -> String {
@@ -58,7 +142,7 @@ impl Value {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SensorKey {
     pub owner_id: SensorOwner,
     pub owner_channel: u8,
@@ -90,6 +174,16 @@ impl SensorKey {
             sensor_number,
         })
     }
+
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        let channel_lun = ((self.owner_channel & 0xF) << 4)
+            | (self.fru_inv_device_owner_lun.value() << 2)
+            | self.owner_lun.value();
+
+        buffer.push(self.owner_id.into());
+        buffer.push(channel_lun);
+        buffer.push(self.sensor_number.get());
+    }
 }
 
 impl SensorKey {
@@ -106,7 +200,7 @@ impl SensorKey {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SensorOwner {
     I2C(u8),
     System(u8),
@@ -133,13 +227,13 @@ impl Into<u8> for SensorOwner {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EntityRelativeTo {
     System,
     Device,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 
 pub enum EntityInstance {
     Physical {
@@ -175,7 +269,20 @@ impl From<u8> for EntityInstance {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+impl From<EntityInstance> for u8 {
+    fn from(value: EntityInstance) -> Self {
+        match value {
+            EntityInstance::Physical {
+                instance_number, ..
+            } => instance_number,
+            EntityInstance::LogicalContainer {
+                instance_number, ..
+            } => 0x80 | instance_number,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub struct SensorInitialization {
     pub settable: bool,
     pub scanning: bool,
@@ -187,20 +294,22 @@ pub struct SensorInitialization {
     pub sensor_scanning_enabled_on_startup: bool,
 }
 
+bitflags::bitflags! {
+    struct InitializationFlags: u8 {
+        const SETTABLE = 1 << 7;
+        const SCANNING = 1 << 6;
+        const EVENTS = 1 << 5;
+        const THRESHOLDS = 1 << 4;
+        const HYSTERESIS = 1 << 3;
+        const TYPE = 1 << 2;
+        const EVENTGEN_ON_STARTUP = 1 << 1;
+        const SCANNING_ON_STARTUP = 1 << 0;
+    }
+}
+
 impl From<u8> for SensorInitialization {
     fn from(value: u8) -> Self {
-        bitflags::bitflags! {
-            pub struct Flags: u8 {
-                const SETTABLE = 1 << 7;
-                const SCANNING = 1 << 6;
-                const EVENTS = 1 << 5;
-                const THRESHOLDS = 1 << 4;
-                const HYSTERESIS = 1 << 3;
-                const TYPE = 1 << 2;
-                const EVENTGEN_ON_STARTUP = 1 << 1;
-                const SCANNING_ON_STARTUP = 1 << 0;
-            }
-        }
+        type Flags = InitializationFlags;
 
         let flags = Flags::from_bits_truncate(value);
 
@@ -209,7 +318,7 @@ impl From<u8> for SensorInitialization {
             scanning: flags.contains(Flags::SCANNING),
             events: flags.contains(Flags::EVENTS),
             thresholds: flags.contains(Flags::THRESHOLDS),
-            hysteresis: flags.contains(Flags::THRESHOLDS),
+            hysteresis: flags.contains(Flags::HYSTERESIS),
             sensor_type: flags.contains(Flags::TYPE),
             event_generation_enabled_on_startup: flags.contains(Flags::EVENTGEN_ON_STARTUP),
             sensor_scanning_enabled_on_startup: flags.contains(Flags::SCANNING_ON_STARTUP),
@@ -217,8 +326,32 @@ impl From<u8> for SensorInitialization {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+impl From<SensorInitialization> for u8 {
+    fn from(value: SensorInitialization) -> Self {
+        type Flags = InitializationFlags;
+
+        let mut flags = Flags::empty();
+        flags.set(Flags::SETTABLE, value.settable);
+        flags.set(Flags::SCANNING, value.scanning);
+        flags.set(Flags::EVENTS, value.events);
+        flags.set(Flags::THRESHOLDS, value.thresholds);
+        flags.set(Flags::HYSTERESIS, value.hysteresis);
+        flags.set(Flags::TYPE, value.sensor_type);
+        flags.set(
+            Flags::EVENTGEN_ON_STARTUP,
+            value.event_generation_enabled_on_startup,
+        );
+        flags.set(
+            Flags::SCANNING_ON_STARTUP,
+            value.sensor_scanning_enabled_on_startup,
+        );
+        flags.bits()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum HysteresisCapability {
+    #[default]
     NoneOrUnspecified,
     Readable,
     ReadableAndSettable,
@@ -226,6 +359,7 @@ pub enum HysteresisCapability {
 }
 
 bitflags::bitflags! {
+    #[derive(Default)]
     pub struct ThresholdAssertEventMask: u16 {
         const UPPER_NON_RECOVERABLE_GOING_HIGH = 1 << 11;
         const UPPER_NON_RECOVERABLE_GOING_LOW = 1 << 10;
@@ -295,8 +429,7 @@ pub enum EventKind {
     GoingLow,
 }
 
-#[derive(Debug, Clone, Copy)]
-
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Thresholds {
     pub lower_non_recoverable: bool,
     pub lower_critical: bool,
@@ -307,6 +440,30 @@ pub struct Thresholds {
 }
 
 impl Thresholds {
+    /// Parse a threshold mask, where bits 0 through 5 indicate the lower
+    /// non-critical, lower critical, lower non-recoverable, upper non-critical,
+    /// upper critical and upper non-recoverable thresholds respectively.
+    pub fn from_bits(bits: u8) -> Self {
+        Self {
+            lower_non_critical: (bits & 0x01) == 0x01,
+            lower_critical: (bits & 0x02) == 0x02,
+            lower_non_recoverable: (bits & 0x04) == 0x04,
+            upper_non_critical: (bits & 0x08) == 0x08,
+            upper_critical: (bits & 0x10) == 0x10,
+            upper_non_recoverable: (bits & 0x20) == 0x20,
+        }
+    }
+
+    /// Get the threshold mask, in the format accepted by [`Thresholds::from_bits`].
+    pub fn bits(&self) -> u8 {
+        (self.lower_non_critical as u8)
+            | (self.lower_critical as u8) << 1
+            | (self.lower_non_recoverable as u8) << 2
+            | (self.upper_non_critical as u8) << 3
+            | (self.upper_critical as u8) << 4
+            | (self.upper_non_recoverable as u8) << 5
+    }
+
     pub fn for_kind(&self, kind: ThresholdKind) -> bool {
         match kind {
             ThresholdKind::LowerNonCritical => self.lower_non_critical,
@@ -354,8 +511,9 @@ pub struct Threshold {
     pub event_deassert_going_low: bool,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ThresholdAccessCapability {
+    #[default]
     None,
     Readable {
         readable: Thresholds,
@@ -392,7 +550,18 @@ impl ThresholdAccessCapability {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The event and reading masks of a sensor that is not threshold-based.
+///
+/// Every bit corresponds to an offset (state) of the event/reading type
+/// of the sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DiscreteMasks {
+    pub assertion_events: u16,
+    pub deassertion_events: u16,
+    pub readable_offsets: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct SensorCapabilities {
     pub ignore: bool,
     pub auto_rearm: bool,
@@ -402,6 +571,13 @@ pub struct SensorCapabilities {
     pub threshold_access: ThresholdAccessCapability,
     pub assertion_threshold_events: ThresholdAssertEventMask,
     pub deassertion_threshold_events: ThresholdAssertEventMask,
+    /// The event and reading masks, if the sensor is not threshold-based.
+    ///
+    /// If this is `Some`, the threshold masks are empty.
+    pub discrete_masks: Option<DiscreteMasks>,
+    /// Bits of the masks that are not represented by the fields above,
+    /// such as reserved bits.
+    reserved_mask_bits: [u16; 3],
 }
 
 impl SensorCapabilities {
@@ -413,7 +589,7 @@ impl SensorCapabilities {
     ) -> Self {
         let ignore = (caps & 0x80) == 0x80;
         let auto_rearm = (caps & 0x40) == 0x40;
-        let hysteresis = match caps & 0x30 >> 4 {
+        let hysteresis = match (caps & 0x30) >> 4 {
             0b00 => HysteresisCapability::NoneOrUnspecified,
             0b01 => HysteresisCapability::Readable,
             0b10 => HysteresisCapability::ReadableAndSettable,
@@ -431,27 +607,13 @@ impl SensorCapabilities {
             lower_critical: ((assert_lower_thrsd >> 13) & 0x1) == 1,
             lower_non_critical: ((assert_lower_thrsd >> 12) & 0x1) == 1,
             upper_non_recoverable: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
-            upper_critical: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
-            upper_non_critical: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
-        };
-
-        let threshold_set_mask = Thresholds {
-            upper_non_recoverable: ((discrete_rd_thrsd_set_thrshd_read >> 13) & 0x1) == 1,
-            upper_critical: ((discrete_rd_thrsd_set_thrshd_read >> 12) & 0x1) == 1,
-            upper_non_critical: ((discrete_rd_thrsd_set_thrshd_read >> 11) & 0x1) == 1,
-            lower_non_recoverable: ((discrete_rd_thrsd_set_thrshd_read >> 10) & 0x1) == 1,
-            lower_critical: ((discrete_rd_thrsd_set_thrshd_read >> 9) & 0x1) == 1,
-            lower_non_critical: ((discrete_rd_thrsd_set_thrshd_read >> 8) & 0x1) == 1,
+            upper_critical: ((deassert_upper_thrshd >> 13) & 0x1) == 1,
+            upper_non_critical: ((deassert_upper_thrshd >> 12) & 0x1) == 1,
         };
 
-        let threshold_read_mask = Thresholds {
-            upper_non_recoverable: ((discrete_rd_thrsd_set_thrshd_read >> 5) & 0x1) == 1,
-            upper_critical: ((discrete_rd_thrsd_set_thrshd_read >> 4) & 0x1) == 1,
-            upper_non_critical: ((discrete_rd_thrsd_set_thrshd_read >> 3) & 0x1) == 1,
-            lower_non_recoverable: ((discrete_rd_thrsd_set_thrshd_read >> 2) & 0x1) == 1,
-            lower_critical: ((discrete_rd_thrsd_set_thrshd_read >> 1) & 0x1) == 1,
-            lower_non_critical: (discrete_rd_thrsd_set_thrshd_read & 0x1) == 1,
-        };
+        let threshold_set_mask =
+            Thresholds::from_bits((discrete_rd_thrsd_set_thrshd_read >> 8) as u8);
+        let threshold_read_mask = Thresholds::from_bits(discrete_rd_thrsd_set_thrshd_read as u8);
 
         let threshold_access_support = match (caps & 0xC) >> 2 {
             0b00 => ThresholdAccessCapability::None,
@@ -478,7 +640,135 @@ impl SensorCapabilities {
             threshold_access: threshold_access_support,
             assertion_threshold_events: assertion_event_mask,
             deassertion_threshold_events: deassertion_event_mask,
+            discrete_masks: None,
+            reserved_mask_bits: [0; 3],
+        }
+        .with_reserved_mask_bits([
+            assert_lower_thrsd,
+            deassert_upper_thrshd,
+            discrete_rd_thrsd_set_thrshd_read,
+        ])
+    }
+
+    /// Create the capabilities of a sensor that is not threshold-based.
+    pub fn new_discrete(
+        caps: u8,
+        assertion_events: u16,
+        deassertion_events: u16,
+        readable_offsets: u16,
+    ) -> Self {
+        let threshold_access = match (caps & 0xC) >> 2 {
+            0b00 => ThresholdAccessCapability::None,
+            0b01 => ThresholdAccessCapability::Readable {
+                readable: Default::default(),
+                values: Default::default(),
+            },
+            0b10 => ThresholdAccessCapability::ReadableAndSettable {
+                readable: Default::default(),
+                values: Default::default(),
+                settable: Default::default(),
+            },
+            _ => ThresholdAccessCapability::FixedAndUnreadable {
+                supported: Default::default(),
+            },
+        };
+
+        Self {
+            threshold_access,
+            assertion_threshold_events: Default::default(),
+            deassertion_threshold_events: Default::default(),
+            discrete_masks: Some(DiscreteMasks {
+                assertion_events: assertion_events & 0x7FFF,
+                deassertion_events: deassertion_events & 0x7FFF,
+                readable_offsets: readable_offsets & 0x7FFF,
+            }),
+            ..Self::new(caps, 0, 0, 0)
+        }
+        .with_reserved_mask_bits([assertion_events, deassertion_events, readable_offsets])
+    }
+
+    /// Keep the bits of `masks` that are not represented by the other
+    /// fields, so that they are returned by [`SensorCapabilities::masks`].
+    fn with_reserved_mask_bits(mut self, masks: [u16; 3]) -> Self {
+        let represented = self.masks();
+
+        for ((reserved, mask), represented) in self
+            .reserved_mask_bits
+            .iter_mut()
+            .zip(masks)
+            .zip(represented)
+        {
+            *reserved = mask & !represented;
+        }
+
+        self
+    }
+
+    /// Get the sensor capabilities byte.
+    pub fn caps(&self) -> u8 {
+        let hysteresis = match self.hysteresis {
+            HysteresisCapability::NoneOrUnspecified => 0b00,
+            HysteresisCapability::Readable => 0b01,
+            HysteresisCapability::ReadableAndSettable => 0b10,
+            HysteresisCapability::FixedAndUnreadable => 0b11,
+        };
+
+        let threshold_access = match self.threshold_access {
+            ThresholdAccessCapability::None => 0b00,
+            ThresholdAccessCapability::Readable { .. } => 0b01,
+            ThresholdAccessCapability::ReadableAndSettable { .. } => 0b10,
+            ThresholdAccessCapability::FixedAndUnreadable { .. } => 0b11,
+        };
+
+        (self.ignore as u8) << 7
+            | (self.auto_rearm as u8) << 6
+            | hysteresis << 4
+            | threshold_access << 2
+            | (self.event_message_control & 0b11)
+    }
+
+    /// Get the assertion, deassertion and reading masks, in
+    /// the order in which they appear in sensor records.
+    ///
+    /// Masks that do not apply to the threshold access capability
+    /// of the sensor are returned as zero, except for bits that were
+    /// kept when parsing the capabilities.
+    pub fn masks(&self) -> [u16; 3] {
+        let [assertion, deassertion, reading] = self.reserved_mask_bits;
+
+        if let Some(discrete) = self.discrete_masks {
+            return [
+                discrete.assertion_events | assertion,
+                discrete.deassertion_events | deassertion,
+                discrete.readable_offsets | reading,
+            ];
         }
+
+        let none = Thresholds::default();
+        let (readable, values, settable) = match self.threshold_access {
+            ThresholdAccessCapability::None => (none, none, none),
+            ThresholdAccessCapability::Readable { readable, values } => (readable, values, none),
+            ThresholdAccessCapability::ReadableAndSettable {
+                readable,
+                values,
+                settable,
+            } => (readable, values, settable),
+            ThresholdAccessCapability::FixedAndUnreadable { supported } => (supported, none, none),
+        };
+
+        let lower_values = (values.lower_non_critical as u16) << 12
+            | (values.lower_critical as u16) << 13
+            | (values.lower_non_recoverable as u16) << 14;
+
+        let upper_values = (values.upper_non_critical as u16) << 12
+            | (values.upper_critical as u16) << 13
+            | (values.upper_non_recoverable as u16) << 14;
+
+        [
+            self.assertion_threshold_events.bits() | lower_values | assertion,
+            self.deassertion_threshold_events.bits() | upper_values | deassertion,
+            readable.bits() as u16 | (settable.bits() as u16) << 8 | reading,
+        ]
     }
 }
 
@@ -489,6 +779,32 @@ pub enum DataFormat {
     TwosComplement,
 }
 
+impl DataFormat {
+    /// Parse the analog data format bits of the Sensor Units 1 byte,
+    /// where `None` indicates that the sensor does not return an analog
+    /// reading.
+    pub fn from_units_1(sensor_units_1: u8) -> Option<Self> {
+        match (sensor_units_1 >> 6) & 0x03 {
+            0b00 => Some(DataFormat::Unsigned),
+            0b01 => Some(DataFormat::OnesComplement),
+            0b10 => Some(DataFormat::TwosComplement),
+            _ => None,
+        }
+    }
+
+    /// Get the analog data format bits of the Sensor Units 1 byte.
+    pub fn units_1_bits(value: Option<Self>) -> u8 {
+        let bits = match value {
+            Some(DataFormat::Unsigned) => 0b00,
+            Some(DataFormat::OnesComplement) => 0b01,
+            Some(DataFormat::TwosComplement) => 0b10,
+            None => 0b11,
+        };
+
+        bits << 6
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RateUnit {
     Microsecond,
@@ -548,10 +864,34 @@ impl SensorUnits {
             is_percentage,
         }
     }
-}
 
-#[derive(Debug, Clone, Copy)]
+    /// Write the Sensor Units 1, base unit and modifier unit bytes.
+    ///
+    /// The analog data format bits of Sensor Units 1 are left unset.
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        let rate = match self.rate {
+            None => 0b000,
+            Some(RateUnit::Microsecond) => 0b001,
+            Some(RateUnit::Millisecond) => 0b010,
+            Some(RateUnit::Second) => 0b011,
+            Some(RateUnit::Minute) => 0b100,
+            Some(RateUnit::Hour) => 0b101,
+            Some(RateUnit::Day) => 0b110,
+        };
+
+        let (modifier, modifier_unit) = match self.modifier {
+            None => (0b00, 0),
+            Some(ModifierUnit::BasUnitDivByModifier(unit)) => (0b01, unit.into()),
+            Some(ModifierUnit::BaseUnitMulByModifier(unit)) => (0b10, unit.into()),
+        };
 
+        buffer.push(rate << 3 | modifier << 1 | self.is_percentage as u8);
+        buffer.push(self.base_unit.into());
+        buffer.push(modifier_unit);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Linearization {
     Linear,
     Ln,
@@ -565,10 +905,47 @@ pub enum Linearization {
     Cube,
     Sqrt,
     CubeRoot,
+    /// A non-linear sensor, whose reading factors depend on the reading
+    /// and must be retrieved with `GetSensorReadingFactors`.
+    NonLinear,
+    /// An OEM non-linear sensor (`0x71` through `0x7F`), whose reading
+    /// factors are retrieved like those of [`Linearization::NonLinear`].
     Oem(u8),
     Unknown(u8),
 }
 
+impl Linearization {
+    /// Whether this is a (possibly OEM) non-linear sensor.
+    pub fn is_non_linear(&self) -> bool {
+        matches!(self, Self::NonLinear | Self::Oem(_))
+    }
+
+    /// Apply the linearization function to `value`.
+    ///
+    /// Returns `None` if the linearization is unknown. The
+    /// reading factors of non-linear sensors are already linear,
+    /// so `value` is returned as-is for them.
+    pub fn apply(&self, value: f32) -> Option<f32> {
+        let value = match self {
+            Self::Linear | Self::NonLinear | Self::Oem(_) => value,
+            Self::Ln => value.ln(),
+            Self::Log10 => value.log10(),
+            Self::Log2 => value.log2(),
+            Self::E => value.exp(),
+            Self::Exp10 => 10f32.powf(value),
+            Self::Exp2 => value.exp2(),
+            Self::OneOverX => value.recip(),
+            Self::Sqr => value.powi(2),
+            Self::Cube => value.powi(3),
+            Self::Sqrt => value.sqrt(),
+            Self::CubeRoot => value.cbrt(),
+            Self::Unknown(_) => return None,
+        };
+
+        Some(value)
+    }
+}
+
 impl From<u8> for Linearization {
     fn from(value: u8) -> Self {
         match value {
@@ -581,16 +958,38 @@ impl From<u8> for Linearization {
             6 => Self::Exp2,
             7 => Self::OneOverX,
             8 => Self::Sqr,
-            9 => Self::Sqrt,
-            10 => Self::Cube,
-            11 => Self::Sqrt,
-            12 => Self::CubeRoot,
+            9 => Self::Cube,
+            10 => Self::Sqrt,
+            11 => Self::CubeRoot,
+            0x70 => Self::NonLinear,
             0x71..=0x7F => Self::Oem(value),
             v => Self::Unknown(v),
         }
     }
 }
 
+impl From<Linearization> for u8 {
+    fn from(value: Linearization) -> Self {
+        match value {
+            Linearization::Linear => 0,
+            Linearization::Ln => 1,
+            Linearization::Log10 => 2,
+            Linearization::Log2 => 3,
+            Linearization::E => 4,
+            Linearization::Exp10 => 5,
+            Linearization::Exp2 => 6,
+            Linearization::OneOverX => 7,
+            Linearization::Sqr => 8,
+            Linearization::Cube => 9,
+            Linearization::Sqrt => 10,
+            Linearization::CubeRoot => 11,
+            Linearization::NonLinear => 0x70,
+            Linearization::Oem(v) => v,
+            Linearization::Unknown(v) => v,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Direction {
     UnspecifiedNotApplicable,
@@ -612,6 +1011,16 @@ impl TryFrom<u8> for Direction {
     }
 }
 
+impl From<Direction> for u8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::UnspecifiedNotApplicable => 0b00,
+            Direction::Input => 0b01,
+            Direction::Output => 0b10,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeLengthRaw<'a>(u8, &'a [u8]);
 
@@ -630,13 +1039,12 @@ impl<'a> From<TypeLengthRaw<'a>> for SensorId {
 
         let data = &data[..(length as usize).min(data.len())];
 
-        let str = core::str::from_utf8(data).map(ToString::to_string);
-
         match type_code {
-            0b00 => SensorId::Unicode(str.unwrap()),
+            0b00 => SensorId::Unicode(String::from_utf8_lossy(data).into_owned()),
             0b01 => SensorId::BCDPlus(data.to_vec()),
             0b10 => SensorId::Ascii6BPacked(data.to_vec()),
-            0b11 => SensorId::Ascii8BAndLatin1(str.unwrap()),
+            // Every Latin-1 character has the same value as its code point.
+            0b11 => SensorId::Ascii8BAndLatin1(data.iter().copied().map(char::from).collect()),
             _ => unreachable!(),
         }
     }
@@ -650,12 +1058,47 @@ pub enum SensorId {
     Ascii8BAndLatin1(String),
 }
 
+impl SensorId {
+    /// The maximum length of an encoded ID string.
+    pub const MAX_LEN: usize = 0x1F;
+
+    fn type_code_and_data(&self) -> (u8, Vec<u8>) {
+        match self {
+            SensorId::Unicode(v) => (0b00, v.as_bytes().to_vec()),
+            SensorId::BCDPlus(v) => (0b01, v.clone()),
+            SensorId::Ascii6BPacked(v) => (0b10, v.clone()),
+            SensorId::Ascii8BAndLatin1(v) => (
+                0b11,
+                v.chars().map(|c| u8::try_from(c).unwrap_or(b'?')).collect(),
+            ),
+        }
+    }
+
+    /// The length of the encoded ID string, excluding the type/length byte.
+    pub fn encoded_len(&self) -> usize {
+        self.type_code_and_data().1.len()
+    }
+
+    /// Write the type/length byte, followed by the ID string.
+    ///
+    /// The caller must ensure that the encoded ID string is no longer
+    /// than [`SensorId::MAX_LEN`], as [`Record::to_bytes`] does.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        let (type_code, data) = self.type_code_and_data();
+
+        debug_assert!(data.len() <= Self::MAX_LEN, "ID string is too long");
+
+        buffer.push(type_code << 6 | data.len() as u8);
+        buffer.extend_from_slice(&data);
+    }
+}
+
 impl core::fmt::Display for SensorId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             SensorId::Unicode(v) => write!(f, "{}", v),
             SensorId::Ascii8BAndLatin1(v) => write!(f, "{}", v),
-            _ => todo!(),
+            SensorId::BCDPlus(v) | SensorId::Ascii6BPacked(v) => write!(f, "{:02X?}", v),
         }
     }
 }
@@ -666,7 +1109,7 @@ impl Default for SensorId {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SensorNumber(pub NonMaxU8);
 
 impl SensorNumber {
@@ -679,6 +1122,35 @@ impl SensorNumber {
     }
 }
 
+/// The reserved bits of a record, followed by any data that follows the
+/// fields of the record.
+///
+/// These are kept when a record is parsed, so that it is written back unchanged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReservedBits(Vec<u8>);
+
+impl ReservedBits {
+    /// Keep the bits of `data` that are set in `mask`, and all
+    /// data that follows the part covered by `mask`.
+    pub(crate) fn parse(data: &[u8], mask: &[u8]) -> Self {
+        let bits = data.iter().zip(mask).map(|(data, mask)| data & mask);
+        let rest = data.iter().skip(mask.len()).copied();
+
+        Self(bits.chain(rest).collect())
+    }
+
+    /// Set the kept bits in the data that was written to `buffer`
+    /// starting at `start`, and append the kept data that follows it.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>, start: usize) {
+        for (idx, bits) in self.0.iter().enumerate() {
+            match buffer.get_mut(start + idx) {
+                Some(value) => *value |= bits,
+                None => buffer.push(*bits),
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RecordHeader {
     pub id: RecordId,
@@ -697,15 +1169,56 @@ pub struct Record {
 pub enum RecordContents {
     FullSensor(FullSensorRecord),
     CompactSensor(CompactSensorRecord),
+    EventOnly(EventOnlyRecord),
+    EntityAssociation(EntityAssociationRecord),
+    DeviceRelativeEntityAssociation(DeviceRelativeEntityAssociationRecord),
+    GenericDeviceLocator(GenericDeviceLocatorRecord),
+    ManagementControllerConfirmation(ManagementControllerConfirmationRecord),
+    BmcMessageChannelInfo(BmcMessageChannelInfoRecord),
+    Oem(OemRecord),
     Unknown { ty: u8, data: Vec<u8> },
 }
 
+impl RecordContents {
+    /// The record type of these contents.
+    pub fn record_type(&self) -> u8 {
+        match self {
+            RecordContents::FullSensor(_) => 0x01,
+            RecordContents::CompactSensor(_) => 0x02,
+            RecordContents::EventOnly(_) => 0x03,
+            RecordContents::EntityAssociation(_) => 0x08,
+            RecordContents::DeviceRelativeEntityAssociation(_) => 0x09,
+            RecordContents::GenericDeviceLocator(_) => 0x10,
+            RecordContents::ManagementControllerConfirmation(_) => 0x13,
+            RecordContents::BmcMessageChannelInfo(_) => 0x14,
+            RecordContents::Oem(_) => 0xC0,
+            RecordContents::Unknown { ty, .. } => *ty,
+        }
+    }
+
+    /// Write the record data, excluding the record header.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        match self {
+            RecordContents::FullSensor(r) => r.write_data(buffer),
+            RecordContents::CompactSensor(r) => r.write_data(buffer),
+            RecordContents::EventOnly(r) => r.write_data(buffer),
+            RecordContents::EntityAssociation(r) => r.write_data(buffer),
+            RecordContents::DeviceRelativeEntityAssociation(r) => r.write_data(buffer),
+            RecordContents::GenericDeviceLocator(r) => r.write_data(buffer),
+            RecordContents::ManagementControllerConfirmation(r) => r.write_data(buffer),
+            RecordContents::BmcMessageChannelInfo(r) => r.write_data(buffer),
+            RecordContents::Oem(r) => r.write_data(buffer),
+            RecordContents::Unknown { data, .. } => buffer.extend_from_slice(data),
+        }
+    }
+}
+
 impl Record {
     pub fn common_data(&self) -> Option<&SensorRecordCommon> {
         match &self.contents {
             RecordContents::FullSensor(s) => Some(s.common()),
             RecordContents::CompactSensor(s) => Some(s.common()),
-            RecordContents::Unknown { .. } => None,
+            _ => None,
         }
     }
 
@@ -741,15 +1254,40 @@ impl Record {
             return None;
         }
 
-        let contents = if record_type == 0x01 {
-            RecordContents::FullSensor(FullSensorRecord::parse(record_data).ok()?)
-        } else if record_type == 0x02 {
-            RecordContents::CompactSensor(CompactSensorRecord::parse(record_data)?)
-        } else {
-            RecordContents::Unknown {
+        // Records of other types than sensor records fall back to
+        // `Unknown` if they cannot be parsed.
+        let typed = |contents: Option<RecordContents>| {
+            contents.unwrap_or_else(|| RecordContents::Unknown {
                 ty: record_type,
                 data: record_data.to_vec(),
-            }
+            })
+        };
+
+        let contents = match record_type {
+            0x01 => RecordContents::FullSensor(FullSensorRecord::parse(record_data).ok()?),
+            0x02 => RecordContents::CompactSensor(CompactSensorRecord::parse(record_data)?),
+            0x03 => typed(EventOnlyRecord::parse(record_data).map(RecordContents::EventOnly)),
+            0x08 => typed(
+                EntityAssociationRecord::parse(record_data).map(RecordContents::EntityAssociation),
+            ),
+            0x09 => typed(
+                DeviceRelativeEntityAssociationRecord::parse(record_data)
+                    .map(RecordContents::DeviceRelativeEntityAssociation),
+            ),
+            0x10 => typed(
+                GenericDeviceLocatorRecord::parse(record_data)
+                    .map(RecordContents::GenericDeviceLocator),
+            ),
+            0x13 => typed(
+                ManagementControllerConfirmationRecord::parse(record_data)
+                    .map(RecordContents::ManagementControllerConfirmation),
+            ),
+            0x14 => typed(
+                BmcMessageChannelInfoRecord::parse(record_data)
+                    .map(RecordContents::BmcMessageChannelInfo),
+            ),
+            0xC0 => typed(OemRecord::parse(record_data).map(RecordContents::Oem)),
+            _ => typed(None),
         };
 
         Some(Self {
@@ -762,19 +1300,102 @@ impl Record {
         })
     }
 
+    /// Get the binary representation of this record, including its header.
+    ///
+    /// This is the inverse of [`Record::parse`]: reserved bits and bytes that
+    /// were parsed are written back unchanged.
+    ///
+    /// Returns `None` if the record data is longer than 255 bytes, if
+    /// the ID string is longer than [`SensorId::MAX_LEN`], or if the minor
+    /// firmware revision of a Management Controller Confirmation record
+    /// cannot be encoded as two BCD digits.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        if self
+            .id()
+            .is_some_and(|id| id.encoded_len() > SensorId::MAX_LEN)
+        {
+            return None;
+        }
+
+        if let RecordContents::ManagementControllerConfirmation(r) = &self.contents {
+            if r.minor_fw_revision > 99 {
+                return None;
+            }
+        }
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.header.id.value().to_le_bytes());
+        data.push(
+            (self.header.sdr_version_minor & 0xF) << 4 | (self.header.sdr_version_major & 0xF),
+        );
+        data.push(self.contents.record_type());
+        // Placeholder for the record length
+        data.push(0);
+
+        self.contents.write_data(&mut data);
+
+        data[4] = u8::try_from(data.len() - 5).ok()?;
+        Some(data)
+    }
+
     pub fn id(&self) -> Option<&SensorId> {
         match &self.contents {
             RecordContents::FullSensor(full) => Some(full.id_string()),
             RecordContents::CompactSensor(compact) => Some(compact.id_string()),
-            RecordContents::Unknown { .. } => None,
+            RecordContents::EventOnly(event_only) => Some(&event_only.id_string),
+            RecordContents::GenericDeviceLocator(locator) => Some(&locator.id_string),
+            _ => None,
         }
     }
 
+    pub fn entity(&self) -> Option<Entity> {
+        match &self.contents {
+            RecordContents::FullSensor(full) => Some(full.entity()),
+            RecordContents::CompactSensor(compact) => Some(compact.entity()),
+            RecordContents::EventOnly(event_only) => Some(Entity {
+                entity_id: event_only.entity_id,
+                entity_instance: event_only.entity_instance,
+            }),
+            RecordContents::GenericDeviceLocator(locator) => Some(Entity {
+                entity_id: locator.entity_id,
+                entity_instance: locator.entity_instance,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The key of the sensor described by this record.
+    pub fn sensor_key(&self) -> Option<&SensorKey> {
+        match &self.contents {
+            RecordContents::FullSensor(full) => Some(full.key_data()),
+            RecordContents::CompactSensor(compact) => Some(compact.key_data()),
+            RecordContents::EventOnly(event_only) => Some(&event_only.key),
+            _ => None,
+        }
+    }
+
+    /// The sensor numbers of the sensors described by this record.
+    ///
+    /// Compact and Event-Only records can be shared by several sensors
+    /// with consecutive sensor numbers.
+    pub fn sensor_numbers(&self) -> Option<RangeInclusive<u8>> {
+        let share_count = match &self.contents {
+            RecordContents::CompactSensor(compact) => compact.record_sharing.share_count,
+            RecordContents::EventOnly(event_only) => event_only.record_sharing.share_count,
+            _ => 1,
+        };
+
+        let first = self.sensor_key()?.sensor_number.get();
+        let last = first.saturating_add(share_count.max(1) - 1);
+        Some(first..=last)
+    }
+
     pub fn sensor_number(&self) -> Option<SensorNumber> {
         match &self.contents {
             RecordContents::FullSensor(full) => Some(full.sensor_number()),
             RecordContents::CompactSensor(compact) => Some(compact.sensor_number()),
-            RecordContents::Unknown { .. } => None,
+            RecordContents::EventOnly(event_only) => Some(event_only.key.sensor_number),
+            _ => None,
         }
     }
 }
@@ -782,8 +1403,7 @@ impl Record {
 #[derive(Debug, Clone)]
 pub struct SensorRecordCommon {
     pub key: SensorKey,
-    // TODO: make a type EntityId
-    pub entity_id: u8,
+    pub entity_id: EntityId,
     pub entity_instance: EntityInstance,
     pub initialization: SensorInitialization,
     pub capabilities: SensorCapabilities,
@@ -799,13 +1419,13 @@ impl SensorRecordCommon {
     /// You _must_ remember to [`SensorRecordCommon::set_id`] once the ID of the
     /// record has been parsed.
     pub(crate) fn parse_without_id(record_data: &[u8]) -> Option<(Self, &[u8])> {
-        if record_data.len() < 17 {
+        if record_data.len() < 18 {
             return None;
         }
 
         let sensor_key = SensorKey::parse(&record_data[..3])?;
 
-        let entity_id = record_data[3];
+        let entity_id = record_data[3].into();
 
         let entity_instance = record_data[4];
         let entity_instance = EntityInstance::from(entity_instance);
@@ -816,7 +1436,7 @@ impl SensorRecordCommon {
         let sensor_capabilities = record_data[6];
 
         let sensor_type = record_data[7].into();
-        let event_reading_type_code = record_data[8].into();
+        let event_reading_type_code: EventReadingTypeCodes = record_data[8].into();
 
         let assertion_event_mask_lower_thrsd_reading_mask =
             u16::from_le_bytes([record_data[9], record_data[10]]);
@@ -825,12 +1445,21 @@ impl SensorRecordCommon {
         let settable_thrsd_readable_thrsd_mask =
             u16::from_le_bytes([record_data[13], record_data[14]]);
 
-        let capabilities = SensorCapabilities::new(
-            sensor_capabilities,
-            assertion_event_mask_lower_thrsd_reading_mask,
-            deassertion_event_mask_upper_thrsd_reading_mask,
-            settable_thrsd_readable_thrsd_mask,
-        );
+        let capabilities = if event_reading_type_code == EventReadingTypeCodes::Threshold {
+            SensorCapabilities::new(
+                sensor_capabilities,
+                assertion_event_mask_lower_thrsd_reading_mask,
+                deassertion_event_mask_upper_thrsd_reading_mask,
+                settable_thrsd_readable_thrsd_mask,
+            )
+        } else {
+            SensorCapabilities::new_discrete(
+                sensor_capabilities,
+                assertion_event_mask_lower_thrsd_reading_mask,
+                deassertion_event_mask_upper_thrsd_reading_mask,
+                settable_thrsd_readable_thrsd_mask,
+            )
+        };
 
         let sensor_units_1 = record_data[15];
         let base_unit = record_data[16];
@@ -857,6 +1486,51 @@ impl SensorRecordCommon {
     pub(crate) fn set_id(&mut self, id: SensorId) {
         self.sensor_id = id;
     }
+
+    /// Get the mask of the bits of the common sensor record data that are not
+    /// represented by [`SensorRecordCommon`], such as reserved sensor unit values.
+    ///
+    /// The bits of the sensor masks are kept by [`SensorCapabilities`] instead.
+    pub(crate) fn reserved_mask(record_data: &[u8]) -> [u8; 18] {
+        let mut mask = [0; 18];
+        let sensor_units_1 = record_data[15];
+
+        if (sensor_units_1 >> 3) & 0b111 == 0b111 {
+            mask[15] |= 0b111 << 3;
+        }
+
+        match (sensor_units_1 >> 1) & 0b11 {
+            0b00 => mask[17] = 0xFF,
+            0b11 => {
+                mask[15] |= 0b11 << 1;
+                mask[17] = 0xFF;
+            }
+            _ => {}
+        }
+
+        mask
+    }
+
+    /// Write the common sensor record data, excluding the
+    /// SensorID, which is written after the type-specific data.
+    ///
+    /// The analog data format bits of the Sensor Units 1 byte
+    /// are left unset.
+    pub(crate) fn write_data(&self, buffer: &mut Vec<u8>) {
+        self.key.write_data(buffer);
+        buffer.push(self.entity_id.into());
+        buffer.push(self.entity_instance.into());
+        buffer.push(self.initialization.into());
+        buffer.push(self.capabilities.caps());
+        buffer.push(self.ty.into());
+        buffer.push(self.event_reading_type_code.into());
+
+        for mask in self.capabilities.masks() {
+            buffer.extend_from_slice(&mask.to_le_bytes());
+        }
+
+        self.sensor_units.write_data(buffer);
+    }
 }
 
 impl Loggable for Record {
@@ -864,16 +1538,20 @@ impl Loggable for Record {
         let full = self.full_sensor();
         let compact = self.compact_sensor();
 
-        let mut log = Vec::new();
-
-        if full.is_some() {
-            log.push((0, "SDR Record (Full)").into());
-        } else if compact.is_some() {
-            log.push((0, "SDR Record (Compact)").into());
-        } else {
-            log.push((0, "Cannot log unknown sensor type").into());
-            return log;
-        }
+        let mut log = match &self.contents {
+            RecordContents::FullSensor(_) => vec![(0, "SDR Record (Full)").into()],
+            RecordContents::CompactSensor(_) => vec![(0, "SDR Record (Compact)").into()],
+            RecordContents::EventOnly(r) => r.into_log(),
+            RecordContents::EntityAssociation(r) => r.into_log(),
+            RecordContents::DeviceRelativeEntityAssociation(r) => r.into_log(),
+            RecordContents::GenericDeviceLocator(r) => r.into_log(),
+            RecordContents::ManagementControllerConfirmation(r) => r.into_log(),
+            RecordContents::BmcMessageChannelInfo(r) => r.into_log(),
+            RecordContents::Oem(r) => r.into_log(),
+            RecordContents::Unknown { .. } => {
+                return vec![(0, "Cannot log unknown sensor type").into()];
+            }
+        };
 
         let RecordHeader {
             id,
@@ -881,8 +1559,13 @@ impl Loggable for Record {
             sdr_version_minor: sdr_v_min,
         } = &self.header;
 
-        log.push((1, "Record ID", format!("0x{:04X}", id.0)).into());
-        log.push((1, "SDR Version", format!("{sdr_v_maj}.{sdr_v_min}")).into());
+        let header = [
+            (1, "Record ID", format!("0x{:04X}", id.0)).into(),
+            (1, "SDR Version", format!("{sdr_v_maj}.{sdr_v_min}")).into(),
+        ];
+
+        // Insert the header directly after the title
+        log.splice(1..1, header);
 
         if let Some(common) = self.common_data() {
             log.push((1, "Sensor Type", format!("{:?}", common.ty)).into());
@@ -902,7 +1585,7 @@ impl Loggable for Record {
             let min_reading = full.min_reading().map(display).unwrap_or("Unknown".into());
 
             log.push((1, "Sensor ID", full.id_string()).into());
-            log.push((1, "Entity ID", full.entity_id()).into());
+            log.push((1, "Entity ID", format!("{:?}", full.entity_id())).into());
             log.push((1, "Nominal reading", nominal_reading).into());
             log.push((1, "Max reading", max_reading).into());
             log.push((1, "Min reading", min_reading).into());
@@ -927,4 +1610,516 @@ mod tests {
             assert_eq!(x, value);
         }
     }
+
+    #[test]
+    fn sensor_flags() {
+        let initialization = SensorInitialization::from(0x08);
+        assert!(initialization.hysteresis);
+        assert!(!initialization.thresholds);
+
+        let capabilities = SensorCapabilities::new(0x20, 0, 0, 0);
+        assert!(matches!(
+            capabilities.hysteresis,
+            HysteresisCapability::ReadableAndSettable
+        ));
+
+        assert!(matches!(Linearization::from(9), Linearization::Cube));
+        assert!(matches!(Linearization::from(10), Linearization::Sqrt));
+        assert!(matches!(Linearization::from(11), Linearization::CubeRoot));
+        assert!(matches!(
+            Linearization::from(0x70),
+            Linearization::NonLinear
+        ));
+        assert!(matches!(
+            Linearization::from(0x71),
+            Linearization::Oem(0x71)
+        ));
+        assert_eq!(u8::from(Linearization::Oem(0x71)), 0x71);
+    }
+
+    #[test]
+    fn upper_threshold_value_mask() {
+        let values = |deassert_upper_thrshd| match SensorCapabilities::new(
+            0x04,
+            0,
+            deassert_upper_thrshd,
+            0,
+        )
+        .threshold_access
+        {
+            ThresholdAccessCapability::Readable { values, .. } => values,
+            a => panic!("Unexpected threshold access {a:?}"),
+        };
+
+        let critical = values(1 << 13);
+        assert!(critical.upper_critical);
+        assert!(!critical.upper_non_critical);
+        assert!(!critical.upper_non_recoverable);
+
+        let non_critical = values(1 << 12);
+        assert!(!non_critical.upper_critical);
+        assert!(non_critical.upper_non_critical);
+    }
+
+    const SENSOR_COMMON: [u8; 18] = [
+        0x20, 0x00, 0x10, 0x03, 0x01, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00,
+    ];
+
+    #[test]
+    fn parse_compact_record_sharing() {
+        let mut body = SENSOR_COMMON.to_vec();
+        body.extend_from_slice(&[0x40, 0x45, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC1, b'A']);
+
+        let compact = match record(0x02, &body).contents {
+            RecordContents::CompactSensor(compact) => compact,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        assert_eq!(compact.direction, Direction::Input);
+        assert_eq!(compact.record_sharing.modifier_offset, 0x45);
+        assert!(!compact.record_sharing.entity_instance_increments);
+    }
+
+    #[test]
+    fn parse_full_record_m_and_accuracy() {
+        let mut body = SENSOR_COMMON.to_vec();
+        body.extend_from_slice(&[0x00, 0x10, 0x80, 0x00, 0x05, 0x31]);
+        body.extend_from_slice(&[0x00; 18]);
+        body.extend_from_slice(&[0xC1, b'A']);
+
+        let full = match record(0x01, &body).contents {
+            RecordContents::FullSensor(full) => full,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        // 10-bit two's complement M of 0b10_0001_0000
+        assert_eq!(full.m, -496);
+        assert_eq!(full.accuracy, 5 | (3 << 6));
+        assert_eq!(full.direction, Direction::Input);
+    }
+
+    fn record(ty: u8, body: &[u8]) -> Record {
+        let mut data = vec![0x34, 0x12, 0x51, ty, body.len() as u8];
+        data.extend_from_slice(body);
+        let record = Record::parse(&data).unwrap();
+        assert_eq!(record.to_bytes().unwrap(), data);
+        record
+    }
+
+    fn entity(entity_id: u8, instance: u8) -> Entity {
+        Entity {
+            entity_id: entity_id.into(),
+            entity_instance: EntityInstance::from(instance),
+        }
+    }
+
+    #[test]
+    fn parse_event_only_record() {
+        let body = [
+            0x20, 0x00, 0x40, 0x07, 0x01, 0x07, 0x6F, 0x40, 0x00, 0x00, 0x00, 0xC4, b'C', b'P',
+            b'U', b'1',
+        ];
+
+        let record = record(0x03, &body);
+        let event_only = match &record.contents {
+            RecordContents::EventOnly(event_only) => event_only,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        assert_eq!(event_only.ty, SensorType::Processor);
+        assert_eq!(
+            event_only.event_reading_type_code,
+            EventReadingTypeCodes::SensorSpecific
+        );
+        assert_eq!(event_only.direction, Direction::Input);
+        assert_eq!(record.sensor_number().map(|v| v.get()), Some(0x40));
+        assert_eq!(
+            record.id(),
+            Some(&SensorId::Ascii8BAndLatin1("CPU1".into()))
+        );
+    }
+
+    #[test]
+    fn invalid_record_falls_back_to_unknown() {
+        let body = [0x20, 0x00, 0x40];
+
+        let record = record(0x03, &body);
+        assert!(matches!(
+            &record.contents,
+            RecordContents::Unknown { ty: 0x03, data } if data == &body
+        ));
+    }
+
+    #[test]
+    fn parse_entity_association_list() {
+        let body = [
+            0x17, 0x01, 0x40, 0x0A, 0x01, 0x0A, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = record(0x08, &body);
+        let association = match &record.contents {
+            RecordContents::EntityAssociation(association) => association,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        assert_eq!(association.container, entity(0x17, 0x01));
+        assert!(association.flags.linked);
+        assert_eq!(
+            association.contained_entities,
+            ContainedEntities::List(vec![entity(0x0A, 0x01), entity(0x0A, 0x02)])
+        );
+    }
+
+    #[test]
+    fn parse_entity_association_ranges() {
+        let body = [
+            0x17, 0x01, 0x80, 0x03, 0x01, 0x03, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = record(0x08, &body);
+        let association = match &record.contents {
+            RecordContents::EntityAssociation(association) => association,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        assert_eq!(
+            association.contained_entities,
+            ContainedEntities::Ranges(vec![EntityRange {
+                first: entity(0x03, 0x01),
+                last: entity(0x03, 0x04)
+            }])
+        );
+    }
+
+    #[test]
+    fn entity_association_keeps_unused_slots() {
+        // Reserved flag bits, an unused slot with a non-zero instance, a
+        // gap between the contained entities and a half-populated range.
+        for body in [
+            [
+                0x17, 0x01, 0x5F, 0x00, 0x05, 0x0A, 0x01, 0x00, 0x00, 0x0A, 0x02,
+            ],
+            [
+                0x17, 0x01, 0x80, 0x03, 0x01, 0x03, 0x04, 0x03, 0x05, 0x00, 0x00,
+            ],
+        ] {
+            record(0x08, &body);
+        }
+
+        let record = record(
+            0x09,
+            &[
+                0x17, 0x01, 0x21, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x21, 0x05, 0x0A, 0x01, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+        );
+
+        let mut association = match record.contents {
+            RecordContents::DeviceRelativeEntityAssociation(association) => association,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        // Modified contained entities are written to the first slots.
+        let entity = association.contained_entities.entities()[0];
+        association.contained_entities = ContainedEntities::List(vec![entity, entity]);
+
+        let mut data = Vec::new();
+        association.write_data(&mut data);
+        assert_eq!(
+            data[5..13],
+            [0x20, 0x00, 0x0A, 0x01, 0x20, 0x00, 0x0A, 0x01]
+        );
+        assert_eq!(data[2..5], [0x21, 0x0F, 0x00]);
+    }
+
+    #[test]
+    fn sensor_id_encoding() {
+        let body = [
+            0x20, 0x00, 0x40, 0x07, 0x01, 0x07, 0x6F, 0x40, 0x00, 0x00, 0x00, 0xE3, b'C', 0xB0,
+            b'F',
+        ];
+
+        let mut record = record(0x03, &body);
+        assert_eq!(record.id(), Some(&SensorId::Ascii8BAndLatin1("C°F".into())));
+
+        let RecordContents::EventOnly(event_only) = &mut record.contents else {
+            panic!("Unexpected record contents {:?}", record.contents);
+        };
+
+        event_only.id_string = SensorId::Ascii8BAndLatin1("A".repeat(SensorId::MAX_LEN + 1));
+        assert!(record.to_bytes().is_none());
+    }
+
+    #[test]
+    fn parse_oem_record() {
+        let record = record(0xC0, &[0x57, 0x01, 0x00, 0xAA, 0xBB]);
+
+        let oem = match &record.contents {
+            RecordContents::Oem(oem) => oem,
+            c => panic!("Unexpected record contents {c:?}"),
+        };
+
+        assert_eq!(oem.manufacturer_id, 0x157);
+        assert_eq!(oem.data, vec![0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn compact_sensor_round_trip() {
+        let body = [
+            0x20, 0x00, 0x10, 0x07, 0x01, 0x67, 0x40, 0x07, 0x6F, 0x80, 0x03, 0x00, 0x00, 0x80,
+            0x03, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC4, b'C',
+            b'P', b'U', b'1',
+        ];
+
+        let record = record(0x02, &body);
+        let compact = record.compact_sensor().unwrap();
+
+        assert_eq!(compact.direction, Direction::Input);
+        assert_eq!(compact.record_sharing.share_count, 1);
+        assert_eq!(
+            compact.capabilities().discrete_masks,
+            Some(DiscreteMasks {
+                assertion_events: 0x0380,
+                deassertion_events: 0x0000,
+                readable_offsets: 0x0380,
+            })
+        );
+    }
+
+    #[test]
+    fn locator_and_info_records_round_trip() {
+        record(
+            0x09,
+            &[
+                0x17, 0x01, 0x20, 0x00, 0x00, 0x20, 0x00, 0x0A, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+        );
+
+        record(
+            0x10,
+            &[
+                0x20, 0xA0, 0x22, 0x01, 0x00, 0x10, 0x01, 0x07, 0x01, 0x00, 0xC3, b'D', b'I', b'M',
+            ],
+        );
+
+        let mut body = vec![
+            0x20, 0x01, 0x02, 0x03, 0x42, 0x02, 0xA2, 0x02, 0x00, 0x00, 0x01,
+        ];
+        body.extend(0..16);
+        let mut confirmation = record(0x13, &body);
+        match &mut confirmation.contents {
+            RecordContents::ManagementControllerConfirmation(r) => {
+                assert_eq!(r.minor_fw_revision, 42);
+                r.minor_fw_revision = 100;
+            }
+            c => panic!("Unexpected record contents {c:?}"),
+        }
+        assert!(confirmation.to_bytes().is_none());
+
+        record(
+            0x14,
+            &[0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0xFF],
+        );
+    }
+
+    fn header() -> RecordHeader {
+        RecordHeader {
+            id: RecordId::new_raw(0x0042),
+            sdr_version_major: 1,
+            sdr_version_minor: 5,
+        }
+    }
+
+    fn key() -> SensorKey {
+        SensorKey {
+            owner_id: SensorOwner::I2C(0x10),
+            owner_channel: 0,
+            fru_inv_device_owner_lun: LogicalUnit::Zero,
+            owner_lun: LogicalUnit::Zero,
+            sensor_number: SensorNumber::new(NonMaxU8::new(0x21).unwrap()),
+        }
+    }
+
+    #[test]
+    fn full_sensor_builder() {
+        let units = SensorUnits::from(0x00, 0x01, 0x00);
+
+        let full = FullSensorRecordBuilder::new(key(), SensorType::Temperature, units)
+            .entity(Entity::new(EntityId::Processor, 1))
+            .id_string(SensorId::Ascii8BAndLatin1("CPU Temp".into()))
+            .analog_data_format(Some(DataFormat::TwosComplement))
+            .conversion(-3, -200, 1, -2)
+            .accuracy(0x2AB, 2)
+            .tolerance(5)
+            .nominal_reading(40)
+            .threshold(ThresholdKind::UpperCritical, 90)
+            .hysteresis(2, 0)
+            .build()
+            .unwrap();
+
+        let record = Record {
+            header: header(),
+            contents: RecordContents::FullSensor(full),
+        };
+
+        let data = record.to_bytes().unwrap();
+        let parsed = Record::parse(&data).unwrap();
+        assert_eq!(parsed.to_bytes().unwrap(), data);
+
+        let parsed = parsed.full_sensor().unwrap();
+        assert_eq!(parsed.id_string().to_string(), "CPU Temp");
+        assert_eq!(parsed.entity(), Entity::new(EntityId::Processor, 1));
+        assert_eq!(parsed.analog_data_format, Some(DataFormat::TwosComplement));
+        assert_eq!((parsed.m, parsed.b), (-3, -200));
+        assert_eq!((parsed.b_exponent, parsed.result_exponent), (1, -2));
+        assert_eq!((parsed.accuracy, parsed.accuracy_exponent), (0x2AB, 2));
+        assert_eq!(parsed.tolerance, 5);
+        assert_eq!(parsed.nominal_reading(), Some(40));
+        assert_eq!(parsed.normal_maximum(), None);
+        assert_eq!(parsed.raw_threshold(ThresholdKind::UpperCritical), 90);
+        assert_eq!(
+            parsed
+                .positive_going_threshold_hysteresis_value
+                .map(|v| v.get()),
+            Some(2)
+        );
+
+        assert!(
+            FullSensorRecordBuilder::new(key(), SensorType::Temperature, units)
+                .conversion(512, 0, 0, 0)
+                .build()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn linearization() {
+        let units = SensorUnits::from(0x00, 0x01, 0x00);
+
+        let convert = |linearization: Linearization, raw: u8| {
+            FullSensorRecordBuilder::new(key(), SensorType::Temperature, units)
+                .analog_data_format(Some(DataFormat::Unsigned))
+                .linearization(linearization)
+                .conversion(2, 0, 0, 0)
+                .build()
+                .unwrap()
+                .convert(raw)
+                .map(|v| v.value())
+        };
+
+        assert_eq!(convert(Linearization::Linear, 4), Some(8.0));
+        assert_eq!(convert(Linearization::Sqr, 4), Some(64.0));
+        assert_eq!(convert(Linearization::Cube, 1), Some(8.0));
+        assert_eq!(convert(Linearization::Sqrt, 8), Some(4.0));
+        assert_eq!(convert(Linearization::CubeRoot, 4), Some(2.0));
+        assert_eq!(convert(Linearization::OneOverX, 2), Some(0.25));
+        assert_eq!(convert(Linearization::Log2, 4), Some(3.0));
+        assert_eq!(convert(Linearization::Log10, 50), Some(2.0));
+        assert_eq!(convert(Linearization::Exp2, 3), Some(64.0));
+        assert_eq!(convert(Linearization::Exp10, 1), Some(100.0));
+        assert_eq!(convert(Linearization::E, 0), Some(1.0));
+        assert_eq!(convert(Linearization::Ln, 0), Some(f32::NEG_INFINITY));
+        assert_eq!(convert(Linearization::NonLinear, 4), None);
+        assert_eq!(convert(Linearization::Oem(0x71), 4), None);
+        assert_eq!(convert(Linearization::Unknown(0x20), 4), None);
+    }
+
+    #[test]
+    fn tolerance_and_accuracy() {
+        let units = SensorUnits::from(0x00, 0x01, 0x00);
+
+        let builder = || {
+            FullSensorRecordBuilder::new(key(), SensorType::Temperature, units)
+                .analog_data_format(Some(DataFormat::Unsigned))
+                .conversion(2, 0, 0, -1)
+        };
+
+        let exact = builder().build().unwrap().convert(100).unwrap();
+        assert_eq!(exact.tolerance(), None);
+        assert_eq!(exact.accuracy(), None);
+        assert_eq!(exact.error(), 0.0);
+
+        let value = builder()
+            .tolerance(4)
+            .accuracy(150, 1)
+            .build()
+            .unwrap()
+            .convert(100)
+            .unwrap();
+
+        // +/- 2 raw counts of 0.2 degrees each
+        assert!((value.tolerance().unwrap() - 0.4).abs() < 1e-4);
+        // 150 * 10^1 hundredths of a percent
+        assert_eq!(value.accuracy(), Some(15.0));
+        // 0.4 + 15 % of 20
+        assert!((value.error() - 3.4).abs() < 1e-4);
+        assert!(value.within_error(23.0));
+        assert!(!value.within_error(23.5));
+
+        let squared = builder()
+            .linearization(Linearization::Sqr)
+            .tolerance(2)
+            .build()
+            .unwrap()
+            .convert(100)
+            .unwrap();
+
+        // (20.2)^2 - 20^2
+        assert!((squared.tolerance().unwrap() - 8.04).abs() < 1e-3);
+    }
+
+    #[test]
+    fn reading_factors_round_trip() {
+        let factors = ReadingFactors {
+            m: -3,
+            tolerance: 5,
+            b: -200,
+            accuracy: 0x2AB,
+            accuracy_exponent: 2,
+            result_exponent: -2,
+            b_exponent: 1,
+        };
+
+        let mut data = Vec::new();
+        factors.write_data(&mut data);
+        assert_eq!(ReadingFactors::parse(&data), Some(factors));
+    }
+
+    #[test]
+    fn compact_sensor_builder() {
+        let units = SensorUnits::from(0x00, 0x00, 0x00);
+        let capabilities = SensorCapabilities::new_discrete(0x40, 0x0003, 0x0000, 0x0003);
+
+        let compact = CompactSensorRecordBuilder::new(key(), SensorType::Processor, units)
+            .event_reading_type_code(EventReadingTypeCodes::SensorSpecific)
+            .capabilities(capabilities)
+            .id_string(SensorId::Ascii8BAndLatin1("CPU".into()))
+            .record_sharing(RecordSharing {
+                id_string_modifier: IdStringModifier::Numeric,
+                share_count: 2,
+                entity_instance_increments: true,
+                modifier_offset: 1,
+            })
+            .build()
+            .unwrap();
+
+        let record = Record {
+            header: header(),
+            contents: RecordContents::CompactSensor(compact),
+        };
+
+        assert_eq!(record.sensor_numbers(), Some(0x21..=0x22));
+
+        let data = record.to_bytes().unwrap();
+        let parsed = Record::parse(&data).unwrap();
+        assert_eq!(parsed.to_bytes().unwrap(), data);
+
+        let parsed = parsed.compact_sensor().unwrap();
+        assert_eq!(parsed.capabilities(), &capabilities);
+        assert_eq!(parsed.record_sharing.share_count, 2);
+        assert!(parsed.record_sharing.entity_instance_increments);
+        assert_eq!(parsed.record_sharing.modifier_offset, 1);
+    }
 }
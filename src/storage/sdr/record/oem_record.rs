@@ -0,0 +1,45 @@
+use crate::{log_vec, Loggable};
+
+/// An OEM record.
+///
+/// The contents of OEM records are specific to the manufacturer
+/// identified by `manufacturer_id`.
+#[derive(Debug, Clone)]
+pub struct OemRecord {
+    pub manufacturer_id: u32,
+    pub data: Vec<u8>,
+}
+
+impl OemRecord {
+    pub fn parse(record_data: &[u8]) -> Option<Self> {
+        if record_data.len() < 3 {
+            return None;
+        }
+
+        let manufacturer_id =
+            u32::from_le_bytes([record_data[0], record_data[1], record_data[2], 0]);
+
+        Some(Self {
+            manufacturer_id,
+            data: record_data[3..].to_vec(),
+        })
+    }
+
+    /// Write the record data, excluding the record header.
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.manufacturer_id.to_le_bytes()[..3]);
+        buffer.extend_from_slice(&self.data);
+    }
+}
+
+impl Loggable for OemRecord {
+    fn into_log(&self) -> Vec<crate::fmt::LogItem> {
+        let manf_id = self.manufacturer_id;
+
+        log_vec![
+            (0, "SDR Record (OEM)"),
+            (1, "Manufacturer ID", format!("0x{manf_id:02X}")),
+            (1, "Data", format!("{:02X?}", self.data)),
+        ]
+    }
+}
@@ -0,0 +1,36 @@
+use std::num::NonZeroU16;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// Reserve the SDR repository.
+///
+/// The returned reservation ID is required for partial reads of SDRs, and
+/// for modifying the SDR repository. A reservation is cancelled when the
+/// repository is modified, or when another reservation is made.
+#[derive(Debug, Clone, Copy)]
+pub struct ReserveRepository;
+
+impl From<ReserveRepository> for Message {
+    fn from(_: ReserveRepository) -> Self {
+        Message::new_request(NetFn::Storage, 0x22, Vec::new())
+    }
+}
+
+impl IpmiCommand for ReserveRepository {
+    type Output = NonZeroU16;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        NonZeroU16::new(u16::from_le_bytes([data[0], data[1]])).ok_or(ParseResponseError::Parse(()))
+    }
+}
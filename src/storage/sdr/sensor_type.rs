@@ -102,3 +102,58 @@ sensor_type! {
         [0xC0..=0xFF],
     }
 }
+
+impl core::fmt::Display for SensorType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            SensorType::Temperature => "Temperature",
+            SensorType::Voltage => "Voltage",
+            SensorType::Current => "Current",
+            SensorType::Fan => "Fan",
+            SensorType::ChassisIntrusion => "Physical Security",
+            SensorType::PlatformSecurityViolationAttempt => "Platform Security",
+            SensorType::Processor => "Processor",
+            SensorType::PowerSupply => "Power Supply",
+            SensorType::PowerUnit => "Power Unit",
+            SensorType::CoolingDevice => "Cooling Device",
+            SensorType::UnitsBasedSensor => "Other Units-based Sensor",
+            SensorType::Memory => "Memory",
+            SensorType::DriveSlotBay => "Drive Slot (Bay)",
+            SensorType::PostMemoryResize => "POST Memory Resize",
+            SensorType::SystemFirmwareProgress => "System Firmware Progress",
+            SensorType::EventLoggingDisabled => "Event Logging Disabled",
+            SensorType::Watchdog1 => "Watchdog 1",
+            SensorType::SystemEvent => "System Event",
+            SensorType::CriticalInterrupt => "Critical Interrupt",
+            SensorType::ButtonOrSwitch => "Button / Switch",
+            SensorType::ModuleOrBoard => "Module / Board",
+            SensorType::MicroControllerOrCoprocessor => "Microcontroller / Coprocessor",
+            SensorType::AddinCard => "Add-in Card",
+            SensorType::Chassis => "Chassis",
+            SensorType::ChipSet => "Chip Set",
+            SensorType::OtherFRU => "Other FRU",
+            SensorType::CableOrInterconnect => "Cable / Interconnect",
+            SensorType::Terminator => "Terminator",
+            SensorType::SystemBootOrRestartInitiated => "System Boot / Restart Initiated",
+            SensorType::BootError => "Boot Error",
+            SensorType::BaseOsBootOrInstallationStatus => "OS Boot",
+            SensorType::OsStopOrShutdown => "OS Stop / Shutdown",
+            SensorType::SlotOrConnector => "Slot / Connector",
+            SensorType::SystemACPIPowerState => "System ACPI Power State",
+            SensorType::Watchdog2 => "Watchdog 2",
+            SensorType::PlatformAlert => "Platform Alert",
+            SensorType::EntityPresence => "Entity Presence",
+            SensorType::MonitorAsicOrIc => "Monitor ASIC / IC",
+            SensorType::LAN => "LAN",
+            SensorType::ManagementSubSysHealth => "Management Subsystem Health",
+            SensorType::Battery => "Battery",
+            SensorType::SessionAudit => "Session Audit",
+            SensorType::VersionChange => "Version Change",
+            SensorType::FRUState => "FRU State",
+            SensorType::Reserved(v) => return write!(f, "Reserved (0x{v:02X})"),
+            SensorType::OemReserved(v) => return write!(f, "OEM (0x{v:02X})"),
+        };
+
+        write!(f, "{name}")
+    }
+}
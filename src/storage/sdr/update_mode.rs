@@ -0,0 +1,50 @@
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// Enter SDR Repository Update mode.
+///
+/// While in update mode, the BMC may refuse to execute commands
+/// other than those that modify the SDR repository.
+#[derive(Debug, Clone, Copy)]
+pub struct EnterUpdateMode;
+
+impl From<EnterUpdateMode> for Message {
+    fn from(_: EnterUpdateMode) -> Self {
+        Message::new_request(NetFn::Storage, 0x2A, Vec::new())
+    }
+}
+
+impl IpmiCommand for EnterUpdateMode {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)
+    }
+}
+
+/// Exit SDR Repository Update mode.
+#[derive(Debug, Clone, Copy)]
+pub struct ExitUpdateMode;
+
+impl From<ExitUpdateMode> for Message {
+    fn from(_: ExitUpdateMode) -> Self {
+        Message::new_request(NetFn::Storage, 0x2B, Vec::new())
+    }
+}
+
+impl IpmiCommand for ExitUpdateMode {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    num::NonZeroU16,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    connection::{CompletionCode, IpmiConnection},
+    Ipmi, IpmiCommandError,
+};
+
+use super::{
+    AddSdr, ClearSdrAction, ClearSdrRepository, DeleteSdr, EnterSdrRepositoryUpdateMode,
+    ExitSdrRepositoryUpdateMode, GetSdrRepositoryInfo, PartialAddSdr, RecordId,
+    ReserveSdrRepository, SdrErasureProgress, SdrOperation, SdrRepositoryInfo,
+};
+
+#[derive(Debug)]
+pub enum SdrWriteError<CON> {
+    /// The repository does not support the operation that
+    /// is required to perform the modification.
+    Unsupported(SdrOperation),
+    /// The length of the record does not match the length in its header.
+    InvalidRecord,
+    /// The record is too large to be added using Partial Add SDR.
+    RecordTooLarge,
+    /// Erasing the repository did not complete in time.
+    ClearTimedOut,
+    Command(IpmiCommandError<CON, ()>),
+}
+
+impl<CON> From<IpmiCommandError<CON, ()>> for SdrWriteError<CON> {
+    fn from(value: IpmiCommandError<CON, ()>) -> Self {
+        Self::Command(value)
+    }
+}
+
+/// Modify the SDR repository.
+///
+/// Every modification is checked against the operations that the
+/// repository reports as supported in its [`SdrRepositoryInfo`]. If the
+/// repository only supports modal updates, [`SdrWriter::enter_update_mode`]
+/// must be called before modifying it. Update mode is exited when the
+/// writer is dropped, if [`SdrWriter::exit_update_mode`] was not called.
+pub struct SdrWriter<'ipmi, CON>
+where
+    CON: IpmiConnection,
+{
+    ipmi: &'ipmi mut Ipmi<CON>,
+    info: SdrRepositoryInfo,
+    in_update_mode: bool,
+}
+
+impl<'ipmi, CON> SdrWriter<'ipmi, CON>
+where
+    CON: IpmiConnection,
+{
+    /// The amount of record data sent per Partial Add SDR request.
+    const PARTIAL_ADD_SIZE: usize = 16;
+
+    pub fn new(ipmi: &'ipmi mut Ipmi<CON>) -> Result<Self, SdrWriteError<CON::Error>> {
+        let info = ipmi.send_recv(GetSdrRepositoryInfo)?;
+
+        Ok(Self {
+            ipmi,
+            info,
+            in_update_mode: false,
+        })
+    }
+
+    /// The repository information that modifications are checked against.
+    pub fn info(&self) -> &SdrRepositoryInfo {
+        &self.info
+    }
+
+    fn require(&self, operation: SdrOperation) -> Result<(), SdrWriteError<CON::Error>> {
+        if self.info.supports(operation) {
+            Ok(())
+        } else {
+            Err(SdrWriteError::Unsupported(operation))
+        }
+    }
+
+    /// Check that the repository can currently be modified.
+    fn require_writable(&self) -> Result<(), SdrWriteError<CON::Error>> {
+        let non_modal = self.info.supports(SdrOperation::NonModalUpdate)
+            || self.info.supports(SdrOperation::ModalityUnspecified);
+
+        if non_modal || self.in_update_mode {
+            Ok(())
+        } else {
+            Err(SdrWriteError::Unsupported(SdrOperation::NonModalUpdate))
+        }
+    }
+
+    fn check_record(record: &[u8]) -> Result<(), SdrWriteError<CON::Error>> {
+        if record.len() >= 5 && record.len() == 5 + record[4] as usize {
+            Ok(())
+        } else {
+            Err(SdrWriteError::InvalidRecord)
+        }
+    }
+
+    fn reserve(&mut self) -> Result<NonZeroU16, SdrWriteError<CON::Error>> {
+        self.require(SdrOperation::Reserve)?;
+        Ok(self.ipmi.send_recv(ReserveSdrRepository)?)
+    }
+
+    pub fn enter_update_mode(&mut self) -> Result<(), SdrWriteError<CON::Error>> {
+        self.require(SdrOperation::ModalUpdate)?;
+        self.ipmi.send_recv(EnterSdrRepositoryUpdateMode)?;
+        self.in_update_mode = true;
+        Ok(())
+    }
+
+    pub fn exit_update_mode(&mut self) -> Result<(), SdrWriteError<CON::Error>> {
+        self.require(SdrOperation::ModalUpdate)?;
+        self.ipmi.send_recv(ExitSdrRepositoryUpdateMode)?;
+        self.in_update_mode = false;
+        Ok(())
+    }
+
+    /// Add a record, including its header, to the repository.
+    ///
+    /// The record is added using a single Add SDR request if possible. If
+    /// the BMC cannot accept the record in a single request, it is added
+    /// using Partial Add SDR instead.
+    ///
+    /// Returns the ID that the BMC assigned to the record.
+    pub fn add(&mut self, record: &[u8]) -> Result<RecordId, SdrWriteError<CON::Error>> {
+        self.require_writable()?;
+        Self::check_record(record)?;
+
+        match self.ipmi.send_recv(AddSdr::new(record.to_vec())) {
+            Ok(record_id) => Ok(record_id),
+            Err(e)
+                if matches!(
+                    e.completion_code(),
+                    Some(CompletionCode::RequestDataLenInvalid)
+                        | Some(CompletionCode::RequestDataLengthLimitExceeded)
+                ) && self.info.supports(SdrOperation::PartialAdd) =>
+            {
+                self.partial_add(record)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Add a record, including its header, to the repository
+    /// using Partial Add SDR.
+    ///
+    /// Returns the ID that the BMC assigned to the record.
+    pub fn partial_add(&mut self, record: &[u8]) -> Result<RecordId, SdrWriteError<CON::Error>> {
+        self.require_writable()?;
+        self.require(SdrOperation::PartialAdd)?;
+        Self::check_record(record)?;
+
+        // The offset of every part must fit in a single byte.
+        let last_offset = (record.len() - 1) / Self::PARTIAL_ADD_SIZE * Self::PARTIAL_ADD_SIZE;
+        if last_offset > u8::MAX as usize {
+            return Err(SdrWriteError::RecordTooLarge);
+        }
+
+        let reservation_id = self.reserve()?;
+        let mut record_id = RecordId::FIRST;
+
+        let chunks = record.chunks(Self::PARTIAL_ADD_SIZE);
+        let chunk_count = chunks.len();
+
+        for (idx, chunk) in chunks.enumerate() {
+            let offset = (idx * Self::PARTIAL_ADD_SIZE) as u8;
+            let last = idx + 1 == chunk_count;
+
+            let request =
+                PartialAddSdr::new(reservation_id, record_id, offset, last, chunk.to_vec());
+            record_id = self.ipmi.send_recv(request)?;
+        }
+
+        Ok(record_id)
+    }
+
+    /// Delete a record from the repository.
+    pub fn delete(&mut self, record_id: RecordId) -> Result<(), SdrWriteError<CON::Error>> {
+        self.require_writable()?;
+        self.require(SdrOperation::Delete)?;
+
+        let reservation_id = self.reserve()?;
+        self.ipmi
+            .send_recv(DeleteSdr::new(reservation_id, record_id))?;
+        Ok(())
+    }
+
+    /// Erase all records in the repository, waiting at most
+    /// `timeout` for the erase to complete.
+    pub fn clear(&mut self, timeout: Duration) -> Result<(), SdrWriteError<CON::Error>> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        self.require_writable()?;
+
+        let reservation_id = self.reserve()?;
+        let start = Instant::now();
+
+        let mut progress = self.ipmi.send_recv(ClearSdrRepository::new(
+            reservation_id,
+            ClearSdrAction::Initiate,
+        ))?;
+
+        while progress != SdrErasureProgress::Completed {
+            if start.elapsed() > timeout {
+                return Err(SdrWriteError::ClearTimedOut);
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+
+            progress = self.ipmi.send_recv(ClearSdrRepository::new(
+                reservation_id,
+                ClearSdrAction::GetStatus,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<CON> Drop for SdrWriter<'_, CON>
+where
+    CON: IpmiConnection,
+{
+    fn drop(&mut self) {
+        if self.in_update_mode {
+            // There is no way to report a failure from here.
+            let _ = self.ipmi.send_recv(ExitSdrRepositoryUpdateMode);
+        }
+    }
+}
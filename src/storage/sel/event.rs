@@ -0,0 +1,299 @@
+use crate::{
+    sensor_event::{GenericState, SensorSpecificState},
+    storage::sdr::{
+        event_reading_type_code::EventReadingTypeCodes,
+        record::{FullSensorRecord, SensorOwner, Value},
+        Record, SensorType,
+    },
+};
+
+use super::{Entry, EventDirection, EventGenerator};
+
+/// The contents of event data byte 2 of a system event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventData2 {
+    Unspecified,
+    /// The raw reading that triggered a threshold event.
+    TriggerReading(u8),
+    /// The previous state and/or severity of a discrete sensor.
+    PreviousState {
+        offset: Option<u8>,
+        severity: Option<u8>,
+    },
+    Oem(u8),
+    SensorSpecific(u8),
+}
+
+/// The contents of event data byte 3 of a system event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventData3 {
+    Unspecified,
+    /// The raw value of the threshold that triggered a threshold event.
+    TriggerThreshold(u8),
+    Oem(u8),
+    SensorSpecific(u8),
+}
+
+/// A decoded system event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemEvent {
+    pub sensor_type: SensorType,
+    pub sensor_number: u8,
+    pub event_type: EventReadingTypeCodes,
+    pub direction: EventDirection,
+    /// The event offset, which identifies the state that
+    /// was asserted or deasserted.
+    pub offset: u8,
+    pub data_2: EventData2,
+    pub data_3: EventData3,
+}
+
+impl SystemEvent {
+    /// Decode the event described by the fields of an [`Entry::System`].
+    pub fn new(
+        sensor_type: u8,
+        sensor_number: u8,
+        event_type: u8,
+        direction: EventDirection,
+        event_data: [u8; 3],
+    ) -> Self {
+        let event_type = EventReadingTypeCodes::from(event_type);
+        let [data_1, data_2, data_3] = event_data;
+        let is_threshold = event_type == EventReadingTypeCodes::Threshold;
+
+        let nibble = |v: u8| Some(v & 0xF).filter(|v| *v != 0xF);
+
+        let data_2 = match (data_1 >> 6) & 0x3 {
+            0b00 => EventData2::Unspecified,
+            0b01 if is_threshold => EventData2::TriggerReading(data_2),
+            0b01 => EventData2::PreviousState {
+                offset: nibble(data_2),
+                severity: nibble(data_2 >> 4),
+            },
+            0b10 => EventData2::Oem(data_2),
+            _ => EventData2::SensorSpecific(data_2),
+        };
+
+        let data_3 = match (data_1 >> 4) & 0x3 {
+            0b01 if is_threshold => EventData3::TriggerThreshold(data_3),
+            0b10 => EventData3::Oem(data_3),
+            0b11 => EventData3::SensorSpecific(data_3),
+            _ => EventData3::Unspecified,
+        };
+
+        Self {
+            sensor_type: sensor_type.into(),
+            sensor_number,
+            event_type,
+            direction,
+            offset: data_1 & 0xF,
+            data_2,
+            data_3,
+        }
+    }
+
+    /// The description of the state identified by the event offset, as
+    /// defined by the event/reading type code and the sensor type.
+    pub fn offset_description(&self) -> Option<&'static str> {
+        match self.event_type {
+            EventReadingTypeCodes::Threshold => THRESHOLD.get(self.offset as usize).copied(),
+            EventReadingTypeCodes::DiscreteGeneric(code) => {
+                GenericState::new(code, self.offset).map(|s| s.description())
+            }
+            EventReadingTypeCodes::SensorSpecific => {
+                SensorSpecificState::new(self.sensor_type, self.offset).map(|s| s.description())
+            }
+            _ => None,
+        }
+    }
+
+    /// The reading that triggered this threshold event, converted
+    /// to the units of the sensor described by `record`.
+    pub fn trigger_reading(&self, record: &FullSensorRecord) -> Option<Value> {
+        match self.data_2 {
+            EventData2::TriggerReading(raw) => record.convert(raw),
+            _ => None,
+        }
+    }
+
+    /// The threshold that triggered this threshold event, converted
+    /// to the units of the sensor described by `record`.
+    pub fn trigger_threshold(&self, record: &FullSensorRecord) -> Option<Value> {
+        match self.data_3 {
+            EventData3::TriggerThreshold(raw) => record.convert(raw),
+            _ => None,
+        }
+    }
+
+    /// Additional details that are encoded in event data 2 and 3.
+    ///
+    /// If `record` is the SDR of the sensor that generated the event,
+    /// the trigger reading and threshold of threshold events are
+    /// converted to the units of the sensor.
+    fn details(&self, record: Option<&Record>) -> Vec<String> {
+        let mut details = Vec::new();
+
+        let full = record.and_then(Record::full_sensor);
+        let value = |raw: u8, value: Option<Value>| {
+            value
+                .map(|v| v.display(true))
+                .unwrap_or_else(|| format!("0x{raw:02X}"))
+        };
+
+        if let EventData2::TriggerReading(raw) = self.data_2 {
+            let reading = full.and_then(|f| self.trigger_reading(f));
+            details.push(format!("reading {}", value(raw, reading)));
+        }
+
+        if let EventData3::TriggerThreshold(raw) = self.data_3 {
+            let threshold = full.and_then(|f| self.trigger_threshold(f));
+            details.push(format!("threshold {}", value(raw, threshold)));
+        }
+
+        match (self.sensor_type, self.event_type, self.data_3) {
+            (
+                SensorType::Memory,
+                EventReadingTypeCodes::SensorSpecific,
+                EventData3::SensorSpecific(module),
+            ) => details.push(format!("memory module {module}")),
+            (
+                SensorType::PowerSupply,
+                EventReadingTypeCodes::SensorSpecific,
+                EventData3::SensorSpecific(error),
+            ) if self.offset == 0x06 => {
+                let error = match error & 0xF {
+                    0x0 => "vendor mismatch",
+                    0x1 => "revision mismatch",
+                    0x2 => "processor missing",
+                    0x3 => "power supply rating mismatch",
+                    0x4 => "voltage rating mismatch",
+                    _ => "unknown error type",
+                };
+
+                details.push(error.to_string());
+            }
+            _ => {}
+        }
+
+        details
+    }
+
+    /// Describe this event.
+    ///
+    /// If `record` is the SDR of the sensor that generated the event,
+    /// the description includes the name of the sensor, and readings are
+    /// converted to the units of the sensor.
+    pub fn describe(&self, record: Option<&Record>) -> String {
+        let source = match record.and_then(Record::id) {
+            Some(id) => id.to_string(),
+            None => self.sensor_type.to_string(),
+        };
+
+        let description = match self.offset_description() {
+            Some(description) => description.to_string(),
+            None => format!(
+                "Event type 0x{:02X}, offset 0x{:X}",
+                u8::from(self.event_type),
+                self.offset
+            ),
+        };
+
+        let mut output = format!("{source}: {description}");
+
+        for detail in self.details(record) {
+            output.push_str(", ");
+            output.push_str(&detail);
+        }
+
+        if self.direction == EventDirection::Deassert {
+            output.push_str(" (deasserted)");
+        }
+
+        output
+    }
+}
+
+impl core::fmt::Display for SystemEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.describe(None))
+    }
+}
+
+impl Entry {
+    /// Decode the event of a system event entry.
+    pub fn system_event(&self) -> Option<SystemEvent> {
+        match self {
+            Entry::System {
+                sensor_type,
+                sensor_number,
+                event_direction,
+                event_type,
+                event_data,
+                ..
+            } => Some(SystemEvent::new(
+                *sensor_type,
+                *sensor_number,
+                *event_type,
+                *event_direction,
+                *event_data,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Find the SDR of the sensor that generated this system event
+    /// entry in `records`.
+    ///
+    /// Records are matched by the owner ID and LUN of the sensor, which
+    /// must equal the generator ID of the entry, and by sensor number. The
+    /// channel of the generator ID is ignored, as it is the channel that the
+    /// event message was received on.
+    pub fn sensor_record<'a>(
+        &self,
+        records: impl IntoIterator<Item = &'a Record>,
+    ) -> Option<&'a Record> {
+        let Entry::System {
+            generator_id,
+            sensor_number,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        records.into_iter().find(|record| {
+            let Some(key) = record.sensor_key() else {
+                return false;
+            };
+
+            let owner_matches = match *generator_id {
+                EventGenerator::RqSAAndLun { i2c_addr, lun, .. } => {
+                    key.owner_id == SensorOwner::I2C(i2c_addr) && key.owner_lun == lun
+                }
+                EventGenerator::SoftwareId { software_id, .. } => {
+                    key.owner_id == SensorOwner::System(software_id)
+                }
+            };
+
+            owner_matches
+                && record
+                    .sensor_numbers()
+                    .is_some_and(|numbers| numbers.contains(sensor_number))
+        })
+    }
+}
+
+const THRESHOLD: &[&str] = &[
+    "Lower Non-critical going low",
+    "Lower Non-critical going high",
+    "Lower Critical going low",
+    "Lower Critical going high",
+    "Lower Non-recoverable going low",
+    "Lower Non-recoverable going high",
+    "Upper Non-critical going low",
+    "Upper Non-critical going high",
+    "Upper Critical going low",
+    "Upper Critical going high",
+    "Upper Non-recoverable going low",
+    "Upper Non-recoverable going high",
+];
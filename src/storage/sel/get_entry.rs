@@ -66,6 +66,80 @@ impl IpmiCommand for GetEntry {
     }
 }
 
+/// Get part of a SEL entry.
+///
+/// This command is used to read SEL entries from BMCs that cannot return
+/// an entire entry in a single response. A valid reservation, obtained
+/// using [`ReserveSel`], is required.
+///
+/// [`ReserveSel`]: super::ReserveSel
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetEntryPartial {
+    inner: GetEntry,
+}
+
+impl GetEntryPartial {
+    pub fn new(
+        reservation_id: NonZeroU16,
+        record_id: RecordId,
+        offset: u8,
+        bytes_to_read: NonMaxU8,
+    ) -> Self {
+        Self {
+            inner: GetEntry {
+                reservation_id: Some(reservation_id),
+                record_id,
+                offset,
+                bytes_to_read: Some(bytes_to_read),
+            },
+        }
+    }
+
+    /// Read an entire entry without a reservation, without
+    /// parsing its contents.
+    pub(crate) fn whole_entry(record_id: RecordId) -> Self {
+        Self {
+            inner: GetEntry::new(None, record_id),
+        }
+    }
+}
+
+impl From<GetEntryPartial> for Message {
+    fn from(value: GetEntryPartial) -> Self {
+        value.inner.into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialEntryInfo {
+    pub next_entry: RecordId,
+    pub data: Vec<u8>,
+}
+
+impl IpmiCommand for GetEntryPartial {
+    type Output = PartialEntryInfo;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        let next_entry = RecordId::new_raw(u16::from_le_bytes([data[0], data[1]]));
+
+        Ok(PartialEntryInfo {
+            next_entry,
+            data: data[2..].to_vec(),
+        })
+    }
+}
+
 impl From<GetEntry> for Message {
     fn from(value: GetEntry) -> Self {
         let GetEntry {
@@ -27,14 +27,22 @@ impl From<GetInfo> for Message {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Command {
-    Clear,
+    Delete,
     PartialAddEntry,
     Reserve,
     GetAllocInfo,
 }
 
+impl Command {
+    /// Bit 3 of the operation support byte indicates support for
+    /// Delete SEL Entry, not for Clear SEL.
+    #[deprecated(note = "use `Command::Delete` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Clear: Self = Self::Delete;
+}
+
 #[derive(Debug, Clone)]
 pub struct Info {
     pub version_maj: u8,
@@ -66,7 +74,7 @@ impl Info {
         let mut supported_cmds = Vec::with_capacity(4);
 
         if data[13] & 0x08 == 0x08 {
-            supported_cmds.push(Command::Clear);
+            supported_cmds.push(Command::Delete);
         }
         if data[13] & 0x04 == 0x04 {
             supported_cmds.push(Command::PartialAddEntry);
@@ -83,14 +91,21 @@ impl Info {
             version_min,
             entries,
             bytes_free: free,
-            last_add_time: Timestamp(last_add_time),
-            last_del_time: Timestamp(last_del_time),
+            last_add_time: Timestamp::from(last_add_time),
+            last_del_time: Timestamp::from(last_del_time),
             overflow,
             supported_cmds,
         })
     }
 }
 
+impl Info {
+    /// Whether the SEL supports the optional command `command`.
+    pub fn supports(&self, command: Command) -> bool {
+        self.supported_cmds.contains(&command)
+    }
+}
+
 impl Loggable for Info {
     fn into_log(&self) -> Vec<crate::fmt::LogItem> {
         let (ver_maj, ver_min) = (self.version_maj, self.version_min);
@@ -100,7 +115,7 @@ impl Loggable for Info {
             .iter()
             .map(|cmd| match cmd {
                 Command::GetAllocInfo => "Get Alloc Info",
-                Command::Clear => "Clear",
+                Command::Delete => "Delete",
                 Command::PartialAddEntry => "Partial Add",
                 Command::Reserve => "Reserve",
             })
@@ -6,12 +6,40 @@ mod get_alloc_info;
 pub use get_alloc_info::{AllocInfo as SelAllocInfo, GetAllocInfo as GetSelAllocInfo};
 
 mod get_entry;
-pub use get_entry::{EntryInfo as SelEntryInfo, GetEntry as GetSelEntry};
+pub use get_entry::{
+    EntryInfo as SelEntryInfo, GetEntry as GetSelEntry, GetEntryPartial as GetSelEntryPartial,
+    PartialEntryInfo as PartialSelEntryInfo,
+};
+
+mod reserve;
+pub use reserve::Reserve as ReserveSel;
+
+mod modify;
+pub use modify::{
+    AddEntry as AddSelEntry, DeleteEntry as DeleteSelEntry, PartialAddEntry as PartialAddSelEntry,
+};
+
+mod clear;
+pub use clear::{
+    Clear as ClearSel, ClearAction as ClearSelAction, ErasureProgress as SelErasureProgress,
+};
+
+mod time;
+pub use time::{
+    GetTime as GetSelTime, GetUtcOffset as GetSelTimeUtcOffset, SetTime as SetSelTime,
+    SetUtcOffset as SetSelTimeUtcOffset, UtcOffset as SelUtcOffset,
+};
+
+mod writer;
+pub use writer::{SelWriteError, SelWriter};
+
+mod event;
+pub use event::{EventData2, EventData3, SystemEvent};
 
 mod get_info;
 pub use get_info::{Command as SelCommand, GetInfo as GetSelInfo, Info as SelInfo};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RecordId(u16);
 
 impl RecordId {
@@ -75,6 +103,22 @@ pub enum EventGenerator {
     },
 }
 
+impl From<EventGenerator> for (u8, u8) {
+    fn from(value: EventGenerator) -> Self {
+        match value {
+            EventGenerator::RqSAAndLun {
+                i2c_addr,
+                channel_number,
+                lun,
+            } => (i2c_addr << 1, channel_number << 4 | lun.value()),
+            EventGenerator::SoftwareId {
+                software_id,
+                channel_number,
+            } => (software_id << 1 | 0x1, channel_number << 4),
+        }
+    }
+}
+
 impl From<(u8, u8)> for EventGenerator {
     fn from(value: (u8, u8)) -> Self {
         let is_software_id = (value.0 & 0x1) == 0x1;
@@ -105,6 +149,16 @@ pub enum EventMessageRevision {
     Unknown(u8),
 }
 
+impl From<EventMessageRevision> for u8 {
+    fn from(value: EventMessageRevision) -> Self {
+        match value {
+            EventMessageRevision::V2_0 => 0x04,
+            EventMessageRevision::V1_0 => 0x03,
+            EventMessageRevision::Unknown(v) => v,
+        }
+    }
+}
+
 impl From<u8> for EventMessageRevision {
     fn from(value: u8) -> Self {
         match value {
@@ -155,8 +209,78 @@ pub enum ParseEntryError {
 }
 
 impl Entry {
+    /// The length of a SEL entry, in bytes.
+    pub const LEN: usize = 16;
+
+    /// The record ID of this entry.
+    pub fn record_id(&self) -> RecordId {
+        match self {
+            Entry::System { record_id, .. }
+            | Entry::OemTimestamped { record_id, .. }
+            | Entry::OemNotTimestamped { record_id, .. } => *record_id,
+        }
+    }
+
+    /// Get the binary representation of this entry.
+    ///
+    /// This is the inverse of [`Entry::parse`].
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut data = [0u8; 16];
+        data[0..2].copy_from_slice(&self.record_id().value().to_le_bytes());
+
+        match self {
+            Entry::System {
+                timestamp,
+                generator_id,
+                event_message_format,
+                sensor_type,
+                sensor_number,
+                event_direction,
+                event_type,
+                event_data,
+                ..
+            } => {
+                let (generator_0, generator_1) = (*generator_id).into();
+                let direction = match event_direction {
+                    EventDirection::Assert => 0x00,
+                    EventDirection::Deassert => 0x80,
+                };
+
+                data[2] = 0x02;
+                data[3..7].copy_from_slice(&u32::from(*timestamp).to_le_bytes());
+                data[7] = generator_0;
+                data[8] = generator_1;
+                data[9] = (*event_message_format).into();
+                data[10] = *sensor_type;
+                data[11] = *sensor_number;
+                data[12] = direction | (event_type & 0x7F);
+                data[13..16].copy_from_slice(event_data);
+            }
+            Entry::OemTimestamped {
+                ty,
+                timestamp,
+                manufacturer_id,
+                data: oem_data,
+                ..
+            } => {
+                data[2] = *ty;
+                data[3..7].copy_from_slice(&u32::from(*timestamp).to_le_bytes());
+                data[7..10].copy_from_slice(&manufacturer_id.to_le_bytes()[..3]);
+                data[10..16].copy_from_slice(oem_data);
+            }
+            Entry::OemNotTimestamped {
+                ty, data: oem_data, ..
+            } => {
+                data[2] = *ty;
+                data[3..16].copy_from_slice(oem_data);
+            }
+        }
+
+        data
+    }
+
     pub fn parse(data: &[u8]) -> Result<Self, ParseEntryError> {
-        if data.len() < 15 {
+        if data.len() < Self::LEN {
             return Err(ParseEntryError::NotEnoughData);
         }
 
@@ -171,9 +295,9 @@ impl Entry {
                 let sensor_type = data[10];
                 let sensor_number = data[11];
                 let event_direction = if (data[12] & 0x80) == 0x80 {
-                    EventDirection::Assert
-                } else {
                     EventDirection::Deassert
+                } else {
+                    EventDirection::Assert
                 };
                 let event_type = data[12] & 0x7F;
                 let event_data = [data[13], data[14], data[15]];
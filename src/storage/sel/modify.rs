@@ -0,0 +1,156 @@
+use std::num::NonZeroU16;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+use super::RecordId;
+
+fn parse_record_id<E>(data: &[u8]) -> Result<RecordId, ParseResponseError<E>> {
+    if data.len() < 2 {
+        return Err(ParseResponseError::NotEnoughData);
+    }
+
+    Ok(RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])))
+}
+
+/// Add an entry to the SEL.
+///
+/// The record ID in `entry` is ignored, and the ID assigned to the
+/// entry by the BMC is returned. Most BMCs also replace the timestamp
+/// of system event entries with the current SEL time.
+#[derive(Debug, Clone)]
+pub struct AddEntry {
+    entry: [u8; 16],
+}
+
+impl AddEntry {
+    pub fn new(entry: [u8; 16]) -> Self {
+        Self { entry }
+    }
+}
+
+impl From<AddEntry> for Message {
+    fn from(value: AddEntry) -> Self {
+        Message::new_request(NetFn::Storage, 0x44, value.entry.to_vec())
+    }
+}
+
+impl IpmiCommand for AddEntry {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
+
+/// Add part of an entry to the SEL.
+///
+/// Entries are added by sending their data in order, starting at offset 0.
+/// The first part must be sent with [`RecordId::FIRST`], and subsequent
+/// parts must use the record ID returned for the first part. The entry is
+/// only added to the SEL once the part with `last` set is sent.
+#[derive(Debug, Clone)]
+pub struct PartialAddEntry {
+    reservation_id: NonZeroU16,
+    record_id: RecordId,
+    offset: u8,
+    last: bool,
+    data: Vec<u8>,
+}
+
+impl PartialAddEntry {
+    pub fn new(
+        reservation_id: NonZeroU16,
+        record_id: RecordId,
+        offset: u8,
+        last: bool,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+            offset,
+            last,
+            data,
+        }
+    }
+}
+
+impl From<PartialAddEntry> for Message {
+    fn from(value: PartialAddEntry) -> Self {
+        let mut data = Vec::with_capacity(6 + value.data.len());
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+        data.push(value.offset);
+        data.push(if value.last { 0x01 } else { 0x00 });
+        data.extend_from_slice(&value.data);
+
+        Message::new_request(NetFn::Storage, 0x45, data)
+    }
+}
+
+impl IpmiCommand for PartialAddEntry {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
+
+/// Delete an entry from the SEL.
+///
+/// Returns the ID of the deleted entry.
+#[derive(Debug, Clone, Copy)]
+pub struct DeleteEntry {
+    reservation_id: NonZeroU16,
+    record_id: RecordId,
+}
+
+impl DeleteEntry {
+    pub fn new(reservation_id: NonZeroU16, record_id: RecordId) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+        }
+    }
+}
+
+impl From<DeleteEntry> for Message {
+    fn from(value: DeleteEntry) -> Self {
+        let mut data = Vec::with_capacity(4);
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+
+        Message::new_request(NetFn::Storage, 0x46, data)
+    }
+}
+
+impl IpmiCommand for DeleteEntry {
+    type Output = RecordId;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        parse_record_id(data)
+    }
+}
@@ -0,0 +1,37 @@
+use std::num::NonZeroU16;
+
+use crate::connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError};
+
+/// Reserve the SEL.
+///
+/// The returned reservation ID is required for partial reads of SEL
+/// entries, and for deleting entries or clearing the SEL. A reservation
+/// is cancelled when an entry is added to or deleted from the SEL, or
+/// when another reservation is made.
+#[derive(Debug, Clone, Copy)]
+pub struct Reserve;
+
+impl From<Reserve> for Message {
+    fn from(_: Reserve) -> Self {
+        Message::new_request(NetFn::Storage, 0x42, Vec::new())
+    }
+}
+
+impl IpmiCommand for Reserve {
+    type Output = NonZeroU16;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        NonZeroU16::new(u16::from_le_bytes([data[0], data[1]])).ok_or(ParseResponseError::Parse(()))
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    connection::{CompletionCode, IpmiCommand, Message, NetFn, ParseResponseError},
+    storage::Timestamp,
+};
+
+/// Get the current time of the SEL clock.
+#[derive(Debug, Clone, Copy)]
+pub struct GetTime;
+
+impl From<GetTime> for Message {
+    fn from(_: GetTime) -> Self {
+        Message::new_request(NetFn::Storage, 0x48, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetTime {
+    type Output = Timestamp;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 4 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        Ok(Timestamp::from(u32::from_le_bytes([
+            data[0], data[1], data[2], data[3],
+        ])))
+    }
+}
+
+/// Set the time of the SEL clock.
+#[derive(Debug, Clone, Copy)]
+pub struct SetTime {
+    time: Timestamp,
+}
+
+impl SetTime {
+    pub fn new(time: Timestamp) -> Self {
+        Self { time }
+    }
+}
+
+impl From<SetTime> for Message {
+    fn from(value: SetTime) -> Self {
+        let data = u32::from(value.time).to_le_bytes().to_vec();
+        Message::new_request(NetFn::Storage, 0x49, data)
+    }
+}
+
+impl IpmiCommand for SetTime {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)
+    }
+}
+
+/// The offset of the SEL clock from UTC, in minutes.
+///
+/// The SEL clock runs in local time, so that `local = UTC + offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UtcOffset {
+    Minutes(i16),
+    Unspecified,
+}
+
+impl UtcOffset {
+    const UNSPECIFIED: i16 = 0x07FF;
+    const MAX_MINUTES: i16 = 1440;
+
+    /// Create a new offset, which must be at most 24 hours.
+    pub fn new(minutes: i16) -> Option<Self> {
+        if (-Self::MAX_MINUTES..=Self::MAX_MINUTES).contains(&minutes) {
+            Some(Self::Minutes(minutes))
+        } else {
+            None
+        }
+    }
+
+    fn from_raw(value: i16) -> Option<Self> {
+        if value == Self::UNSPECIFIED {
+            Some(Self::Unspecified)
+        } else {
+            Self::new(value)
+        }
+    }
+
+    fn raw(&self) -> i16 {
+        match self {
+            UtcOffset::Minutes(minutes) => *minutes,
+            UtcOffset::Unspecified => Self::UNSPECIFIED,
+        }
+    }
+}
+
+/// Get the offset of the SEL clock from UTC.
+#[derive(Debug, Clone, Copy)]
+pub struct GetUtcOffset;
+
+impl From<GetUtcOffset> for Message {
+    fn from(_: GetUtcOffset) -> Self {
+        Message::new_request(NetFn::Storage, 0x5C, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetUtcOffset {
+    type Output = UtcOffset;
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)?;
+
+        if data.len() < 2 {
+            return Err(ParseResponseError::NotEnoughData);
+        }
+
+        UtcOffset::from_raw(i16::from_le_bytes([data[0], data[1]]))
+            .ok_or(ParseResponseError::Parse(()))
+    }
+}
+
+/// Set the offset of the SEL clock from UTC.
+#[derive(Debug, Clone, Copy)]
+pub struct SetUtcOffset {
+    offset: UtcOffset,
+}
+
+impl SetUtcOffset {
+    pub fn new(offset: UtcOffset) -> Self {
+        Self { offset }
+    }
+}
+
+impl From<SetUtcOffset> for Message {
+    fn from(value: SetUtcOffset) -> Self {
+        let data = value.offset.raw().to_le_bytes().to_vec();
+        Message::new_request(NetFn::Storage, 0x5D, data)
+    }
+}
+
+impl IpmiCommand for SetUtcOffset {
+    type Output = ();
+
+    type Error = ();
+
+    fn parse_response(
+        completion_code: CompletionCode,
+        _data: &[u8],
+    ) -> Result<Self::Output, ParseResponseError<Self::Error>> {
+        Self::check_cc_success(completion_code)
+    }
+}
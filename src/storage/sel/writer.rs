@@ -0,0 +1,156 @@
+use std::{
+    num::NonZeroU16,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    connection::{CompletionCode, IpmiConnection},
+    Ipmi, IpmiCommandError,
+};
+
+use super::{
+    AddSelEntry, ClearSel, ClearSelAction, DeleteSelEntry, GetSelInfo, PartialAddSelEntry,
+    RecordId, ReserveSel, SelCommand, SelErasureProgress, SelInfo,
+};
+
+#[derive(Debug)]
+pub enum SelWriteError<CON> {
+    /// The SEL does not support the command that is
+    /// required to perform the modification.
+    Unsupported(SelCommand),
+    /// Erasing the SEL did not complete in time.
+    ClearTimedOut,
+    Command(IpmiCommandError<CON, ()>),
+}
+
+impl<CON> From<IpmiCommandError<CON, ()>> for SelWriteError<CON> {
+    fn from(value: IpmiCommandError<CON, ()>) -> Self {
+        Self::Command(value)
+    }
+}
+
+/// Modify the SEL.
+///
+/// Every modification that requires an optional command is checked
+/// against the commands that the SEL reports as supported in its
+/// [`SelInfo`].
+pub struct SelWriter<'ipmi, CON> {
+    ipmi: &'ipmi mut Ipmi<CON>,
+    info: SelInfo,
+}
+
+impl<'ipmi, CON> SelWriter<'ipmi, CON>
+where
+    CON: IpmiConnection,
+{
+    /// The amount of entry data sent per Partial Add SEL Entry request.
+    const PARTIAL_ADD_SIZE: usize = 8;
+
+    pub fn new(ipmi: &'ipmi mut Ipmi<CON>) -> Result<Self, SelWriteError<CON::Error>> {
+        let info = ipmi.send_recv(GetSelInfo)?;
+
+        Ok(Self { ipmi, info })
+    }
+
+    /// The SEL information that modifications are checked against.
+    pub fn info(&self) -> &SelInfo {
+        &self.info
+    }
+
+    fn require(&self, command: SelCommand) -> Result<(), SelWriteError<CON::Error>> {
+        if self.info.supports(command) {
+            Ok(())
+        } else {
+            Err(SelWriteError::Unsupported(command))
+        }
+    }
+
+    fn reserve(&mut self) -> Result<NonZeroU16, SelWriteError<CON::Error>> {
+        self.require(SelCommand::Reserve)?;
+        Ok(self.ipmi.send_recv(ReserveSel)?)
+    }
+
+    /// Add an entry to the SEL.
+    ///
+    /// The entry is added using a single Add SEL Entry request if possible.
+    /// If the BMC cannot accept the entry in a single request, it is added
+    /// using Partial Add SEL Entry instead.
+    ///
+    /// Returns the ID that the BMC assigned to the entry.
+    pub fn add(&mut self, entry: [u8; 16]) -> Result<RecordId, SelWriteError<CON::Error>> {
+        match self.ipmi.send_recv(AddSelEntry::new(entry)) {
+            Ok(record_id) => Ok(record_id),
+            Err(e)
+                if matches!(
+                    e.completion_code(),
+                    Some(CompletionCode::RequestDataLenInvalid)
+                        | Some(CompletionCode::RequestDataLengthLimitExceeded)
+                ) && self.info.supports(SelCommand::PartialAddEntry) =>
+            {
+                self.partial_add(entry)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Add an entry to the SEL using Partial Add SEL Entry.
+    ///
+    /// Returns the ID that the BMC assigned to the entry.
+    pub fn partial_add(&mut self, entry: [u8; 16]) -> Result<RecordId, SelWriteError<CON::Error>> {
+        self.require(SelCommand::PartialAddEntry)?;
+
+        let reservation_id = self.reserve()?;
+        let mut record_id = RecordId::FIRST;
+
+        let chunks = entry.chunks(Self::PARTIAL_ADD_SIZE);
+        let chunk_count = chunks.len();
+
+        for (idx, chunk) in chunks.enumerate() {
+            let offset = (idx * Self::PARTIAL_ADD_SIZE) as u8;
+            let last = idx + 1 == chunk_count;
+
+            let request =
+                PartialAddSelEntry::new(reservation_id, record_id, offset, last, chunk.to_vec());
+            record_id = self.ipmi.send_recv(request)?;
+        }
+
+        Ok(record_id)
+    }
+
+    /// Delete an entry from the SEL.
+    pub fn delete(&mut self, record_id: RecordId) -> Result<(), SelWriteError<CON::Error>> {
+        self.require(SelCommand::Delete)?;
+
+        let reservation_id = self.reserve()?;
+        self.ipmi
+            .send_recv(DeleteSelEntry::new(reservation_id, record_id))?;
+        Ok(())
+    }
+
+    /// Erase all entries in the SEL, waiting at most
+    /// `timeout` for the erase to complete.
+    pub fn clear(&mut self, timeout: Duration) -> Result<(), SelWriteError<CON::Error>> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        let reservation_id = self.reserve()?;
+        let start = Instant::now();
+
+        let mut progress = self
+            .ipmi
+            .send_recv(ClearSel::new(reservation_id, ClearSelAction::Initiate))?;
+
+        while progress != SelErasureProgress::Completed {
+            if start.elapsed() > timeout {
+                return Err(SelWriteError::ClearTimedOut);
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+
+            progress = self
+                .ipmi
+                .send_recv(ClearSel::new(reservation_id, ClearSelAction::GetStatus))?;
+        }
+
+        Ok(())
+    }
+}
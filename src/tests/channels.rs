@@ -0,0 +1,106 @@
+use crate::{
+    app::{
+        auth::{Channel, PrivilegeLevel},
+        channel::{
+            AccessMode, ChannelAccess, ChannelAccessStorage, ChannelMedium, ChannelProtocol,
+            GetChannelAccess, SessionSupport, SetChannelAccess,
+        },
+    },
+    connection::NetFn,
+};
+
+use super::mock::MockConnection;
+
+#[test]
+fn enumerate_channels() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x42));
+
+        let channel = request.data()[0];
+        let (medium, protocol, session) = match channel {
+            // IPMB
+            0x00 => (0x01, 0x01, 0x00),
+            // 802.3 LAN, multi-session
+            0x03 => (0x04, 0x01, 0x80 | 0x02),
+            // OEM medium and protocol
+            0x05 => (0x60, 0x1C, 0x00),
+            _ => return (0xCC, Vec::new()),
+        };
+
+        (
+            0x00,
+            vec![
+                channel, medium, protocol, session, 0xF2, 0x1B, 0x00, 0x00, 0x00,
+            ],
+        )
+    });
+
+    let channels = ipmi.channels().unwrap();
+    assert_eq!(3, channels.len());
+
+    assert_eq!(0, channels[0].number);
+    assert_eq!(ChannelMedium::Ipmb, channels[0].medium);
+    assert_eq!(SessionSupport::SessionLess, channels[0].session_support);
+
+    let lan = channels.iter().find(|c| c.medium.is_lan()).unwrap();
+    assert_eq!(3, lan.number);
+    assert_eq!(ChannelProtocol::IpmbV10, lan.protocol);
+    assert_eq!(SessionSupport::MultiSession, lan.session_support);
+    assert_eq!(2, lan.active_sessions);
+    assert_eq!(7154, lan.vendor_id);
+
+    assert_eq!(ChannelMedium::Oem(0x60), channels[2].medium);
+    assert_eq!(ChannelProtocol::Oem(0x1C), channels[2].protocol);
+}
+
+#[test]
+fn channel_access() {
+    let mut sent = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(request.netfn(), NetFn::App);
+        sent.push((request.cmd(), request.data().to_vec()));
+
+        match request.cmd() {
+            0x41 => (0x00, vec![0x22, 0x04]),
+            0x40 => (0x00, Vec::new()),
+            _ => unreachable!(),
+        }
+    });
+
+    let access = ipmi
+        .send_recv(GetChannelAccess::new(
+            Channel::Number(1),
+            ChannelAccessStorage::NonVolatile,
+        ))
+        .unwrap();
+
+    assert_eq!(
+        ChannelAccess {
+            alerting_enabled: false,
+            per_message_authentication_enabled: true,
+            user_level_authentication_enabled: true,
+            access_mode: AccessMode::AlwaysAvailable,
+            privilege_limit: Some(PrivilegeLevel::Administrator),
+        },
+        access
+    );
+
+    let access = ChannelAccess {
+        alerting_enabled: true,
+        privilege_limit: Some(PrivilegeLevel::Operator),
+        ..access
+    };
+
+    ipmi.send_recv(SetChannelAccess::new(
+        Channel::Number(1),
+        ChannelAccessStorage::Volatile,
+        access,
+    ))
+    .unwrap();
+    drop(ipmi);
+
+    assert_eq!(
+        vec![(0x41, vec![0x01, 0x40]), (0x40, vec![0x01, 0x82, 0x83])],
+        sent
+    );
+}
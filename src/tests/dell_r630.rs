@@ -2,7 +2,7 @@ use crate::sensor_event::{RawSensorReading, ThresholdReading};
 use crate::storage::sdr::record::SensorId;
 use crate::storage::sdr::Record;
 
-const FAN_2A_SDR: [u8; 55] = [
+pub(super) const FAN_2A_SDR: [u8; 55] = [
     0x0E, 0x00, 0x0D, 0x00, 0x51, 0x01, 0x30, 0x20, 0x00, 0x32, 0x07, 0x01, 0x7F, 0xD4, 0x04, 0x01,
     0x05, 0x30, 0x05, 0x00, 0x03, 0x00, 0x00, 0x12, 0x00, 0x00, 0x78, 0x02, 0x00, 0x02, 0x30, 0x00,
     0x07, 0x54, 0xC5, 0x8B, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x05, 0x07, 0x01, 0x01, 0x00, 0x00,
@@ -28,7 +28,7 @@ fn test_decode_fan_rpm() {
     );
 }
 
-const INLET_TEMP_SDR: [u8; 60] = [
+pub(super) const INLET_TEMP_SDR: [u8; 60] = [
     0x13, 0x00, 0x12, 0x00, 0x51, 0x01, 0x35, 0x20, 0x00, 0x04, 0x07, 0x01, 0x7F, 0x68, 0x01, 0x01,
     0x85, 0x32, 0x85, 0x32, 0x1B, 0x09, 0x00, 0x01, 0x00, 0x00, 0x01, 0x02, 0x80, 0xC2, 0x30, 0x00,
     0x07, 0x97, 0xC5, 0x8B, 0xFF, 0x00, 0xFF, 0xAF, 0xAA, 0x00, 0x79, 0x83, 0x01, 0x01, 0x00, 0x00,
@@ -53,3 +53,39 @@ fn test_decode_inlet_temp() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_round_trip() {
+    for data in [&FAN_2A_SDR[2..], &INLET_TEMP_SDR[2..]] {
+        let record = Record::parse(data).unwrap();
+        assert_eq!(record.to_bytes().unwrap(), data);
+    }
+}
+
+#[test]
+fn test_round_trip_keeps_reserved_bits() {
+    let mut data = FAN_2A_SDR[2..].to_vec();
+    // A reserved bit in the lower threshold reading mask
+    data[15] |= 0x80;
+    // The reserved bit of the linearization byte
+    data[23] |= 0x80;
+    // The two reserved bytes
+    data[44] = 0x12;
+    data[45] = 0x34;
+    // The reserved bit of the ID string type/length byte
+    data[47] |= 0x20;
+
+    let record = Record::parse(&data).unwrap();
+    assert_eq!(record.to_bytes().unwrap(), data);
+
+    let raw_reading = RawSensorReading::parse(&FAN_2A_READING).unwrap();
+    let reading: ThresholdReading = (&raw_reading).into();
+    assert_eq!(
+        "5280.00 rpm",
+        record
+            .full_sensor()
+            .unwrap()
+            .display_reading(reading.reading.unwrap())
+            .unwrap()
+    );
+}
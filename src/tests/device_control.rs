@@ -0,0 +1,98 @@
+use crate::{
+    app::{
+        AcpiPowerState, ColdReset, DevicePowerState, GetAcpiPowerState, GetSelfTestResults,
+        SelfTestFailures, SelfTestResult, SetAcpiPowerState, SystemPowerState, WarmReset,
+    },
+    connection::NetFn,
+};
+
+use super::mock::MockConnection;
+
+#[test]
+fn resets() {
+    let mut commands = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(NetFn::App, request.netfn());
+        assert!(request.data().is_empty());
+        commands.push(request.cmd());
+        (0x00, Vec::new())
+    });
+
+    ipmi.send_recv(ColdReset).unwrap();
+    ipmi.send_recv(WarmReset).unwrap();
+    drop(ipmi);
+
+    assert_eq!(vec![0x02, 0x03], commands);
+}
+
+#[test]
+fn self_test_results() {
+    let responses = [
+        ([0x55, 0x00], SelfTestResult::Passed),
+        ([0x56, 0x00], SelfTestResult::NotImplemented),
+        (
+            [0x57, 0b1000_1001],
+            SelfTestResult::Failed(
+                SelfTestFailures::SEL_INACCESSIBLE
+                    | SelfTestFailures::SDR_REPOSITORY_EMPTY
+                    | SelfTestFailures::FIRMWARE_CORRUPTED,
+            ),
+        ),
+        ([0x58, 0x12], SelfTestResult::FatalHardwareError(0x12)),
+        (
+            [0x81, 0x03],
+            SelfTestResult::DeviceSpecific {
+                code: 0x81,
+                data: 0x03,
+            },
+        ),
+    ];
+
+    for (response, expected) in responses {
+        let mut ipmi = MockConnection::ipmi(|request| {
+            assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x04));
+            (0x00, response.to_vec())
+        });
+
+        let result = ipmi.send_recv(GetSelfTestResults).unwrap();
+        assert_eq!(expected, result);
+        assert_eq!(expected == SelfTestResult::Passed, result.passed());
+    }
+}
+
+#[test]
+fn acpi_power_state() {
+    let mut sent = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(NetFn::App, request.netfn());
+
+        match request.cmd() {
+            0x06 => {
+                sent.push(request.data().to_vec());
+                (0x00, Vec::new())
+            }
+            0x07 => (0x00, vec![0x05, 0x2A]),
+            cmd => panic!("Unexpected command {cmd:02X}"),
+        }
+    });
+
+    ipmi.send_recv(SetAcpiPowerState::new(
+        Some(SystemPowerState::S0),
+        Some(DevicePowerState::D3),
+    ))
+    .unwrap();
+    ipmi.send_recv(SetAcpiPowerState::new(Some(SystemPowerState::S5), None))
+        .unwrap();
+
+    let state = ipmi.send_recv(GetAcpiPowerState).unwrap();
+    drop(ipmi);
+
+    assert_eq!(
+        AcpiPowerState {
+            system: SystemPowerState::S5,
+            device: DevicePowerState::Unknown,
+        },
+        state
+    );
+    assert_eq!(vec![vec![0x80, 0x83], vec![0x85, 0x7F]], sent);
+}
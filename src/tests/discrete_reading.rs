@@ -0,0 +1,109 @@
+use crate::{
+    sensor_event::{
+        discrete::{
+            generic::{PredictiveFailureState, SeverityState},
+            sensor_specific::{DriveSlotState, PowerSupplyState, ProcessorState},
+        },
+        DiscreteReading, DiscreteState, DiscreteStates, FromSensorReading, GenericState,
+        RawSensorReading, SensorSpecificState, ThresholdReading,
+    },
+    storage::sdr::{event_reading_type_code::PredictiveFailure, SensorType},
+};
+
+#[test]
+fn power_supply() {
+    // Presence detected, failure detected and AC lost
+    let raw = RawSensorReading::parse(&[0x00, 0xC0, 0x0B, 0x80]).unwrap();
+    let reading = DiscreteReading::from(&raw);
+
+    assert_eq!(reading.asserted, Some(0x000B));
+
+    let states = reading.states::<PowerSupplyState>().unwrap();
+    assert!(states.contains(PowerSupplyState::AcLost));
+    assert!(!states.contains(PowerSupplyState::PredictiveFailure));
+    assert_eq!(
+        states.iter().collect::<Vec<_>>(),
+        [
+            PowerSupplyState::PresenceDetected,
+            PowerSupplyState::FailureDetected,
+            PowerSupplyState::AcLost
+        ]
+    );
+
+    let states = reading
+        .sensor_specific_states(SensorType::PowerSupply)
+        .unwrap();
+    assert_eq!(states.len(), 3);
+    assert_eq!(
+        states[2],
+        SensorSpecificState::PowerSupply(PowerSupplyState::AcLost)
+    );
+    assert_eq!(states[2].to_string(), "AC lost");
+}
+
+#[test]
+fn high_offsets() {
+    // Processor thermal trip and correctable machine check error (offset 12)
+    let raw = RawSensorReading::parse(&[0x00, 0xC0, 0x02, 0x10]).unwrap();
+    let reading = DiscreteReading::from(&raw);
+
+    let states: Vec<_> = reading.states::<ProcessorState>().unwrap().iter().collect();
+    assert_eq!(
+        states,
+        [
+            ProcessorState::ThermalTrip,
+            ProcessorState::CorrectableMachineCheck
+        ]
+    );
+    assert_eq!(ProcessorState::CorrectableMachineCheck.offset(), 0x0C);
+
+    // Offsets without a state are skipped
+    let states = reading.states::<DriveSlotState>().unwrap();
+    assert_eq!(
+        states.iter().collect::<Vec<_>>(),
+        [DriveSlotState::DriveFault]
+    );
+}
+
+#[test]
+fn generic() {
+    let raw = RawSensorReading::parse(&[0x00, 0xC0, 0x02]).unwrap();
+
+    let states = <DiscreteStates<PredictiveFailureState> as FromSensorReading>::from(
+        &PredictiveFailure::new(0),
+        &raw,
+    );
+    assert!(states.contains(PredictiveFailureState::Asserted));
+    assert!(!states.contains(PredictiveFailureState::Deasserted));
+
+    let reading = DiscreteReading::from(&raw);
+    assert_eq!(
+        reading.generic_states(0x07).unwrap(),
+        [GenericState::Severity(SeverityState::NonCriticalFromOk)]
+    );
+    assert_eq!(GenericState::new(0x07, 0x09), None);
+    assert_eq!(GenericState::new(0x6F, 0x00), None);
+}
+
+#[test]
+fn unavailable() {
+    let raw = RawSensorReading::parse(&[0x00, 0xE0, 0x0B, 0x80]).unwrap();
+    let reading = DiscreteReading::from(&raw);
+
+    assert_eq!(reading.asserted, None);
+    assert!(reading.states::<PowerSupplyState>().is_none());
+    assert!(reading
+        .sensor_specific_states(SensorType::PowerSupply)
+        .is_none());
+}
+
+#[test]
+fn threshold_status() {
+    let raw = RawSensorReading::parse(&[0x10, 0xC0, 0x02]).unwrap();
+    let status = <ThresholdReading as From<_>>::from(&raw)
+        .threshold_status
+        .unwrap();
+
+    assert!(status.at_or_below_lower_critical);
+    assert!(!status.at_or_above_non_recoverable);
+}
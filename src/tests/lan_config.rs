@@ -0,0 +1,83 @@
+use std::net::Ipv4Addr;
+
+use crate::{
+    app::auth::Channel,
+    connection::NetFn,
+    transport::lan::{
+        GetLanConfigurationParameter, IpAddressSource, LanParameter, LanParameterSelector,
+        SetLanConfigurationParameter,
+    },
+};
+
+use super::mock::MockConnection;
+
+#[test]
+fn provision_static_address() {
+    let mut sent = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::Transport, 0x01));
+        sent.push(request.data().to_vec());
+        (0x00, Vec::new())
+    });
+
+    let channel = Channel::Number(1);
+    let parameters = [
+        LanParameter::IpAddressSource(IpAddressSource::Static),
+        LanParameter::IpAddress(Ipv4Addr::new(10, 0, 0, 20)),
+        LanParameter::SubnetMask(Ipv4Addr::new(255, 255, 255, 0)),
+        LanParameter::DefaultGateway(Ipv4Addr::new(10, 0, 0, 1)),
+    ];
+
+    for parameter in parameters {
+        ipmi.send_recv(SetLanConfigurationParameter::new(channel, parameter))
+            .unwrap();
+    }
+    drop(ipmi);
+
+    assert_eq!(
+        vec![
+            vec![0x01, 0x04, 0x01],
+            vec![0x01, 0x03, 10, 0, 0, 20],
+            vec![0x01, 0x06, 255, 255, 255, 0],
+            vec![0x01, 0x0C, 10, 0, 0, 1],
+        ],
+        sent
+    );
+}
+
+#[test]
+fn get_mac_address() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::Transport, 0x02));
+        assert_eq!(&[0x0E, 0x05, 0x00, 0x00], request.data());
+        (0x00, vec![0x11, 0x00, 0x1B, 0x21, 0x3C, 0x4D, 0x5E])
+    });
+
+    let request =
+        GetLanConfigurationParameter::new(Channel::Current, LanParameterSelector::MacAddress, 0);
+    let selector = request.selector();
+    let raw = ipmi.send_recv(request).unwrap();
+
+    assert_eq!(0x11, raw.revision);
+    assert_eq!(
+        Some(LanParameter::MacAddress([
+            0x00, 0x1B, 0x21, 0x3C, 0x4D, 0x5E
+        ])),
+        raw.parse(selector)
+    );
+}
+
+#[test]
+fn short_response() {
+    let mut ipmi = MockConnection::ipmi(|_| (0x00, vec![0x11, 10, 0, 0]));
+
+    let raw = ipmi
+        .send_recv(GetLanConfigurationParameter::new(
+            Channel::Current,
+            LanParameterSelector::IpAddress,
+            0,
+        ))
+        .unwrap();
+
+    assert_eq!(None, raw.parse(LanParameterSelector::IpAddress));
+}
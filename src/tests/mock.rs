@@ -0,0 +1,52 @@
+use crate::{
+    connection::{IpmiConnection, Message, Request, Response},
+    Ipmi,
+};
+
+/// A connection that answers requests using a handler function, which
+/// returns the completion code and data of the response.
+pub struct MockConnection<F> {
+    handler: F,
+    response: Option<Response>,
+}
+
+impl<F> MockConnection<F>
+where
+    F: FnMut(&Request) -> (u8, Vec<u8>),
+{
+    pub fn ipmi(handler: F) -> Ipmi<Self> {
+        Ipmi::new(Self {
+            handler,
+            response: None,
+        })
+    }
+}
+
+impl<F> IpmiConnection for MockConnection<F>
+where
+    F: FnMut(&Request) -> (u8, Vec<u8>),
+{
+    type SendError = ();
+    type RecvError = ();
+    type Error = ();
+
+    fn send(&mut self, request: &mut Request) -> Result<(), Self::SendError> {
+        let (cc, data) = (self.handler)(request);
+
+        let mut response_data = vec![cc];
+        response_data.extend(data);
+
+        let message = Message::new_response(request.netfn(), request.cmd(), response_data);
+        self.response = Response::new(message, 0);
+        Ok(())
+    }
+
+    fn recv(&mut self) -> Result<Response, Self::RecvError> {
+        self.response.take().ok_or(())
+    }
+
+    fn send_recv(&mut self, request: &mut Request) -> Result<Response, Self::Error> {
+        self.send(request)?;
+        self.recv()
+    }
+}
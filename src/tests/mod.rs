@@ -1 +1,20 @@
+mod channels;
 mod dell_r630;
+mod device_control;
+mod discrete_reading;
+mod lan_config;
+mod mock;
+mod pef;
+mod platform_event;
+mod sdr_cache;
+mod sdr_iter;
+mod sdr_writer;
+mod sel_event;
+mod sel_iter;
+mod sel_writer;
+mod sensor_events;
+mod sensor_reading_factors;
+mod sensor_thresholds;
+mod sol;
+mod user_accounts;
+mod watchdog;
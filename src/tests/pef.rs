@@ -0,0 +1,168 @@
+use crate::{
+    connection::NetFn,
+    sensor_event::pef::{
+        AlertStringBlock, ArmPefPostponeTimer, EventFilter, EventSeverity, GetLastProcessedEventId,
+        GetPefCapabilities, GetPefConfigurationParameter, PefActions, PefParameter,
+        PefParameterSelector, PostponeTimer, ProcessedBy, SetLastProcessedEventId,
+        SetPefConfigurationParameter,
+    },
+    storage::{sel::RecordId, Timestamp},
+};
+
+use super::mock::MockConnection;
+
+#[test]
+fn capabilities() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::SensorEvent, 0x10));
+        (0x00, vec![0x51, 0x0F, 0x28])
+    });
+
+    let capabilities = ipmi.send_recv(GetPefCapabilities).unwrap();
+
+    assert_eq!(
+        (1, 5),
+        (capabilities.major_version, capabilities.minor_version)
+    );
+    assert!(!capabilities.oem_event_record_filtering);
+    assert_eq!(
+        PefActions::ALERT | PefActions::POWER_DOWN | PefActions::RESET | PefActions::POWER_CYCLE,
+        capabilities.actions
+    );
+    assert_eq!(40, capabilities.event_filter_entries);
+}
+
+#[test]
+fn postpone_timer() {
+    let mut sent = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::SensorEvent, 0x11));
+        sent.push(request.data().to_vec());
+        (0x00, vec![0x3C])
+    });
+
+    let timer = ipmi
+        .send_recv(ArmPefPostponeTimer::new(PostponeTimer::Armed(60)))
+        .unwrap();
+    assert_eq!(PostponeTimer::Armed(60), timer);
+
+    ipmi.send_recv(ArmPefPostponeTimer::new(PostponeTimer::TemporaryDisable))
+        .unwrap();
+    ipmi.send_recv(ArmPefPostponeTimer::get()).unwrap();
+    drop(ipmi);
+
+    assert_eq!(vec![vec![0x3C], vec![0xFE], vec![0xFF]], sent);
+}
+
+#[test]
+fn event_filter_round_trip() {
+    let filter = EventFilter {
+        enabled: true,
+        actions: PefActions::ALERT,
+        alert_policy: 1,
+        severity: EventSeverity::Critical,
+        sensor_type: Some(0x01),
+        ..EventFilter::new(3)
+    };
+
+    let mut stored = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(request.netfn(), NetFn::SensorEvent);
+
+        match request.cmd() {
+            0x12 => {
+                assert_eq!(0x06, request.data()[0]);
+                stored = request.data()[1..].to_vec();
+                (0x00, Vec::new())
+            }
+            0x13 => {
+                assert_eq!(&[0x06, 0x03, 0x00], request.data());
+                let mut response = vec![0x11];
+                response.extend_from_slice(&stored);
+                (0x00, response)
+            }
+            _ => unreachable!(),
+        }
+    });
+
+    ipmi.send_recv(SetPefConfigurationParameter::new(
+        PefParameter::EventFilter(filter),
+    ))
+    .unwrap();
+
+    let request = GetPefConfigurationParameter::event_filter(3);
+    let selector = request.selector();
+    let raw = ipmi.send_recv(request).unwrap();
+
+    assert_eq!(0x11, raw.revision);
+    assert_eq!(Some(PefParameter::EventFilter(filter)), raw.parse(selector));
+}
+
+#[test]
+fn alert_string_blocks() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::SensorEvent, 0x13));
+        let block = request.data()[2];
+
+        let data: &[u8] = match block {
+            1 => b"Fan failure in c",
+            2 => b"hassis\0",
+            _ => unreachable!(),
+        };
+
+        let mut response = vec![0x11, 0x01, block];
+        response.extend_from_slice(data);
+        (0x00, response)
+    });
+
+    let blocks: Vec<_> = (1..=2)
+        .map(|block| {
+            let raw = ipmi
+                .send_recv(GetPefConfigurationParameter::alert_string(1, block))
+                .unwrap();
+
+            match raw.parse(PefParameterSelector::AlertString) {
+                Some(PefParameter::AlertString(block)) => block,
+                v => panic!("Unexpected parameter {v:?}"),
+            }
+        })
+        .collect();
+
+    assert_eq!("Fan failure in chassis", AlertStringBlock::concat(&blocks));
+}
+
+#[test]
+fn last_processed_event_id() {
+    let mut sent = None;
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(request.netfn(), NetFn::SensorEvent);
+
+        match request.cmd() {
+            0x14 => {
+                sent = Some(request.data().to_vec());
+                (0x00, Vec::new())
+            }
+            0x15 => (
+                0x00,
+                vec![0x00, 0x00, 0x00, 0x60, 0x34, 0x12, 0x30, 0x12, 0x00, 0x00],
+            ),
+            _ => unreachable!(),
+        }
+    });
+
+    let ids = ipmi.send_recv(GetLastProcessedEventId).unwrap();
+
+    assert_eq!(Timestamp::Absolute(0x6000_0000), ids.most_recent_addition);
+    assert_eq!(Some(0x1234), ids.last_record.map(|v| v.value()));
+    assert_eq!(0x1230, ids.software_processed.value());
+    assert_eq!(None, ids.bmc_processed);
+
+    ipmi.send_recv(SetLastProcessedEventId::new(
+        ProcessedBy::Software,
+        RecordId::new(0x1234).unwrap(),
+    ))
+    .unwrap();
+    drop(ipmi);
+
+    assert_eq!(Some(vec![0x00, 0x34, 0x12]), sent);
+}
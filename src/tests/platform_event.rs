@@ -0,0 +1,69 @@
+use crate::{
+    connection::{Message, NetFn},
+    sensor_event::PlatformEvent,
+    storage::sel::{Entry, EventDirection, EventGenerator, EventMessageRevision},
+};
+
+use super::{mock::MockConnection, sel_iter::system_entry};
+
+#[test]
+fn replay_entry() {
+    let mut data = system_entry(0x0001);
+    // Software ID 0x20, deasserted memory event
+    data[7] = 0x41;
+    data[10] = 0x0C;
+    data[12] = 0x80 | 0x6F;
+    let entry = Entry::parse(&data).unwrap();
+
+    let event = PlatformEvent::from_entry(&entry).unwrap();
+    assert_eq!(
+        event.generator_id,
+        Some(EventGenerator::SoftwareId {
+            software_id: 0x20,
+            channel_number: 0,
+        })
+    );
+    assert_eq!(event.event_direction, EventDirection::Deassert);
+
+    let mut sent = None;
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::SensorEvent, 0x02));
+        sent = Some(request.data().to_vec());
+        (0x00, Vec::new())
+    });
+
+    ipmi.send_recv(event).unwrap();
+    drop(ipmi);
+
+    // Generator ID, EvMRev, sensor type, sensor number, direction
+    // and event type, and the event data of the entry.
+    assert_eq!(
+        sent.unwrap(),
+        [0x41, 0x04, 0x0C, 0x04, 0xEF, 0x59, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn without_generator_id() {
+    let event = PlatformEvent {
+        generator_id: None,
+        event_message_format: EventMessageRevision::V2_0,
+        sensor_type: 0x01,
+        sensor_number: 0x04,
+        event_direction: EventDirection::Assert,
+        event_type: 0x01,
+        event_data: [0x59, 0xB0, 0xAA],
+    };
+
+    let message: Message = event.into();
+    assert_eq!(message.data(), &[0x04, 0x01, 0x04, 0x01, 0x59, 0xB0, 0xAA]);
+}
+
+#[test]
+fn no_entry() {
+    let mut data = system_entry(0x0001);
+    data[2] = 0xE0;
+    let entry = Entry::parse(&data).unwrap();
+
+    assert!(PlatformEvent::from_entry(&entry).is_none());
+}
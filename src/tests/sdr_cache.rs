@@ -0,0 +1,190 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use crate::{
+    connection::{IpmiConnection, NetFn},
+    storage::sdr::{SdrCache, SdrCacheError, SdrDump},
+    Ipmi,
+};
+
+use super::{
+    dell_r630::{FAN_2A_SDR, INLET_TEMP_SDR},
+    mock::MockConnection,
+    sdr_iter::Repository,
+};
+
+struct Bmc {
+    repository: Repository,
+    most_recent_addition: u32,
+    sdr_reads: usize,
+    device_guid: Option<[u8; 16]>,
+    system_guid: Option<[u8; 16]>,
+}
+
+impl Bmc {
+    fn new(device_guid: Option<[u8; 16]>) -> Self {
+        Self {
+            repository: Repository::new(0xFF),
+            most_recent_addition: 0x1000,
+            sdr_reads: 0,
+            device_guid,
+            system_guid: None,
+        }
+    }
+
+    fn ipmi(bmc: &Rc<RefCell<Bmc>>) -> Ipmi<impl IpmiConnection> {
+        let bmc = bmc.clone();
+        MockConnection::ipmi(move |request| {
+            bmc.borrow_mut()
+                .handle(request.netfn(), request.cmd(), request.data())
+        })
+    }
+}
+
+impl Bmc {
+    fn handle(&mut self, netfn: NetFn, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        match (netfn, cmd) {
+            (NetFn::App, 0x01) => (
+                0x00,
+                vec![
+                    0x20, 0x01, 0x02, 0x03, 0x02, 0xDF, 0xA2, 0x02, 0x00, 0x00, 0x01,
+                ],
+            ),
+            (NetFn::App, 0x08) => match self.device_guid {
+                Some(guid) => (0x00, guid.to_vec()),
+                None => (0xC1, Vec::new()),
+            },
+            (NetFn::App, 0x37) => match self.system_guid {
+                Some(guid) => (0x00, guid.to_vec()),
+                None => (0xC1, Vec::new()),
+            },
+            (NetFn::Storage, 0x20) => {
+                let mut info = vec![0x51];
+                info.extend_from_slice(&(self.repository.records.len() as u16).to_le_bytes());
+                info.extend_from_slice(&[0xFF, 0xFF]);
+                info.extend_from_slice(&self.most_recent_addition.to_le_bytes());
+                info.extend_from_slice(&0u32.to_le_bytes());
+                info.push(0x02);
+                (0x00, info)
+            }
+            (NetFn::Storage, 0x23) => {
+                self.sdr_reads += 1;
+                self.repository.handle(netfn, cmd, data)
+            }
+            _ => self.repository.handle(netfn, cmd, data),
+        }
+    }
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ipmi-rs-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn dump_round_trip() {
+    let mut data = FAN_2A_SDR[2..].to_vec();
+    data.extend_from_slice(&INLET_TEMP_SDR[2..]);
+
+    let dump = SdrDump::from_bytes(&data).unwrap();
+    assert_eq!(dump.len(), 2);
+    assert_eq!(dump.to_bytes(), data);
+
+    let ids: Vec<_> = dump
+        .records()
+        .map(|r| r.unwrap().header.id.value())
+        .collect();
+    assert_eq!(ids, vec![0x000D, 0x0012]);
+
+    assert!(SdrDump::from_bytes(&data[..data.len() - 1]).is_none());
+}
+
+#[test]
+fn cache_is_reused_until_repository_changes() {
+    let dir = cache_dir("sdr-cache");
+    let cache = SdrCache::new(&dir);
+
+    let bmc = Rc::new(RefCell::new(Bmc::new(Some([0x11; 16]))));
+    let mut ipmi = Bmc::ipmi(&bmc);
+
+    let expected: Vec<u8> = [&FAN_2A_SDR[2..], &INLET_TEMP_SDR[2..]].concat();
+
+    // The first load reads all records from the BMC.
+    let dump = cache.load(&mut ipmi).unwrap();
+    assert_eq!(dump.to_bytes(), expected);
+    assert_eq!(bmc.borrow().sdr_reads, 2);
+
+    // The second load uses the cached dump.
+    let dump = cache.load(&mut ipmi).unwrap();
+    assert_eq!(dump.to_bytes(), expected);
+    assert_eq!(bmc.borrow().sdr_reads, 2);
+
+    // A change to the repository invalidates the cached dump.
+    bmc.borrow_mut().most_recent_addition = 0x2000;
+    let dump = cache.load(&mut ipmi).unwrap();
+    assert_eq!(dump.to_bytes(), expected);
+    assert_eq!(bmc.borrow().sdr_reads, 4);
+
+    // Only the most recent dump is kept.
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn cache_is_kept_per_bmc() {
+    let dir = cache_dir("sdr-cache-per-bmc");
+    let cache = SdrCache::new(&dir);
+
+    // Two BMCs of the same model share the cache directory.
+    let first = Rc::new(RefCell::new(Bmc::new(Some([0x11; 16]))));
+    let second = Rc::new(RefCell::new(Bmc::new(None)));
+    second.borrow_mut().system_guid = Some([0x22; 16]);
+    second.borrow_mut().repository.records.remove(1);
+
+    cache.load(&mut Bmc::ipmi(&first)).unwrap();
+    let dump = cache.load(&mut Bmc::ipmi(&second)).unwrap();
+    assert_eq!(dump.len(), 1);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+    // Storing the dump of the second BMC kept the dump of the first one.
+    let dump = cache.load(&mut Bmc::ipmi(&first)).unwrap();
+    assert_eq!(dump.len(), 2);
+    assert_eq!(first.borrow().sdr_reads, 2);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn cache_with_caller_identity() {
+    let dir = cache_dir("sdr-cache-identity");
+    let cache = SdrCache::new(&dir);
+
+    let bmc = Rc::new(RefCell::new(Bmc::new(None)));
+
+    assert!(matches!(
+        cache.load(&mut Bmc::ipmi(&bmc)),
+        Err(SdrCacheError::NoIdentity)
+    ));
+
+    cache.load_as(&mut Bmc::ipmi(&bmc), "bmc1").unwrap();
+    cache
+        .load_as(&mut Bmc::ipmi(&bmc), "bmc1.example.com")
+        .unwrap();
+    cache.load_as(&mut Bmc::ipmi(&bmc), "bmc1").unwrap();
+
+    // Identities that share a prefix do not replace each other's dumps.
+    assert_eq!(bmc.borrow().sdr_reads, 4);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+    // Identities that differ only in characters that are not allowed
+    // in file names do not collide, and stay in the cache directory.
+    cache.load_as(&mut Bmc::ipmi(&bmc), "../bmc_1").unwrap();
+    cache.load_as(&mut Bmc::ipmi(&bmc), "../bmc/1").unwrap();
+    cache.load_as(&mut Bmc::ipmi(&bmc), "../bmc_1").unwrap();
+
+    assert_eq!(bmc.borrow().sdr_reads, 8);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 4);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,175 @@
+use crate::{connection::NetFn, storage::sdr::RecordId, SdrIterError};
+
+use super::{
+    dell_r630::{FAN_2A_SDR, INLET_TEMP_SDR},
+    mock::MockConnection,
+};
+
+/// A mock SDR repository that cannot return more than `max_read`
+/// bytes at once, that can cancel reservations, and that can fail
+/// to return a specific record.
+pub(super) struct Repository {
+    pub(super) records: Vec<&'static [u8]>,
+    max_read: usize,
+    reservation_id: u16,
+    cancel_reservation_after: Option<usize>,
+    partial_reads: usize,
+    failing_record: Option<u16>,
+}
+
+impl Repository {
+    pub(super) fn new(max_read: usize) -> Self {
+        Self {
+            records: vec![&FAN_2A_SDR[2..], &INLET_TEMP_SDR[2..]],
+            max_read,
+            reservation_id: 0,
+            cancel_reservation_after: None,
+            partial_reads: 0,
+            failing_record: None,
+        }
+    }
+
+    pub(super) fn handle(&mut self, netfn: NetFn, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        match (netfn, cmd) {
+            (NetFn::Storage, 0x22) => {
+                self.reservation_id += 1;
+                (0x00, self.reservation_id.to_le_bytes().to_vec())
+            }
+            (NetFn::Storage, 0x23) => self.get_sdr(data),
+            _ => (0xC1, Vec::new()),
+        }
+    }
+
+    fn get_sdr(&mut self, data: &[u8]) -> (u8, Vec<u8>) {
+        let reservation_id = u16::from_le_bytes([data[0], data[1]]);
+        let record_id = RecordId::new_raw(u16::from_le_bytes([data[2], data[3]]));
+        let offset = data[4] as usize;
+        let len = data[5] as usize;
+
+        if Some(record_id.value()) == self.failing_record {
+            return (0xFF, Vec::new());
+        }
+
+        if len > self.max_read {
+            return (0xCA, Vec::new());
+        }
+
+        if reservation_id != self.reservation_id {
+            return (0xC5, Vec::new());
+        }
+
+        self.partial_reads += 1;
+        if Some(self.partial_reads) == self.cancel_reservation_after {
+            self.reservation_id += 1;
+            return (0xC5, Vec::new());
+        }
+
+        let idx = self
+            .records
+            .iter()
+            .position(|r| record_id.is_first() || [r[0], r[1]] == record_id.value().to_le_bytes())
+            .unwrap();
+
+        let next_entry = self
+            .records
+            .get(idx + 1)
+            .map(|r| [r[0], r[1]])
+            .unwrap_or([0xFF, 0xFF]);
+
+        let record = self.records[idx];
+        let end = (offset + len).min(record.len());
+
+        let mut response = next_entry.to_vec();
+        response.extend_from_slice(&record[offset..end]);
+        (0x00, response)
+    }
+}
+
+fn read_all(mut repository: Repository) -> Vec<Result<u16, SdrIterError<()>>> {
+    let mut ipmi = MockConnection::ipmi(move |request| {
+        repository.handle(request.netfn(), request.cmd(), request.data())
+    });
+
+    ipmi.sdrs()
+        .map(|r| r.map(|r| r.header.id.value()))
+        .collect()
+}
+
+fn record_ids(max_read: usize, cancel_reservation_after: Option<usize>) -> Vec<u16> {
+    let mut repository = Repository::new(max_read);
+    repository.cancel_reservation_after = cancel_reservation_after;
+
+    read_all(repository)
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect()
+}
+
+/// A Full Sensor record with ID 0x0020 that is too short to be parsed.
+const TRUNCATED_SDR: [u8; 7] = [0x20, 0x00, 0x51, 0x01, 0x02, 0x20, 0x00];
+
+#[test]
+fn full_reads() {
+    assert_eq!(record_ids(0xFF, None), vec![0x000D, 0x0012]);
+}
+
+#[test]
+fn partial_reads_with_reduced_read_size() {
+    assert_eq!(record_ids(10, None), vec![0x000D, 0x0012]);
+}
+
+#[test]
+fn partial_reads_with_cancelled_reservation() {
+    assert_eq!(record_ids(16, Some(3)), vec![0x000D, 0x0012]);
+}
+
+#[test]
+fn invalid_record_is_reported_and_skipped() {
+    for max_read in [0xFF, 4] {
+        let mut repository = Repository::new(max_read);
+        repository.records.insert(1, &TRUNCATED_SDR);
+
+        let records = read_all(repository);
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].as_ref().unwrap(), &0x000D);
+        assert!(matches!(
+            &records[1],
+            Err(SdrIterError::InvalidRecord { record_id, data, .. })
+                if record_id.value() == 0x0020 && data.as_slice() == TRUNCATED_SDR
+        ));
+        assert_eq!(records[2].as_ref().unwrap(), &0x0012);
+    }
+}
+
+#[test]
+fn command_error_ends_iteration() {
+    let mut repository = Repository::new(0xFF);
+    repository.failing_record = Some(0x0012);
+
+    let records = read_all(repository);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].as_ref().unwrap(), &0x000D);
+    assert!(matches!(
+        &records[1],
+        Err(e @ SdrIterError::Command { .. }) if e.record_id().value() == 0x0012
+    ));
+}
+
+#[test]
+fn looping_repository_is_detected() {
+    let mut repository = Repository::new(0xFF);
+    // The last record links back to the first one.
+    repository.records.push(&FAN_2A_SDR[2..]);
+
+    let records = read_all(repository);
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].as_ref().unwrap(), &0x000D);
+    assert_eq!(records[1].as_ref().unwrap(), &0x0012);
+    assert!(matches!(
+        &records[2],
+        Err(SdrIterError::Loop { record_id }) if record_id.value() == 0x000D
+    ));
+}
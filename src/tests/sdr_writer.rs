@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use crate::{
+    connection::NetFn,
+    storage::sdr::{RecordId, SdrOperation, SdrWriteError, SdrWriter},
+};
+
+use super::{dell_r630::FAN_2A_SDR, mock::MockConnection};
+
+/// A mock SDR repository that only supports modal updates,
+/// partial adds and reservations.
+#[derive(Default)]
+struct Repository {
+    update_mode: bool,
+    pending: Vec<u8>,
+    records: Vec<Vec<u8>>,
+    clear_polls: usize,
+}
+
+impl Repository {
+    const OPERATION_SUPPORT: u8 = 0x40 | 0x04 | 0x02;
+
+    fn handle(&mut self, netfn: NetFn, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        if netfn != NetFn::Storage {
+            return (0xC1, Vec::new());
+        }
+
+        match cmd {
+            0x20 => {
+                let mut info = vec![0x51, self.records.len() as u8, 0x00, 0xFF, 0xFF];
+                info.extend_from_slice(&[0; 8]);
+                info.push(Self::OPERATION_SUPPORT);
+                (0x00, info)
+            }
+            0x22 => (0x00, vec![0x01, 0x00]),
+            // Add SDR: only accept small requests.
+            0x24 if data.len() > 16 => (0xC7, Vec::new()),
+            0x25 => {
+                if !self.update_mode {
+                    return (0xD5, Vec::new());
+                }
+
+                assert_eq!(data[4] as usize, self.pending.len());
+                self.pending.extend_from_slice(&data[6..]);
+
+                if data[5] == 0x01 {
+                    self.records.push(core::mem::take(&mut self.pending));
+                }
+
+                (0x00, vec![0x40, 0x00])
+            }
+            0x27 => {
+                assert_eq!(&data[2..5], b"CLR");
+
+                if data[5] == 0xAA {
+                    self.records.clear();
+                    (0x00, vec![0x00])
+                } else {
+                    self.clear_polls += 1;
+                    (0x00, vec![0x01])
+                }
+            }
+            0x2A => {
+                self.update_mode = true;
+                (0x00, Vec::new())
+            }
+            0x2B => {
+                self.update_mode = false;
+                (0x00, Vec::new())
+            }
+            _ => (0xC1, Vec::new()),
+        }
+    }
+}
+
+#[test]
+fn modal_partial_add_and_clear() {
+    let mut repository = Repository::default();
+    let record = &FAN_2A_SDR[2..];
+
+    {
+        let mut ipmi = MockConnection::ipmi(|request| {
+            repository.handle(request.netfn(), request.cmd(), request.data())
+        });
+
+        let mut writer = SdrWriter::new(&mut ipmi).unwrap();
+
+        assert!(matches!(
+            writer.add(record),
+            Err(SdrWriteError::Unsupported(SdrOperation::NonModalUpdate))
+        ));
+        assert!(matches!(
+            writer.delete(RecordId::new_raw(0x40)),
+            Err(SdrWriteError::Unsupported(SdrOperation::NonModalUpdate))
+        ));
+
+        writer.enter_update_mode().unwrap();
+
+        assert_eq!(writer.add(record).unwrap(), RecordId::new_raw(0x40));
+        assert!(matches!(
+            writer.delete(RecordId::new_raw(0x40)),
+            Err(SdrWriteError::Unsupported(SdrOperation::Delete))
+        ));
+        assert!(matches!(
+            writer.add(&record[..record.len() - 1]),
+            Err(SdrWriteError::InvalidRecord)
+        ));
+
+        writer.exit_update_mode().unwrap();
+    }
+
+    assert_eq!(repository.records, vec![record.to_vec()]);
+    assert!(!repository.update_mode);
+
+    {
+        let mut ipmi = MockConnection::ipmi(|request| {
+            repository.handle(request.netfn(), request.cmd(), request.data())
+        });
+
+        let mut writer = SdrWriter::new(&mut ipmi).unwrap();
+        writer.enter_update_mode().unwrap();
+        writer.clear(Duration::from_secs(1)).unwrap();
+    }
+
+    assert!(repository.records.is_empty());
+    assert_eq!(repository.clear_polls, 1);
+    assert!(!repository.update_mode);
+}
@@ -0,0 +1,154 @@
+use crate::storage::{
+    sdr::Record,
+    sel::{Entry, EventData2, EventData3, EventDirection},
+};
+
+use super::{
+    dell_r630::{FAN_2A_SDR, INLET_TEMP_SDR},
+    sel_iter::system_entry,
+};
+
+/// A system event entry for `sensor_type`, `event_type` and
+/// `event_data`.
+fn entry(sensor_type: u8, event_type: u8, direction: u8, event_data: [u8; 3]) -> Entry {
+    let mut data = system_entry(0x0001);
+    data[10] = sensor_type;
+    data[12] = (direction << 7) | event_type;
+    data[13..].copy_from_slice(&event_data);
+    Entry::parse(&data).unwrap()
+}
+
+/// A threshold event of sensor `sensor_number`, generated by `generator_id`.
+fn threshold_entry(generator_id: [u8; 2], sensor_number: u8) -> Entry {
+    let mut data = system_entry(0x0001);
+    data[7..9].copy_from_slice(&generator_id);
+    data[10] = 0x01;
+    data[11] = sensor_number;
+    data[12] = 0x01;
+    data[13..].copy_from_slice(&[0x59, 0xB0, 0xAA]);
+    Entry::parse(&data).unwrap()
+}
+
+#[test]
+fn memory_correctable_ecc() {
+    let event = entry(0x0C, 0x6F, 0, [0xF0, 0xFF, 0x03])
+        .system_event()
+        .unwrap();
+
+    assert_eq!(event.offset, 0x00);
+    assert_eq!(event.data_2, EventData2::SensorSpecific(0xFF));
+    assert_eq!(event.data_3, EventData3::SensorSpecific(0x03));
+    assert_eq!(
+        event.to_string(),
+        "Memory: Correctable ECC, memory module 3"
+    );
+}
+
+#[test]
+fn power_supply_ac_lost_deasserted() {
+    let event = entry(0x08, 0x6F, 1, [0x03, 0xFF, 0xFF])
+        .system_event()
+        .unwrap();
+
+    assert_eq!(event.direction, EventDirection::Deassert);
+    assert_eq!(event.to_string(), "Power Supply: AC lost (deasserted)");
+}
+
+#[test]
+fn generic_discrete() {
+    let event = entry(0x04, 0x07, 0, [0x52, 0xF1, 0xFF])
+        .system_event()
+        .unwrap();
+
+    assert_eq!(
+        event.data_2,
+        EventData2::PreviousState {
+            offset: Some(1),
+            severity: None,
+        }
+    );
+    assert_eq!(
+        event.offset_description(),
+        Some("Transition to Critical from less severe")
+    );
+}
+
+#[test]
+fn threshold_with_record() {
+    let record = Record::parse(&INLET_TEMP_SDR[2..]).unwrap();
+    let event = entry(0x01, 0x01, 0, [0x59, 0xB0, 0xAA])
+        .system_event()
+        .unwrap();
+
+    assert_eq!(event.data_2, EventData2::TriggerReading(0xB0));
+    assert_eq!(event.data_3, EventData3::TriggerThreshold(0xAA));
+    assert_eq!(
+        event.to_string(),
+        "Temperature: Upper Critical going high, reading 0xB0, threshold 0xAA"
+    );
+    assert_eq!(
+        event.describe(Some(&record)),
+        "Inlet Temp: Upper Critical going high, reading 48.00 °C, threshold 42.00 °C"
+    );
+}
+
+#[test]
+fn unknown_offset() {
+    let event = entry(0x01, 0x01, 0, [0x0E, 0xFF, 0xFF])
+        .system_event()
+        .unwrap();
+
+    assert_eq!(event.offset_description(), None);
+    assert_eq!(
+        event.to_string(),
+        "Temperature: Event type 0x01, offset 0xE"
+    );
+}
+
+#[test]
+fn sensor_record() {
+    let records = [
+        Record::parse(&FAN_2A_SDR[2..]).unwrap(),
+        Record::parse(&INLET_TEMP_SDR[2..]).unwrap(),
+    ];
+
+    let entry = threshold_entry([0x20, 0x00], 0x04);
+    let record = entry.sensor_record(&records).unwrap();
+    assert_eq!(record.id().unwrap().to_string(), "Inlet Temp");
+
+    let event = entry.system_event().unwrap();
+    let full = record.full_sensor().unwrap();
+    assert_eq!(
+        event.trigger_reading(full).unwrap().display(true),
+        "48.00 °C"
+    );
+    assert_eq!(
+        event.trigger_threshold(full).unwrap().display(true),
+        "42.00 °C"
+    );
+
+    // Events received on a different channel still match.
+    assert!(threshold_entry([0x20, 0x10], 0x04)
+        .sensor_record(&records)
+        .is_some());
+}
+
+#[test]
+fn sensor_record_mismatch() {
+    let records = [Record::parse(&INLET_TEMP_SDR[2..]).unwrap()];
+
+    // Different sensor number
+    assert!(threshold_entry([0x20, 0x00], 0x05)
+        .sensor_record(&records)
+        .is_none());
+
+    // Different LUN
+    assert!(threshold_entry([0x20, 0x01], 0x04)
+        .sensor_record(&records)
+        .is_none());
+
+    // Software ID with the same value as the I2C address
+    assert!(threshold_entry([0x21, 0x00], 0x04)
+        .sensor_record(&records)
+        .is_none());
+}
@@ -0,0 +1,212 @@
+use crate::{
+    connection::NetFn,
+    storage::sel::{Entry, EventDirection, RecordId},
+    SelIterError,
+};
+
+use super::mock::MockConnection;
+
+/// A mock SEL that cannot return more than `max_read` bytes
+/// at once, and that can cancel reservations.
+pub(super) struct Sel {
+    pub(super) entries: Vec<[u8; 16]>,
+    max_read: usize,
+    reservation_id: u16,
+    cancel_reservation_after: Option<usize>,
+    partial_reads: usize,
+}
+
+/// A system event entry with ID `id`.
+pub(super) fn system_entry(id: u16) -> [u8; 16] {
+    let [low, high] = id.to_le_bytes();
+    [
+        low, high, 0x02, 0x00, 0x10, 0x00, 0x60, 0x20, 0x00, 0x04, 0x01, 0x04, 0x01, 0x59, 0x00,
+        0x00,
+    ]
+}
+
+impl Sel {
+    pub(super) fn new(max_read: usize) -> Self {
+        Self {
+            entries: vec![
+                system_entry(0x0001),
+                system_entry(0x0002),
+                system_entry(0x0005),
+            ],
+            max_read,
+            reservation_id: 0,
+            cancel_reservation_after: None,
+            partial_reads: 0,
+        }
+    }
+
+    pub(super) fn handle(&mut self, netfn: NetFn, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        match (netfn, cmd) {
+            (NetFn::Storage, 0x42) => {
+                self.reservation_id += 1;
+                (0x00, self.reservation_id.to_le_bytes().to_vec())
+            }
+            (NetFn::Storage, 0x43) => self.get_entry(data),
+            _ => (0xC1, Vec::new()),
+        }
+    }
+
+    fn get_entry(&mut self, data: &[u8]) -> (u8, Vec<u8>) {
+        let reservation_id = u16::from_le_bytes([data[0], data[1]]);
+        let record_id = u16::from_le_bytes([data[2], data[3]]);
+        let offset = data[4] as usize;
+        let len = data[5] as usize;
+
+        if len > self.max_read {
+            return (0xCA, Vec::new());
+        }
+
+        if len != 0xFF {
+            if reservation_id != self.reservation_id {
+                return (0xC5, Vec::new());
+            }
+
+            self.partial_reads += 1;
+            if Some(self.partial_reads) == self.cancel_reservation_after {
+                self.reservation_id += 1;
+                return (0xC5, Vec::new());
+            }
+        }
+
+        let idx = match record_id {
+            0x0000 if !self.entries.is_empty() => 0,
+            0xFFFF if !self.entries.is_empty() => self.entries.len() - 1,
+            id => match self
+                .entries
+                .iter()
+                .position(|e| [e[0], e[1]] == id.to_le_bytes())
+            {
+                Some(idx) => idx,
+                None => return (0xCB, Vec::new()),
+            },
+        };
+
+        let next_entry = self
+            .entries
+            .get(idx + 1)
+            .map(|e| [e[0], e[1]])
+            .unwrap_or([0xFF, 0xFF]);
+
+        let entry = &self.entries[idx];
+        let end = (offset + len).min(entry.len());
+
+        let mut response = next_entry.to_vec();
+        response.extend_from_slice(&entry[offset..end]);
+        (0x00, response)
+    }
+}
+
+enum Start {
+    First,
+    Reverse,
+    After(u16),
+}
+
+fn read_all(mut sel: Sel, start: Start) -> Vec<Result<u16, SelIterError<()>>> {
+    let mut ipmi = MockConnection::ipmi(move |request| {
+        sel.handle(request.netfn(), request.cmd(), request.data())
+    });
+
+    let iter = match start {
+        Start::First => ipmi.sel_entries(),
+        Start::Reverse => ipmi.sel_entries_rev(),
+        Start::After(id) => ipmi.sel_entries_after(RecordId::new(id).unwrap()),
+    };
+
+    iter.map(|e| e.map(|e| e.record_id().value())).collect()
+}
+
+fn entry_ids(sel: Sel, start: Start) -> Vec<u16> {
+    read_all(sel, start)
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect()
+}
+
+#[test]
+fn full_reads() {
+    assert_eq!(entry_ids(Sel::new(0xFF), Start::First), vec![1, 2, 5]);
+}
+
+#[test]
+fn partial_reads_with_cancelled_reservation() {
+    let mut sel = Sel::new(8);
+    sel.cancel_reservation_after = Some(3);
+
+    assert_eq!(entry_ids(sel, Start::First), vec![1, 2, 5]);
+}
+
+#[test]
+fn reverse() {
+    assert_eq!(entry_ids(Sel::new(0xFF), Start::Reverse), vec![5, 2, 1]);
+}
+
+#[test]
+fn resume_after() {
+    assert_eq!(entry_ids(Sel::new(0xFF), Start::After(2)), vec![5]);
+    assert!(entry_ids(Sel::new(0xFF), Start::After(5)).is_empty());
+
+    // The SEL was cleared, and new entries were added since.
+    let mut sel = Sel::new(0xFF);
+    sel.entries = vec![system_entry(0x0010)];
+    assert_eq!(entry_ids(sel, Start::After(5)), vec![0x0010]);
+}
+
+#[test]
+fn resume_after_reused_id() {
+    // The SEL was cleared, and the ID of the entry is reused
+    // by an entry that is added while iterating.
+    let mut sel = Sel::new(0xFF);
+    sel.entries = vec![system_entry(0x0001), system_entry(0x0005)];
+
+    let mut ipmi = MockConnection::ipmi(move |request| {
+        let response = sel.handle(request.netfn(), request.cmd(), request.data());
+        if request.cmd() == 0x43 && response.0 == 0x00 && sel.entries.len() == 2 {
+            sel.entries.push(system_entry(0x0002));
+        }
+        response
+    });
+
+    let ids: Vec<_> = ipmi
+        .sel_entries_after(RecordId::new(2).unwrap())
+        .map(|e| e.unwrap().record_id().value())
+        .collect();
+
+    assert_eq!(ids, vec![1, 5, 2]);
+}
+
+#[test]
+fn invalid_entry_is_reported_and_skipped() {
+    let mut sel = Sel::new(0xFF);
+    sel.entries[1][2] = 0x10;
+
+    let entries = read_all(sel, Start::First);
+
+    assert_eq!(entries.len(), 3);
+    assert!(matches!(
+        &entries[1],
+        Err(SelIterError::InvalidEntry { record_id, .. }) if record_id.value() == 0x0002
+    ));
+    assert_eq!(entries[2].as_ref().unwrap(), &0x0005);
+}
+
+#[test]
+fn event_direction() {
+    let direction = |entry: [u8; 16]| match Entry::parse(&entry).unwrap() {
+        Entry::System {
+            event_direction, ..
+        } => event_direction,
+        e => panic!("Unexpected entry {e:?}"),
+    };
+
+    let mut entry = system_entry(1);
+    assert_eq!(direction(entry), EventDirection::Assert);
+
+    entry[12] |= 0x80;
+    assert_eq!(direction(entry), EventDirection::Deassert);
+}
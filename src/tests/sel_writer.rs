@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use crate::{
+    connection::NetFn,
+    storage::{
+        sel::{
+            Entry, EventDirection, GetSelTime, GetSelTimeUtcOffset, RecordId, SelCommand,
+            SelUtcOffset, SelWriteError, SelWriter, SetSelTime, SetSelTimeUtcOffset,
+        },
+        Timestamp,
+    },
+};
+
+use super::{mock::MockConnection, sel_iter::system_entry};
+
+/// A mock SEL that only accepts entries using partial adds.
+#[derive(Default)]
+struct Sel {
+    pending: Vec<u8>,
+    entries: Vec<Vec<u8>>,
+    time: u32,
+    utc_offset: i16,
+}
+
+impl Sel {
+    // Partial Add and Reserve are supported, Delete is not.
+    const OPERATION_SUPPORT: u8 = 0x04 | 0x02;
+
+    fn handle(&mut self, netfn: NetFn, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        if netfn != NetFn::Storage {
+            return (0xC1, Vec::new());
+        }
+
+        match cmd {
+            0x40 => {
+                let mut info = vec![0x51, self.entries.len() as u8, 0x00, 0xFF, 0xFF];
+                info.extend_from_slice(&[0; 8]);
+                info.push(Self::OPERATION_SUPPORT);
+                (0x00, info)
+            }
+            0x42 => (0x00, vec![0x01, 0x00]),
+            0x44 => (0xC7, Vec::new()),
+            0x45 => {
+                assert_eq!(data[4] as usize, self.pending.len());
+                self.pending.extend_from_slice(&data[6..]);
+
+                if data[5] == 0x01 {
+                    self.entries.push(core::mem::take(&mut self.pending));
+                }
+
+                (0x00, vec![0x10, 0x00])
+            }
+            0x47 => {
+                assert_eq!(&data[2..5], b"CLR");
+                self.entries.clear();
+                (0x00, vec![0x01])
+            }
+            0x48 => (0x00, self.time.to_le_bytes().to_vec()),
+            0x49 => {
+                self.time = u32::from_le_bytes(data.try_into().unwrap());
+                (0x00, Vec::new())
+            }
+            0x5C => (0x00, self.utc_offset.to_le_bytes().to_vec()),
+            0x5D => {
+                self.utc_offset = i16::from_le_bytes(data.try_into().unwrap());
+                (0x00, Vec::new())
+            }
+            _ => (0xC1, Vec::new()),
+        }
+    }
+}
+
+#[test]
+fn entry_round_trip() {
+    let data = system_entry(0x0042);
+    assert_eq!(Entry::parse(&data).unwrap().to_bytes(), data);
+}
+
+#[test]
+fn entry_event_direction() {
+    // Bit 7 of the event type byte is set for deassertion events.
+    let mut data = system_entry(0x0042);
+    assert!(matches!(
+        Entry::parse(&data).unwrap(),
+        Entry::System {
+            event_direction: EventDirection::Assert,
+            ..
+        }
+    ));
+
+    data[12] |= 0x80;
+    let entry = Entry::parse(&data).unwrap();
+    assert!(matches!(
+        entry,
+        Entry::System {
+            event_direction: EventDirection::Deassert,
+            ..
+        }
+    ));
+    assert_eq!(entry.to_bytes(), data);
+}
+
+#[test]
+fn add_delete_and_clear() {
+    let mut sel = Sel::default();
+
+    {
+        let mut ipmi = MockConnection::ipmi(|request| {
+            sel.handle(request.netfn(), request.cmd(), request.data())
+        });
+
+        let mut writer = SelWriter::new(&mut ipmi).unwrap();
+
+        assert_eq!(
+            writer.add(system_entry(0x0000)).unwrap(),
+            RecordId::new(0x0010).unwrap()
+        );
+        assert!(matches!(
+            writer.delete(RecordId::new(0x0010).unwrap()),
+            Err(SelWriteError::Unsupported(SelCommand::Delete))
+        ));
+        #[allow(deprecated)]
+        let clear = SelCommand::Clear;
+        assert!(!writer.info().supports(clear));
+    }
+
+    assert_eq!(sel.entries, vec![system_entry(0x0000).to_vec()]);
+
+    {
+        let mut ipmi = MockConnection::ipmi(|request| {
+            sel.handle(request.netfn(), request.cmd(), request.data())
+        });
+
+        SelWriter::new(&mut ipmi)
+            .unwrap()
+            .clear(Duration::from_secs(1))
+            .unwrap();
+    }
+
+    assert!(sel.entries.is_empty());
+}
+
+#[test]
+fn time_and_utc_offset() {
+    let mut sel = Sel::default();
+    let mut ipmi =
+        MockConnection::ipmi(|request| sel.handle(request.netfn(), request.cmd(), request.data()));
+
+    ipmi.send_recv(SetSelTime::new(Timestamp::from(0x6000_0000)))
+        .unwrap();
+    assert_eq!(u32::from(ipmi.send_recv(GetSelTime).unwrap()), 0x6000_0000);
+
+    let offset = SelUtcOffset::new(-120).unwrap();
+    ipmi.send_recv(SetSelTimeUtcOffset::new(offset)).unwrap();
+    assert_eq!(ipmi.send_recv(GetSelTimeUtcOffset).unwrap(), offset);
+
+    ipmi.send_recv(SetSelTimeUtcOffset::new(SelUtcOffset::Unspecified))
+        .unwrap();
+    assert_eq!(
+        ipmi.send_recv(GetSelTimeUtcOffset).unwrap(),
+        SelUtcOffset::Unspecified
+    );
+
+    assert!(SelUtcOffset::new(1441).is_none());
+}
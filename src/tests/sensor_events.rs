@@ -0,0 +1,124 @@
+use crate::{
+    connection::{CompletionCode, IpmiCommand, Message, NetFn},
+    sensor_event::{
+        discrete::sensor_specific::PowerSupplyState, DiscreteStates, EventEnableChange,
+        GetSensorEventEnable, GetSensorEventStatus, GetSensorHysteresis, RearmSensorEvents,
+        SensorEventMasks, SensorHysteresis, SetSensorEventEnable, SetSensorHysteresis,
+    },
+    storage::sdr::{
+        record::{SensorRecord, ThresholdAssertEventMask},
+        Record,
+    },
+};
+
+use super::{dell_r630::FAN_2A_SDR, mock::MockConnection};
+
+#[test]
+fn quiet_flapping_sensor() {
+    let record = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let key = *record.full_sensor().unwrap().key_data();
+
+    let mut requests = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(request.netfn(), NetFn::SensorEvent);
+        requests.push((request.cmd(), request.data().to_vec()));
+
+        match request.cmd() {
+            0x25 => (0x00, vec![0x00, 0x00]),
+            0x29 => (0x00, vec![0xC0, 0x04, 0x00, 0x08, 0x00]),
+            _ => (0x00, Vec::new()),
+        }
+    });
+
+    let hysteresis = ipmi
+        .send_recv(GetSensorHysteresis::for_sensor_key(&key))
+        .unwrap();
+    assert_eq!(hysteresis, SensorHysteresis::default());
+
+    ipmi.send_recv(SetSensorHysteresis::for_sensor_key(
+        &key,
+        SensorHysteresis {
+            positive_going: 2,
+            negative_going: 2,
+        },
+    ))
+    .unwrap();
+
+    let enable = ipmi
+        .send_recv(GetSensorEventEnable::for_sensor_key(&key))
+        .unwrap();
+    assert!(enable.all_event_messages_enabled);
+    assert!(enable.scanning_enabled);
+    assert_eq!(
+        enable.events.threshold_assertion(),
+        ThresholdAssertEventMask::LOWER_CRITICAL_GOING_LOW
+    );
+    assert_eq!(
+        enable.events.threshold_deassertion(),
+        ThresholdAssertEventMask::LOWER_CRITICAL_GOING_HIGH
+    );
+
+    let deassertion = SensorEventMasks::threshold(
+        ThresholdAssertEventMask::empty(),
+        ThresholdAssertEventMask::LOWER_CRITICAL_GOING_HIGH,
+    );
+
+    ipmi.send_recv(SetSensorEventEnable::for_sensor_key(
+        &key,
+        true,
+        true,
+        EventEnableChange::Disable(deassertion),
+    ))
+    .unwrap();
+
+    ipmi.send_recv(RearmSensorEvents::for_sensor_key(&key, None))
+        .unwrap();
+
+    drop(ipmi);
+
+    assert_eq!(
+        requests,
+        [
+            (0x25, vec![0x32, 0xFF]),
+            (0x24, vec![0x32, 0xFF, 0x02, 0x02]),
+            (0x29, vec![0x32]),
+            (0x28, vec![0x32, 0xE0, 0x00, 0x00, 0x08, 0x00]),
+            (0x2A, vec![0x32, 0x00]),
+        ]
+    );
+}
+
+#[test]
+fn discrete_masks() {
+    let masks = SensorEventMasks::discrete(
+        DiscreteStates::<PowerSupplyState>::from_bits(0x0002),
+        DiscreteStates::from_bits(0x0008),
+    );
+
+    assert!(masks
+        .discrete_assertion()
+        .contains(PowerSupplyState::FailureDetected));
+    assert!(masks
+        .discrete_deassertion()
+        .contains(PowerSupplyState::AcLost));
+
+    let record = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let key = record.full_sensor().unwrap().key_data();
+
+    let message: Message = RearmSensorEvents::for_sensor_key(key, Some(masks)).into();
+    assert_eq!(message.data(), &[0x32, 0x80, 0x02, 0x00, 0x08, 0x00]);
+}
+
+#[test]
+fn event_status() {
+    let status =
+        GetSensorEventStatus::parse_response(CompletionCode::Success, &[0xA0, 0x03]).unwrap();
+
+    assert!(status.all_event_messages_enabled);
+    assert!(!status.scanning_enabled);
+    assert!(status.reading_unavailable);
+    assert_eq!(status.events.assertion, 0x0003);
+    assert_eq!(status.events.deassertion, 0x0000);
+
+    assert!(GetSensorEventStatus::parse_response(CompletionCode::Success, &[]).is_err());
+}
@@ -0,0 +1,107 @@
+use crate::{
+    connection::NetFn,
+    sensor_event::{GetSensorReadingFactors, ReadingConverter},
+    storage::sdr::{
+        record::{Linearization, SensorRecord},
+        Record,
+    },
+};
+
+use super::{dell_r630::INLET_TEMP_SDR, mock::MockConnection};
+
+#[test]
+fn non_linear_sensor() {
+    let record = Record::parse(&INLET_TEMP_SDR[2..]).unwrap();
+    let mut inlet_temp = record.full_sensor().unwrap().clone();
+    inlet_temp.linearization = Linearization::NonLinear;
+
+    assert!(inlet_temp.convert(0x20).is_none());
+
+    let mut requests = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::SensorEvent, 0x23));
+        requests.push(request.data().to_vec());
+
+        if request.data()[1] < 0x30 {
+            // M = 3, B = -5, result exponent = -1, up to reading 0x30
+            (0x00, vec![0x30, 0x03, 0x00, 0xFB, 0xC0, 0x00, 0xF0])
+        } else {
+            // M = 4, B = -5, result exponent = -1, for the remaining readings
+            (0x00, vec![0x30, 0x04, 0x00, 0xFB, 0xC0, 0x00, 0xF0])
+        }
+    });
+
+    let mut converter = ReadingConverter::new();
+
+    for _ in 0..2 {
+        let value = converter
+            .convert(&mut ipmi, &inlet_temp, 0x20)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value.display(true), "9.10 °C");
+    }
+
+    let mut convert = |reading| {
+        converter
+            .convert(&mut ipmi, &inlet_temp, reading)
+            .unwrap()
+            .unwrap()
+            .display(true)
+    };
+
+    assert_eq!(convert(0x2F), "13.60 °C");
+    assert_eq!(convert(0x30), "18.70 °C");
+    assert_eq!(convert(0x31), "19.10 °C");
+    assert_eq!(convert(0x1F), "8.80 °C");
+
+    drop(ipmi);
+
+    // Factors are cached for the range of readings that they apply to.
+    let sensor_number = inlet_temp.sensor_number().get();
+    assert_eq!(
+        requests,
+        [
+            [sensor_number, 0x20],
+            [sensor_number, 0x30],
+            [sensor_number, 0x31],
+            [sensor_number, 0x1F]
+        ]
+    );
+}
+
+#[test]
+fn linear_sensor() {
+    let record = Record::parse(&INLET_TEMP_SDR[2..]).unwrap();
+    let inlet_temp = record.full_sensor().unwrap();
+
+    let mut ipmi = MockConnection::ipmi(|_| panic!("Linear sensors need no reading factors"));
+
+    let value = ReadingConverter::new()
+        .convert(&mut ipmi, inlet_temp, 0x99)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(value.display(true), "25.00 °C");
+}
+
+#[test]
+fn parse_reading_factors() {
+    use crate::connection::{CompletionCode, IpmiCommand};
+
+    let factors = GetSensorReadingFactors::parse_response(
+        CompletionCode::Success,
+        &[0x30, 0x03, 0x00, 0xFB, 0xC0, 0x00, 0xF0],
+    )
+    .unwrap();
+
+    assert_eq!(factors.next_reading, 0x30);
+    assert_eq!(factors.factors.m, 3);
+    assert_eq!(factors.factors.b, -5);
+    assert_eq!(factors.factors.result_exponent, -1);
+    assert_eq!(factors.factors.b_exponent, 0);
+
+    assert!(
+        GetSensorReadingFactors::parse_response(CompletionCode::Success, &[0x30, 0x03]).is_err()
+    );
+}
@@ -0,0 +1,73 @@
+use crate::{
+    connection::NetFn,
+    sensor_event::{GetSensorThresholds, RawSensorThresholds, SetSensorThresholds},
+    storage::sdr::{
+        record::{SensorRecord, ThresholdKind},
+        Record,
+    },
+};
+
+use super::{
+    dell_r630::{FAN_2A_SDR, INLET_TEMP_SDR},
+    mock::MockConnection,
+};
+
+#[test]
+fn to_raw() {
+    let record = Record::parse(&INLET_TEMP_SDR[2..]).unwrap();
+    let inlet_temp = record.full_sensor().unwrap();
+
+    assert_eq!(inlet_temp.to_raw(25.0), Some(0x99));
+    assert_eq!(inlet_temp.to_raw(24.6), Some(0x99));
+    assert_eq!(inlet_temp.to_raw(-10.0), Some(0x76));
+    // Out of range values are clamped
+    assert_eq!(inlet_temp.to_raw(1000.0), Some(0xFF));
+
+    let record = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let fan = record.full_sensor().unwrap();
+
+    for raw in 0..=u8::MAX {
+        let value = fan.convert(raw).unwrap();
+        assert_eq!(fan.to_raw(value.value()), Some(raw));
+    }
+}
+
+#[test]
+fn raise_fan_lower_critical() {
+    let record = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let fan = record.full_sensor().unwrap();
+    let key = *fan.key_data();
+
+    let mut set_request = None;
+    let mut ipmi = MockConnection::ipmi(|request| match (request.netfn(), request.cmd()) {
+        (NetFn::SensorEvent, 0x27) => {
+            assert_eq!(request.data(), &[0x32]);
+            (0x00, vec![0x03, 0x05, 0x03, 0x00, 0x00, 0x00, 0x00])
+        }
+        (NetFn::SensorEvent, 0x26) => {
+            set_request = Some(request.data().to_vec());
+            (0x00, Vec::new())
+        }
+        _ => (0xC1, Vec::new()),
+    });
+
+    let thresholds = ipmi
+        .send_recv(GetSensorThresholds::for_sensor_key(&key))
+        .unwrap();
+
+    assert_eq!(thresholds.get(ThresholdKind::LowerNonCritical), Some(0x05));
+    assert_eq!(thresholds.get(ThresholdKind::LowerCritical), Some(0x03));
+    assert_eq!(thresholds.get(ThresholdKind::UpperCritical), None);
+
+    let mut new = RawSensorThresholds::default();
+    new.set(ThresholdKind::LowerCritical, fan.to_raw(240.0));
+
+    ipmi.send_recv(SetSensorThresholds::for_sensor_key(&key, new))
+        .unwrap();
+
+    drop(ipmi);
+    assert_eq!(
+        set_request.unwrap(),
+        [0x32, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00]
+    );
+}
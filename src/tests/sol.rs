@@ -0,0 +1,347 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    time::Duration,
+};
+
+use crate::{
+    app::{
+        auth::Channel,
+        payload::{
+            ActivatePayload, ActivatedPayload, DeactivatePayload, PayloadActivationError,
+            SolActivation,
+        },
+    },
+    connection::{NetFn, ParseResponseError},
+    transport::sol::{
+        BitRate, GetSolConfigurationParameter, SetSolConfigurationParameter, SolOperation,
+        SolPacket, SolParameter, SolParameterSelector, SolStatus, SolStream, SolStreamConfig,
+        SolTransport,
+    },
+    IpmiError,
+};
+
+use super::mock::MockConnection;
+
+/// A BMC that acknowledges the packets it receives, accepting at most
+/// `accept` characters of each.
+#[derive(Default)]
+struct Bmc {
+    accept: Option<u8>,
+    /// Drop the packets that are sent instead of acknowledging them.
+    drop_packets: usize,
+    sent: Vec<SolPacket>,
+    last_packet: Option<(u8, u8)>,
+    console: Vec<u8>,
+    incoming: VecDeque<SolPacket>,
+}
+
+impl SolTransport for Bmc {
+    fn send(&mut self, payload: &[u8]) -> io::Result<()> {
+        let packet = SolPacket::parse(payload).unwrap();
+        self.sent.push(packet.clone());
+
+        if packet.sequence == 0 {
+            return Ok(());
+        }
+
+        if self.drop_packets > 0 {
+            self.drop_packets -= 1;
+            return Ok(());
+        }
+
+        let accepted = match self.last_packet {
+            // A packet that is sent again is only acknowledged again.
+            Some((sequence, accepted)) if sequence == packet.sequence => accepted,
+            _ => {
+                let accepted = self.accept.unwrap_or(u8::MAX).min(packet.data.len() as u8);
+                self.console
+                    .extend_from_slice(&packet.data[..accepted as usize]);
+                accepted
+            }
+        };
+        self.last_packet = Some((packet.sequence, accepted));
+
+        self.incoming.push_back(SolPacket {
+            ack_sequence: packet.sequence,
+            accepted,
+            ..Default::default()
+        });
+
+        Ok(())
+    }
+
+    fn recv(&mut self, _timeout: Duration) -> io::Result<Option<Vec<u8>>> {
+        let payload = self.incoming.pop_front().map(|packet| {
+            let mut data = Vec::new();
+            packet.write_data(&mut data);
+            data
+        });
+
+        Ok(payload)
+    }
+}
+
+fn config() -> SolStreamConfig {
+    SolStreamConfig {
+        max_characters: 8,
+        accumulate_interval: Duration::from_secs(60),
+        send_threshold: 8,
+        retry_interval: Duration::from_secs(60),
+        retry_count: 2,
+    }
+}
+
+#[test]
+fn activate_sol() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x48));
+        assert_eq!(&[0x01, 0x01, 0xC0, 0x00, 0x00, 0x00], request.data());
+        (
+            0x00,
+            vec![
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x6F, 0x02, 0xFF, 0xFF,
+            ],
+        )
+    });
+
+    let activated = ipmi
+        .send_recv(ActivatePayload::sol(1, SolActivation::default()))
+        .unwrap();
+
+    assert_eq!(
+        ActivatedPayload {
+            inbound_payload_size: 256,
+            outbound_payload_size: 256,
+            port: 623,
+            vlan: None,
+        },
+        activated
+    );
+    assert_eq!(252, SolStreamConfig::new(&activated).max_characters);
+}
+
+#[test]
+fn activate_sol_already_active() {
+    let mut ipmi = MockConnection::ipmi(|_| (0x80, Vec::new()));
+
+    let result = ipmi.send_recv(ActivatePayload::sol(1, SolActivation::default()));
+
+    assert!(matches!(
+        result,
+        Err(IpmiError::ParsingFailed {
+            error: ParseResponseError::Parse(PayloadActivationError::AlreadyActive),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn deactivate_sol() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x49));
+        assert_eq!(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x00], request.data());
+        (0x00, Vec::new())
+    });
+
+    ipmi.send_recv(DeactivatePayload::sol(1)).unwrap();
+}
+
+#[test]
+fn configure_sol() {
+    let mut sent = Vec::new();
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(NetFn::Transport, request.netfn());
+
+        match request.cmd() {
+            0x21 => {
+                sent.push(request.data().to_vec());
+                (0x00, Vec::new())
+            }
+            0x22 => {
+                assert_eq!(&[0x01, 0x06, 0x00, 0x00], request.data());
+                (0x00, vec![0x11, 0x0A])
+            }
+            cmd => panic!("Unexpected command {cmd:02X}"),
+        }
+    });
+
+    let channel = Channel::Number(1);
+    ipmi.send_recv(SetSolConfigurationParameter::new(
+        channel,
+        SolParameter::Enable(true),
+    ))
+    .unwrap();
+    ipmi.send_recv(SetSolConfigurationParameter::new(
+        channel,
+        SolParameter::VolatileBitRate(BitRate::B115200),
+    ))
+    .unwrap();
+
+    let request = GetSolConfigurationParameter::new(channel, SolParameterSelector::VolatileBitRate);
+    let selector = request.selector();
+    let raw = ipmi.send_recv(request).unwrap();
+    drop(ipmi);
+
+    assert_eq!(
+        Some(SolParameter::VolatileBitRate(BitRate::B115200)),
+        raw.parse(selector)
+    );
+    assert_eq!(vec![vec![0x01, 0x01, 0x01], vec![0x01, 0x06, 0x0A]], sent);
+}
+
+#[test]
+fn write_accumulates_characters() {
+    let mut stream = SolStream::new(Bmc::default(), config());
+
+    stream.write_all(b"root").unwrap();
+    assert!(stream.get_ref().sent.is_empty());
+
+    stream.write_all(b"\r\nls -l\r\n").unwrap();
+    stream.flush().unwrap();
+
+    let bmc = stream.into_inner();
+    assert_eq!(b"root\r\nls -l\r\n", bmc.console.as_slice());
+
+    let sequences: Vec<_> = bmc.sent.iter().map(|p| p.sequence).collect();
+    assert_eq!(vec![1, 2], sequences);
+    assert_eq!(b"root\r\nls", bmc.sent[0].data.as_slice());
+}
+
+#[test]
+fn partially_accepted_characters_are_resent() {
+    let bmc = Bmc {
+        accept: Some(3),
+        ..Default::default()
+    };
+    let mut stream = SolStream::new(bmc, config());
+
+    stream.write_all(b"reboot\r").unwrap();
+    stream.flush().unwrap();
+
+    let bmc = stream.into_inner();
+    assert_eq!(b"reboot\r", bmc.console.as_slice());
+
+    let data: Vec<_> = bmc.sent.iter().map(|p| p.data.as_slice()).collect();
+    assert_eq!(vec![&b"reboot\r"[..], b"oot\r", b"\r"], data);
+}
+
+#[test]
+fn unacknowledged_packets_are_retried() {
+    let bmc = Bmc {
+        drop_packets: 2,
+        ..Default::default()
+    };
+    let retry_immediately = SolStreamConfig {
+        retry_interval: Duration::ZERO,
+        ..config()
+    };
+    let mut stream = SolStream::new(bmc, retry_immediately);
+
+    stream.write_all(b"x").unwrap();
+    stream.flush().unwrap();
+
+    let bmc = stream.into_inner();
+    assert_eq!(b"x", bmc.console.as_slice());
+    assert_eq!(3, bmc.sent.len());
+    assert!(bmc.sent.iter().all(|p| p.sequence == 1));
+
+    let bmc = Bmc {
+        drop_packets: usize::MAX,
+        ..Default::default()
+    };
+    let retry_immediately = SolStreamConfig {
+        retry_interval: Duration::ZERO,
+        ..config()
+    };
+    let mut stream = SolStream::new(bmc, retry_immediately);
+
+    stream.write_all(b"x").unwrap();
+    let error = stream.flush().unwrap_err();
+    assert_eq!(io::ErrorKind::TimedOut, error.kind());
+}
+
+#[test]
+fn read_acknowledges_packets() {
+    let mut bmc = Bmc::default();
+    for sequence in [1, 1, 2] {
+        bmc.incoming.push_back(SolPacket {
+            sequence,
+            data: format!("line {sequence}\n").into_bytes(),
+            ..Default::default()
+        });
+    }
+
+    let mut stream = SolStream::new(bmc, config());
+    stream.set_read_timeout(Some(Duration::from_millis(100)));
+
+    let mut output = Vec::new();
+    let mut buffer = [0u8; 4];
+    while output.len() < 14 {
+        let len = stream.read(&mut buffer).unwrap();
+        output.extend_from_slice(&buffer[..len]);
+    }
+
+    // The repeated packet is acknowledged again, but its characters
+    // are only read once.
+    assert_eq!(b"line 1\nline 2\n", output.as_slice());
+
+    let error = stream.read(&mut buffer).unwrap_err();
+    assert_eq!(io::ErrorKind::TimedOut, error.kind());
+
+    let acks: Vec<_> = stream
+        .into_inner()
+        .sent
+        .iter()
+        .map(|p| (p.sequence, p.ack_sequence, p.accepted))
+        .collect();
+    assert_eq!(vec![(0, 1, 7), (0, 1, 7), (0, 2, 7)], acks);
+}
+
+#[test]
+fn breaks() {
+    let mut bmc = Bmc::default();
+    bmc.incoming.push_back(SolPacket {
+        sequence: 1,
+        operation_status: SolStatus::BREAK.bits(),
+        data: b"~".to_vec(),
+        ..Default::default()
+    });
+
+    let mut stream = SolStream::new(bmc, config());
+
+    let mut buffer = [0u8; 1];
+    stream.read_exact(&mut buffer).unwrap();
+    assert!(stream.take_break());
+    assert!(!stream.take_break());
+
+    stream.write_all(b"a").unwrap();
+    stream.send_break().unwrap();
+
+    let sent = stream.into_inner().sent;
+    let last = sent.last().unwrap();
+    assert_eq!(SolOperation::BREAK.bits(), last.operation_status);
+    assert!(last.data.is_empty());
+    assert_eq!(b"a", sent[1].data.as_slice());
+}
+
+#[test]
+fn deactivation_ends_stream() {
+    let mut bmc = Bmc::default();
+    bmc.incoming.push_back(SolPacket {
+        sequence: 1,
+        operation_status: SolStatus::DEACTIVATING.bits(),
+        data: b"bye".to_vec(),
+        ..Default::default()
+    });
+
+    let mut stream = SolStream::new(bmc, config());
+
+    let mut output = String::new();
+    stream.read_to_string(&mut output).unwrap();
+    assert_eq!("bye", output);
+
+    assert!(stream.is_deactivated());
+    let error = stream.write(b"a").unwrap_err();
+    assert_eq!(io::ErrorKind::BrokenPipe, error.kind());
+}
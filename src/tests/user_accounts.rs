@@ -0,0 +1,184 @@
+use crate::{
+    app::{
+        auth::{Channel, PrivilegeLevel},
+        user::{
+            Password, PasswordError, PasswordOperation, PayloadAccess, PayloadAccessChange,
+            SetUserPassword, SetUserPayloadAccess, UserAccount,
+        },
+    },
+    connection::{Message, NetFn, ParseResponseError},
+    IpmiError,
+};
+
+use super::mock::MockConnection;
+
+/// The state of a user as stored by a mock BMC.
+struct MockUser {
+    name: [u8; 16],
+    password: Vec<u8>,
+    enabled: bool,
+    access: [u8; 16],
+}
+
+impl MockUser {
+    fn handle(&mut self, cmd: u8, data: &[u8]) -> (u8, Vec<u8>) {
+        match cmd {
+            // Get User Name
+            0x46 => (0x00, self.name.to_vec()),
+            // Set User Name
+            0x45 => {
+                self.name.copy_from_slice(&data[1..17]);
+                (0x00, Vec::new())
+            }
+            // Set User Password
+            0x47 => match data[1] {
+                0b00 => {
+                    self.enabled = false;
+                    (0x00, Vec::new())
+                }
+                0b01 => {
+                    self.enabled = true;
+                    (0x00, Vec::new())
+                }
+                0b10 => {
+                    self.password = data[2..].to_vec();
+                    (0x00, Vec::new())
+                }
+                _ if data[2..].len() != self.password.len() => (0x81, Vec::new()),
+                _ if data[2..] != self.password[..] => (0x80, Vec::new()),
+                _ => (0x00, Vec::new()),
+            },
+            // Get User Access
+            0x44 => {
+                let status = if self.enabled { 0x40 } else { 0x80 };
+                let access = self.access[(data[0] & 0xF) as usize];
+                (0x00, vec![0x10, status | 0x02, 0x01, access])
+            }
+            // Set User Access
+            0x43 => {
+                self.access[(data[0] & 0xF) as usize] = data[0] & 0x70 | data[2];
+                (0x00, Vec::new())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn account() -> UserAccount {
+    UserAccount {
+        user_id: 3,
+        name: "provision".into(),
+        password: Password::new(b"hunter2").unwrap(),
+        privilege: PrivilegeLevel::Administrator,
+        channels: vec![Channel::Number(1), Channel::Number(8)],
+    }
+}
+
+#[test]
+fn ensure_new_user() {
+    let mut user = MockUser {
+        name: [0; 16],
+        password: vec![0; 20],
+        enabled: false,
+        access: [0x0F; 16],
+    };
+
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!(request.netfn(), NetFn::App);
+        user.handle(request.cmd(), request.data())
+    });
+
+    let changes = account().ensure(&mut ipmi).unwrap();
+    assert!(changes.name && changes.password && changes.enabled);
+    assert_eq!(
+        vec![Channel::Number(1), Channel::Number(8)],
+        changes.channels
+    );
+
+    // Applying the same account again does not change anything.
+    let changes = account().ensure(&mut ipmi).unwrap();
+    assert!(!changes.any());
+    drop(ipmi);
+
+    assert_eq!(b"provision\0", &user.name[..10]);
+    assert_eq!(&b"hunter2\0"[..], &user.password[..8]);
+    assert_eq!(16, user.password.len());
+    assert!(user.enabled);
+    // IPMI messaging, administrator.
+    assert_eq!(0x14, user.access[1]);
+    assert_eq!(0x14, user.access[8]);
+    assert_eq!(0x0F, user.access[2]);
+}
+
+#[test]
+fn ensure_unspecified_enable_status() {
+    let mut user = MockUser {
+        name: [0; 16],
+        password: vec![0; 20],
+        enabled: false,
+        access: [0x0F; 16],
+    };
+
+    let mut ipmi = MockConnection::ipmi(|request| {
+        let (cc, mut data) = user.handle(request.cmd(), request.data());
+        if request.cmd() == 0x44 {
+            // The BMC does not report the enable status.
+            data[1] &= 0x3F;
+        }
+        (cc, data)
+    });
+
+    let changes = account().ensure(&mut ipmi).unwrap();
+    assert!(changes.name && changes.password && !changes.enabled);
+    drop(ipmi);
+
+    assert!(!user.enabled);
+}
+
+#[test]
+fn name_too_long() {
+    let mut ipmi = MockConnection::ipmi(|_| unreachable!());
+
+    let account = UserAccount {
+        name: "a-very-long-user-name".into(),
+        ..account()
+    };
+
+    assert!(account.ensure(&mut ipmi).is_err());
+}
+
+#[test]
+fn password_test_failure() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x47));
+        // 20-byte password of user 2
+        assert_eq!(&[0x82, 0b11], &request.data()[..2]);
+        assert_eq!(22, request.data().len());
+        (0x80, Vec::new())
+    });
+
+    let password = Password::long(b"secret").unwrap();
+    let result = ipmi.send_recv(SetUserPassword::new(2, PasswordOperation::Test(password)));
+
+    assert!(matches!(
+        result,
+        Err(IpmiError::ParsingFailed {
+            error: ParseResponseError::Parse(PasswordError::TestFailed),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn payload_access() {
+    let request = SetUserPayloadAccess::new(
+        Channel::Number(1),
+        3,
+        PayloadAccessChange::Disable,
+        PayloadAccess::sol(),
+    );
+
+    let message: Message = request.into();
+    assert_eq!(message.cmd(), 0x4C);
+    assert_eq!(message.data(), &[0x01, 0x43, 0x02, 0x00, 0x00, 0x00]);
+}
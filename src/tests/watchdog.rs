@@ -0,0 +1,137 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    app::watchdog::{
+        ExpirationFlags, GetWatchdogTimer, PreTimeoutInterrupt, TimeoutAction, TimerUse,
+        WatchdogPetter, WatchdogTimer,
+    },
+    connection::NetFn,
+};
+
+use super::mock::MockConnection;
+
+#[test]
+fn get_watchdog_timer() {
+    let mut ipmi = MockConnection::ipmi(|request| {
+        assert_eq!((request.netfn(), request.cmd()), (NetFn::App, 0x25));
+        (0x00, vec![0x44, 0x21, 0x05, 0x10, 0x58, 0x02, 0x2C, 0x01])
+    });
+
+    let status = ipmi.send_recv(GetWatchdogTimer).unwrap();
+
+    assert!(status.running);
+    assert_eq!(ExpirationFlags::SMS_OS, status.expiration_flags);
+    assert_eq!(300, status.present_countdown);
+    assert_eq!(
+        WatchdogTimer {
+            timer_use: TimerUse::SmsOs,
+            dont_log: false,
+            pre_timeout_interrupt: PreTimeoutInterrupt::Nmi,
+            timeout_action: TimeoutAction::HardReset,
+            pre_timeout_interval: 5,
+            initial_countdown: 600,
+        },
+        status.timer
+    );
+    assert_eq!(Duration::from_secs(60), status.timer.timeout());
+}
+
+#[test]
+fn pet_and_disarm() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+
+    let requests = sent.clone();
+    let ipmi = MockConnection::ipmi(move |request| {
+        assert_eq!(request.netfn(), NetFn::App);
+        let mut requests = requests.lock().unwrap();
+        requests.push((request.cmd(), request.data().to_vec()));
+        (0x00, Vec::new())
+    });
+
+    let timer = WatchdogTimer::new(
+        TimerUse::SmsOs,
+        TimeoutAction::HardReset,
+        Duration::from_secs(30),
+    );
+
+    let petter = WatchdogPetter::start(ipmi, timer, Duration::from_millis(5)).unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+    petter.stop().unwrap();
+
+    let sent = sent.lock().unwrap();
+
+    // Configure the timer, clearing all expiration flags, and start it.
+    assert_eq!((0x24, vec![0x04, 0x01, 0x00, 0x3E, 0x2C, 0x01]), sent[0]);
+    assert_eq!((0x22, vec![]), sent[1]);
+
+    // Pet it at least once more.
+    let resets = sent.iter().filter(|(cmd, _)| *cmd == 0x22).count();
+    assert!(resets > 1);
+
+    // Stop the timer and remove the timeout action.
+    assert_eq!(
+        Some(&(0x24, vec![0x04, 0x00, 0x00, 0x00, 0x2C, 0x01])),
+        sent.last()
+    );
+}
+
+#[test]
+fn drop_disarms() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+
+    let requests = sent.clone();
+    let ipmi = MockConnection::ipmi(move |request| {
+        let mut requests = requests.lock().unwrap();
+        requests.push((request.cmd(), request.data().to_vec()));
+        (0x00, Vec::new())
+    });
+
+    let timer = WatchdogTimer::new(
+        TimerUse::SmsOs,
+        TimeoutAction::HardReset,
+        Duration::from_secs(30),
+    );
+
+    drop(WatchdogPetter::start(ipmi, timer, Duration::from_millis(5)).unwrap());
+
+    let sent = sent.lock().unwrap();
+    assert_eq!(
+        Some(&(0x24, vec![0x04, 0x00, 0x00, 0x00, 0x2C, 0x01])),
+        sent.last()
+    );
+}
+
+#[test]
+fn rearm_uninitialized_timer() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+
+    let requests = sent.clone();
+    let ipmi = MockConnection::ipmi(move |request| {
+        let mut requests = requests.lock().unwrap();
+        requests.push(request.cmd());
+
+        // The timer is reconfigured by someone else after the first reset.
+        let resets = requests.iter().filter(|cmd| **cmd == 0x22).count();
+        if request.cmd() == 0x22 && resets == 2 {
+            (0x80, Vec::new())
+        } else {
+            (0x00, Vec::new())
+        }
+    });
+
+    let timer = WatchdogTimer::new(
+        TimerUse::SmsOs,
+        TimeoutAction::HardReset,
+        Duration::from_secs(30),
+    );
+
+    let petter = WatchdogPetter::start(ipmi, timer, Duration::from_millis(5)).unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+    petter.stop().unwrap();
+
+    let sent = sent.lock().unwrap();
+    assert_eq!(&[0x24, 0x22, 0x22, 0x24, 0x22], &sent[..5]);
+}
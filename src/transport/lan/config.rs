@@ -0,0 +1,55 @@
+use crate::{
+    app::auth::Channel,
+    connection::{
+        ConfigurationParameter, GetConfigurationParameter, NetFn, RawParameter,
+        SetConfigurationParameter,
+    },
+};
+
+use super::{LanParameter, LanParameterSelector};
+
+impl ConfigurationParameter for LanParameter {
+    type Selector = LanParameterSelector;
+
+    const NETFN: NetFn = NetFn::Transport;
+    const GET_CMD: u8 = 0x02;
+    const SET_CMD: u8 = 0x01;
+
+    fn selector(&self) -> Self::Selector {
+        LanParameter::selector(self)
+    }
+
+    fn parse(selector: Self::Selector, data: &[u8]) -> Option<Self> {
+        LanParameter::parse(selector, data)
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        LanParameter::write_data(self, buffer)
+    }
+}
+
+/// The data of a LAN configuration parameter.
+pub type RawLanParameter = RawParameter<LanParameter>;
+
+/// Get a LAN configuration parameter of a channel.
+pub type GetLanConfigurationParameter = GetConfigurationParameter<LanParameter>;
+
+impl GetConfigurationParameter<LanParameter> {
+    /// Get the parameter selected by `selector`.
+    ///
+    /// `set_selector` selects the entry of parameters that have
+    /// multiple sets, such as alert destinations and IPv6 addresses,
+    /// and should be `0` for other parameters.
+    pub fn new(channel: Channel, selector: LanParameterSelector, set_selector: u8) -> Self {
+        Self::request(Some(channel.into()), selector, set_selector, 0)
+    }
+}
+
+/// Set a LAN configuration parameter of a channel.
+pub type SetLanConfigurationParameter = SetConfigurationParameter<LanParameter>;
+
+impl SetConfigurationParameter<LanParameter> {
+    pub fn new(channel: Channel, parameter: LanParameter) -> Self {
+        Self::request(Some(channel.into()), parameter)
+    }
+}
@@ -0,0 +1,24 @@
+//! LAN configuration parameters.
+
+mod parameter;
+pub use parameter::{
+    AddressingMode, AlertDestinationAddress, AlertDestinationType, AuthTypeEnables,
+    DestinationKind, IpAddressSource, Ipv6AddressEntry, Ipv6AddressSource, Ipv6AddressStatus,
+    Ipv6RouterConfig, Ipv6Status, Ipv6Support, LanParameter, LanParameterSelector, StaticRouter,
+    Vlan,
+};
+
+mod config;
+pub use config::{GetLanConfigurationParameter, RawLanParameter, SetLanConfigurationParameter};
+
+bitflags::bitflags! {
+    /// A set of authentication types.
+    #[derive(Default)]
+    pub struct AuthTypes: u8 {
+        const OEM = 1 << 5;
+        const KEY = 1 << 4;
+        const MD5 = 1 << 2;
+        const MD2 = 1 << 1;
+        const NONE = 1 << 0;
+    }
+}
@@ -0,0 +1,965 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{app::auth::PrivilegeLevel, sensor_event::pef::SetInProgress};
+
+use super::AuthTypes;
+
+/// One of the two static IPv6 routers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StaticRouter {
+    One,
+    Two,
+}
+
+impl StaticRouter {
+    /// The offset of the parameters of this router from those of router 1.
+    fn offset(&self) -> u8 {
+        match self {
+            StaticRouter::One => 0,
+            StaticRouter::Two => 4,
+        }
+    }
+}
+
+/// The selector of a LAN configuration parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanParameterSelector {
+    SetInProgress,
+    AuthTypeSupport,
+    AuthTypeEnables,
+    IpAddress,
+    IpAddressSource,
+    MacAddress,
+    SubnetMask,
+    DefaultGateway,
+    DefaultGatewayMac,
+    BackupGateway,
+    BackupGatewayMac,
+    CommunityString,
+    DestinationCount,
+    DestinationType,
+    DestinationAddress,
+    Vlan,
+    VlanPriority,
+    CipherSuiteEntryCount,
+    CipherSuiteEntries,
+    CipherSuitePrivileges,
+    Ipv6Support,
+    AddressingMode,
+    Ipv6Status,
+    Ipv6StaticAddress,
+    Ipv6DynamicAddress,
+    Ipv6RouterConfig,
+    Ipv6StaticRouterAddress(StaticRouter),
+    Ipv6StaticRouterMac(StaticRouter),
+    Ipv6StaticRouterPrefixLength(StaticRouter),
+    Ipv6StaticRouterPrefix(StaticRouter),
+    /// A parameter that is not supported by this library.
+    Other(u8),
+}
+
+impl From<u8> for LanParameterSelector {
+    fn from(value: u8) -> Self {
+        let router = |v: u8| {
+            if v < 69 {
+                StaticRouter::One
+            } else {
+                StaticRouter::Two
+            }
+        };
+
+        match value {
+            0 => Self::SetInProgress,
+            1 => Self::AuthTypeSupport,
+            2 => Self::AuthTypeEnables,
+            3 => Self::IpAddress,
+            4 => Self::IpAddressSource,
+            5 => Self::MacAddress,
+            6 => Self::SubnetMask,
+            12 => Self::DefaultGateway,
+            13 => Self::DefaultGatewayMac,
+            14 => Self::BackupGateway,
+            15 => Self::BackupGatewayMac,
+            16 => Self::CommunityString,
+            17 => Self::DestinationCount,
+            18 => Self::DestinationType,
+            19 => Self::DestinationAddress,
+            20 => Self::Vlan,
+            21 => Self::VlanPriority,
+            22 => Self::CipherSuiteEntryCount,
+            23 => Self::CipherSuiteEntries,
+            24 => Self::CipherSuitePrivileges,
+            50 => Self::Ipv6Support,
+            51 => Self::AddressingMode,
+            55 => Self::Ipv6Status,
+            56 => Self::Ipv6StaticAddress,
+            59 => Self::Ipv6DynamicAddress,
+            64 => Self::Ipv6RouterConfig,
+            65 | 69 => Self::Ipv6StaticRouterAddress(router(value)),
+            66 | 70 => Self::Ipv6StaticRouterMac(router(value)),
+            67 | 71 => Self::Ipv6StaticRouterPrefixLength(router(value)),
+            68 | 72 => Self::Ipv6StaticRouterPrefix(router(value)),
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<LanParameterSelector> for u8 {
+    fn from(value: LanParameterSelector) -> Self {
+        type S = LanParameterSelector;
+
+        match value {
+            S::SetInProgress => 0,
+            S::AuthTypeSupport => 1,
+            S::AuthTypeEnables => 2,
+            S::IpAddress => 3,
+            S::IpAddressSource => 4,
+            S::MacAddress => 5,
+            S::SubnetMask => 6,
+            S::DefaultGateway => 12,
+            S::DefaultGatewayMac => 13,
+            S::BackupGateway => 14,
+            S::BackupGatewayMac => 15,
+            S::CommunityString => 16,
+            S::DestinationCount => 17,
+            S::DestinationType => 18,
+            S::DestinationAddress => 19,
+            S::Vlan => 20,
+            S::VlanPriority => 21,
+            S::CipherSuiteEntryCount => 22,
+            S::CipherSuiteEntries => 23,
+            S::CipherSuitePrivileges => 24,
+            S::Ipv6Support => 50,
+            S::AddressingMode => 51,
+            S::Ipv6Status => 55,
+            S::Ipv6StaticAddress => 56,
+            S::Ipv6DynamicAddress => 59,
+            S::Ipv6RouterConfig => 64,
+            S::Ipv6StaticRouterAddress(r) => 65 + r.offset(),
+            S::Ipv6StaticRouterMac(r) => 66 + r.offset(),
+            S::Ipv6StaticRouterPrefixLength(r) => 67 + r.offset(),
+            S::Ipv6StaticRouterPrefix(r) => 68 + r.offset(),
+            S::Other(v) => v,
+        }
+    }
+}
+
+/// The authentication types that are enabled for each privilege level.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AuthTypeEnables {
+    pub callback: AuthTypes,
+    pub user: AuthTypes,
+    pub operator: AuthTypes,
+    pub administrator: AuthTypes,
+    pub oem: AuthTypes,
+}
+
+impl AuthTypeEnables {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 5 {
+            return None;
+        }
+
+        let types = AuthTypes::from_bits_truncate;
+
+        Some(Self {
+            callback: types(data[0]),
+            user: types(data[1]),
+            operator: types(data[2]),
+            administrator: types(data[3]),
+            oem: types(data[4]),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.callback.bits(),
+            self.user.bits(),
+            self.operator.bits(),
+            self.administrator.bits(),
+            self.oem.bits(),
+        ]);
+    }
+}
+
+/// The source of the IPv4 address of a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpAddressSource {
+    Unspecified,
+    Static,
+    Dhcp,
+    /// The address is loaded by the BIOS or system software.
+    Bios,
+    /// The address is obtained by another protocol.
+    Other,
+    Reserved(u8),
+}
+
+impl From<u8> for IpAddressSource {
+    fn from(value: u8) -> Self {
+        match value & 0xF {
+            0 => Self::Unspecified,
+            1 => Self::Static,
+            2 => Self::Dhcp,
+            3 => Self::Bios,
+            4 => Self::Other,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<IpAddressSource> for u8 {
+    fn from(value: IpAddressSource) -> Self {
+        match value {
+            IpAddressSource::Unspecified => 0,
+            IpAddressSource::Static => 1,
+            IpAddressSource::Dhcp => 2,
+            IpAddressSource::Bios => 3,
+            IpAddressSource::Other => 4,
+            IpAddressSource::Reserved(v) => v & 0xF,
+        }
+    }
+}
+
+/// The kind of an alert destination.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DestinationKind {
+    /// A Platform Event Trap destination.
+    PetTrap,
+    Oem1,
+    Oem2,
+    Reserved(u8),
+}
+
+impl From<u8> for DestinationKind {
+    fn from(value: u8) -> Self {
+        match value & 0x7 {
+            0b000 => Self::PetTrap,
+            0b110 => Self::Oem1,
+            0b111 => Self::Oem2,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<DestinationKind> for u8 {
+    fn from(value: DestinationKind) -> Self {
+        match value {
+            DestinationKind::PetTrap => 0b000,
+            DestinationKind::Oem1 => 0b110,
+            DestinationKind::Oem2 => 0b111,
+            DestinationKind::Reserved(v) => v & 0x7,
+        }
+    }
+}
+
+/// The type of an alert destination.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertDestinationType {
+    /// The destination selector. Destination `0` is the volatile destination.
+    pub destination: u8,
+    /// Whether alerts to this destination must be acknowledged.
+    pub acknowledge: bool,
+    pub kind: DestinationKind,
+    /// The alert acknowledge timeout, or the retry interval
+    /// if `acknowledge` is not set, in seconds.
+    pub timeout: u8,
+    pub retries: u8,
+}
+
+impl AlertDestinationType {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 4 {
+            return None;
+        }
+
+        Some(Self {
+            destination: data[0] & 0xF,
+            acknowledge: (data[1] & 0x80) == 0x80,
+            kind: DestinationKind::from(data[1]),
+            timeout: data[2],
+            retries: data[3] & 0x7,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.destination & 0xF,
+            (self.acknowledge as u8) << 7 | u8::from(self.kind),
+            self.timeout,
+            self.retries & 0x7,
+        ]);
+    }
+}
+
+/// The address of an alert destination.
+///
+/// Only the IPv4 address format is supported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertDestinationAddress {
+    /// The destination selector. Destination `0` is the volatile destination.
+    pub destination: u8,
+    /// Send alerts through the backup gateway instead of the default gateway.
+    pub use_backup_gateway: bool,
+    pub address: Ipv4Addr,
+    pub mac: [u8; 6],
+}
+
+impl AlertDestinationAddress {
+    fn parse(data: &[u8]) -> Option<Self> {
+        // Address formats other than IPv4 use a different layout.
+        if data.len() < 13 || (data[1] >> 4) != 0 {
+            return None;
+        }
+
+        Some(Self {
+            destination: data[0] & 0xF,
+            use_backup_gateway: (data[2] & 0x01) == 0x01,
+            address: ipv4(&data[3..7]),
+            mac: data[7..13].try_into().unwrap(),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[self.destination & 0xF, 0x00, self.use_backup_gateway as u8]);
+        buffer.extend_from_slice(&self.address.octets());
+        buffer.extend_from_slice(&self.mac);
+    }
+}
+
+/// The 802.1q VLAN configuration of a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vlan {
+    pub enabled: bool,
+    /// The 12-bit VLAN ID.
+    pub id: u16,
+}
+
+impl Vlan {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            enabled: (data[1] & 0x80) == 0x80,
+            id: u16::from_le_bytes([data[0], data[1] & 0xF]),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        let [lsb, msb] = self.id.to_le_bytes();
+        buffer.extend_from_slice(&[lsb, (self.enabled as u8) << 7 | (msb & 0xF)]);
+    }
+}
+
+/// The IP versions that a LAN channel supports.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ipv6Support {
+    pub ipv6_alerting: bool,
+    /// Both IPv4 and IPv6 can be used at the same time.
+    pub dual_stack: bool,
+    /// The channel can be configured to only use IPv6.
+    pub ipv6_only: bool,
+}
+
+impl From<u8> for Ipv6Support {
+    fn from(value: u8) -> Self {
+        Self {
+            ipv6_alerting: (value & 0x04) == 0x04,
+            dual_stack: (value & 0x02) == 0x02,
+            ipv6_only: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+impl From<Ipv6Support> for u8 {
+    fn from(value: Ipv6Support) -> Self {
+        (value.ipv6_alerting as u8) << 2 | (value.dual_stack as u8) << 1 | value.ipv6_only as u8
+    }
+}
+
+/// The IP versions that are enabled on a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AddressingMode {
+    Ipv4Only,
+    Ipv6Only,
+    Both,
+    Reserved(u8),
+}
+
+impl From<u8> for AddressingMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Ipv4Only,
+            1 => Self::Ipv6Only,
+            2 => Self::Both,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<AddressingMode> for u8 {
+    fn from(value: AddressingMode) -> Self {
+        match value {
+            AddressingMode::Ipv4Only => 0,
+            AddressingMode::Ipv6Only => 1,
+            AddressingMode::Both => 2,
+            AddressingMode::Reserved(v) => v,
+        }
+    }
+}
+
+/// The IPv6 capabilities of a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ipv6Status {
+    /// The number of static IPv6 addresses.
+    pub static_addresses: u8,
+    /// The number of dynamic IPv6 addresses.
+    pub dynamic_addresses: u8,
+    pub dhcpv6: bool,
+    pub slaac: bool,
+}
+
+impl Ipv6Status {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 3 {
+            return None;
+        }
+
+        Some(Self {
+            static_addresses: data[0],
+            dynamic_addresses: data[1],
+            dhcpv6: (data[2] & 0x02) == 0x02,
+            slaac: (data[2] & 0x01) == 0x01,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.static_addresses,
+            self.dynamic_addresses,
+            (self.dhcpv6 as u8) << 1 | self.slaac as u8,
+        ]);
+    }
+}
+
+/// The source of an IPv6 address.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ipv6AddressSource {
+    Static,
+    Slaac,
+    Dhcpv6,
+    Reserved(u8),
+}
+
+impl From<u8> for Ipv6AddressSource {
+    fn from(value: u8) -> Self {
+        match value & 0xF {
+            0 => Self::Static,
+            1 => Self::Slaac,
+            2 => Self::Dhcpv6,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<Ipv6AddressSource> for u8 {
+    fn from(value: Ipv6AddressSource) -> Self {
+        match value {
+            Ipv6AddressSource::Static => 0,
+            Ipv6AddressSource::Slaac => 1,
+            Ipv6AddressSource::Dhcpv6 => 2,
+            Ipv6AddressSource::Reserved(v) => v & 0xF,
+        }
+    }
+}
+
+/// The status of an IPv6 address.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ipv6AddressStatus {
+    Active,
+    Disabled,
+    Pending,
+    Failed,
+    Deprecated,
+    Invalid,
+    Reserved(u8),
+}
+
+impl From<u8> for Ipv6AddressStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Active,
+            1 => Self::Disabled,
+            2 => Self::Pending,
+            3 => Self::Failed,
+            4 => Self::Deprecated,
+            5 => Self::Invalid,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<Ipv6AddressStatus> for u8 {
+    fn from(value: Ipv6AddressStatus) -> Self {
+        match value {
+            Ipv6AddressStatus::Active => 0,
+            Ipv6AddressStatus::Disabled => 1,
+            Ipv6AddressStatus::Pending => 2,
+            Ipv6AddressStatus::Failed => 3,
+            Ipv6AddressStatus::Deprecated => 4,
+            Ipv6AddressStatus::Invalid => 5,
+            Ipv6AddressStatus::Reserved(v) => v,
+        }
+    }
+}
+
+/// A static or dynamic IPv6 address of a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ipv6AddressEntry {
+    /// The set selector of the address.
+    pub set: u8,
+    pub enabled: bool,
+    pub source: Ipv6AddressSource,
+    pub address: Ipv6Addr,
+    pub prefix_length: u8,
+    /// The status of the address. This is ignored when setting
+    /// a static address.
+    pub status: Ipv6AddressStatus,
+}
+
+impl Ipv6AddressEntry {
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 20 {
+            return None;
+        }
+
+        Some(Self {
+            set: data[0],
+            enabled: (data[1] & 0x80) == 0x80,
+            source: Ipv6AddressSource::from(data[1]),
+            address: ipv6(&data[2..18]),
+            prefix_length: data[18],
+            status: Ipv6AddressStatus::from(data[19]),
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[self.set, (self.enabled as u8) << 7 | u8::from(self.source)]);
+        buffer.extend_from_slice(&self.address.octets());
+        buffer.extend_from_slice(&[self.prefix_length, self.status.into()]);
+    }
+}
+
+/// The IPv6 router configuration of a LAN channel.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ipv6RouterConfig {
+    /// Learn routers using router advertisements.
+    pub dynamic_enabled: bool,
+    /// Use the static routers.
+    pub static_enabled: bool,
+}
+
+impl From<u8> for Ipv6RouterConfig {
+    fn from(value: u8) -> Self {
+        Self {
+            dynamic_enabled: (value & 0x02) == 0x02,
+            static_enabled: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+impl From<Ipv6RouterConfig> for u8 {
+    fn from(value: Ipv6RouterConfig) -> Self {
+        (value.dynamic_enabled as u8) << 1 | value.static_enabled as u8
+    }
+}
+
+fn ipv4(data: &[u8]) -> Ipv4Addr {
+    Ipv4Addr::new(data[0], data[1], data[2], data[3])
+}
+
+fn ipv6(data: &[u8]) -> Ipv6Addr {
+    let octets: [u8; 16] = data[..16].try_into().unwrap();
+    Ipv6Addr::from(octets)
+}
+
+/// A LAN configuration parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LanParameter {
+    SetInProgress(SetInProgress),
+    /// The supported authentication types. Read only.
+    AuthTypeSupport(AuthTypes),
+    AuthTypeEnables(AuthTypeEnables),
+    IpAddress(Ipv4Addr),
+    IpAddressSource(IpAddressSource),
+    MacAddress([u8; 6]),
+    SubnetMask(Ipv4Addr),
+    DefaultGateway(Ipv4Addr),
+    DefaultGatewayMac([u8; 6]),
+    BackupGateway(Ipv4Addr),
+    BackupGatewayMac([u8; 6]),
+    /// The SNMP community string used in traps, of up to 18 bytes.
+    CommunityString(String),
+    /// The number of non-volatile alert destinations. Read only.
+    DestinationCount(u8),
+    DestinationType(AlertDestinationType),
+    DestinationAddress(AlertDestinationAddress),
+    Vlan(Vlan),
+    /// The 802.1q VLAN priority, `0` through `7`.
+    VlanPriority(u8),
+    /// The number of supported cipher suites. Read only.
+    CipherSuiteEntryCount(u8),
+    /// The IDs of the supported cipher suites. Read only.
+    CipherSuiteEntries(Vec<u8>),
+    /// The maximum privilege level that can be used with each of the 16
+    /// cipher suite entries. `None` marks a reserved or unused entry.
+    CipherSuitePrivileges([Option<PrivilegeLevel>; 16]),
+    /// Read only.
+    Ipv6Support(Ipv6Support),
+    AddressingMode(AddressingMode),
+    /// Read only.
+    Ipv6Status(Ipv6Status),
+    Ipv6StaticAddress(Ipv6AddressEntry),
+    /// Read only.
+    Ipv6DynamicAddress(Ipv6AddressEntry),
+    Ipv6RouterConfig(Ipv6RouterConfig),
+    Ipv6StaticRouterAddress(StaticRouter, Ipv6Addr),
+    Ipv6StaticRouterMac(StaticRouter, [u8; 6]),
+    Ipv6StaticRouterPrefixLength(StaticRouter, u8),
+    Ipv6StaticRouterPrefix(StaticRouter, Ipv6Addr),
+    /// A parameter that is not supported by this library.
+    ///
+    /// `selector` must not be the selector of one of the supported
+    /// parameters.
+    Other {
+        selector: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl LanParameter {
+    /// The maximum length of [`LanParameter::CommunityString`].
+    pub const COMMUNITY_STRING_LEN: usize = 18;
+
+    pub fn selector(&self) -> LanParameterSelector {
+        type S = LanParameterSelector;
+
+        match self {
+            Self::SetInProgress(_) => S::SetInProgress,
+            Self::AuthTypeSupport(_) => S::AuthTypeSupport,
+            Self::AuthTypeEnables(_) => S::AuthTypeEnables,
+            Self::IpAddress(_) => S::IpAddress,
+            Self::IpAddressSource(_) => S::IpAddressSource,
+            Self::MacAddress(_) => S::MacAddress,
+            Self::SubnetMask(_) => S::SubnetMask,
+            Self::DefaultGateway(_) => S::DefaultGateway,
+            Self::DefaultGatewayMac(_) => S::DefaultGatewayMac,
+            Self::BackupGateway(_) => S::BackupGateway,
+            Self::BackupGatewayMac(_) => S::BackupGatewayMac,
+            Self::CommunityString(_) => S::CommunityString,
+            Self::DestinationCount(_) => S::DestinationCount,
+            Self::DestinationType(_) => S::DestinationType,
+            Self::DestinationAddress(_) => S::DestinationAddress,
+            Self::Vlan(_) => S::Vlan,
+            Self::VlanPriority(_) => S::VlanPriority,
+            Self::CipherSuiteEntryCount(_) => S::CipherSuiteEntryCount,
+            Self::CipherSuiteEntries(_) => S::CipherSuiteEntries,
+            Self::CipherSuitePrivileges(_) => S::CipherSuitePrivileges,
+            Self::Ipv6Support(_) => S::Ipv6Support,
+            Self::AddressingMode(_) => S::AddressingMode,
+            Self::Ipv6Status(_) => S::Ipv6Status,
+            Self::Ipv6StaticAddress(_) => S::Ipv6StaticAddress,
+            Self::Ipv6DynamicAddress(_) => S::Ipv6DynamicAddress,
+            Self::Ipv6RouterConfig(_) => S::Ipv6RouterConfig,
+            Self::Ipv6StaticRouterAddress(r, _) => S::Ipv6StaticRouterAddress(*r),
+            Self::Ipv6StaticRouterMac(r, _) => S::Ipv6StaticRouterMac(*r),
+            Self::Ipv6StaticRouterPrefixLength(r, _) => S::Ipv6StaticRouterPrefixLength(*r),
+            Self::Ipv6StaticRouterPrefix(r, _) => S::Ipv6StaticRouterPrefix(*r),
+            Self::Other { selector, .. } => S::Other(*selector),
+        }
+    }
+
+    /// Parse the data of the parameter selected by `selector`.
+    ///
+    /// Returns `None` if `data` is too short, or if it uses a format
+    /// that is not supported.
+    pub fn parse(selector: LanParameterSelector, data: &[u8]) -> Option<Self> {
+        type S = LanParameterSelector;
+
+        let first = data.first().copied();
+        let ipv4 = || (data.len() >= 4).then(|| ipv4(data));
+        let ipv6 = || (data.len() >= 16).then(|| ipv6(data));
+        let mac = || data.get(..6).map(|v| v.try_into().unwrap());
+
+        let value = match selector {
+            S::SetInProgress => Self::SetInProgress(first?.into()),
+            S::AuthTypeSupport => Self::AuthTypeSupport(AuthTypes::from_bits_truncate(first?)),
+            S::AuthTypeEnables => Self::AuthTypeEnables(AuthTypeEnables::parse(data)?),
+            S::IpAddress => Self::IpAddress(ipv4()?),
+            S::IpAddressSource => Self::IpAddressSource(first?.into()),
+            S::MacAddress => Self::MacAddress(mac()?),
+            S::SubnetMask => Self::SubnetMask(ipv4()?),
+            S::DefaultGateway => Self::DefaultGateway(ipv4()?),
+            S::DefaultGatewayMac => Self::DefaultGatewayMac(mac()?),
+            S::BackupGateway => Self::BackupGateway(ipv4()?),
+            S::BackupGatewayMac => Self::BackupGatewayMac(mac()?),
+            S::CommunityString => {
+                let data = data.get(..Self::COMMUNITY_STRING_LEN)?;
+                let len = data.iter().position(|v| *v == 0).unwrap_or(data.len());
+                Self::CommunityString(String::from_utf8_lossy(&data[..len]).into_owned())
+            }
+            S::DestinationCount => Self::DestinationCount(first? & 0xF),
+            S::DestinationType => Self::DestinationType(AlertDestinationType::parse(data)?),
+            S::DestinationAddress => {
+                Self::DestinationAddress(AlertDestinationAddress::parse(data)?)
+            }
+            S::Vlan => Self::Vlan(Vlan::parse(data)?),
+            S::VlanPriority => Self::VlanPriority(first? & 0x7),
+            S::CipherSuiteEntryCount => Self::CipherSuiteEntryCount(first? & 0x1F),
+            // The first byte is reserved.
+            S::CipherSuiteEntries => Self::CipherSuiteEntries(data.get(1..)?.to_vec()),
+            S::CipherSuitePrivileges => {
+                let levels = data.get(1..9)?;
+                let level = |idx: usize| {
+                    let nibble = levels[idx / 2] >> (4 * (idx & 1));
+                    PrivilegeLevel::try_from(nibble & 0xF).ok()
+                };
+
+                Self::CipherSuitePrivileges(core::array::from_fn(level))
+            }
+            S::Ipv6Support => Self::Ipv6Support(first?.into()),
+            S::AddressingMode => Self::AddressingMode(first?.into()),
+            S::Ipv6Status => Self::Ipv6Status(Ipv6Status::parse(data)?),
+            S::Ipv6StaticAddress => Self::Ipv6StaticAddress(Ipv6AddressEntry::parse(data)?),
+            S::Ipv6DynamicAddress => Self::Ipv6DynamicAddress(Ipv6AddressEntry::parse(data)?),
+            S::Ipv6RouterConfig => Self::Ipv6RouterConfig(first?.into()),
+            S::Ipv6StaticRouterAddress(r) => Self::Ipv6StaticRouterAddress(r, ipv6()?),
+            S::Ipv6StaticRouterMac(r) => Self::Ipv6StaticRouterMac(r, mac()?),
+            S::Ipv6StaticRouterPrefixLength(r) => Self::Ipv6StaticRouterPrefixLength(r, first?),
+            S::Ipv6StaticRouterPrefix(r) => Self::Ipv6StaticRouterPrefix(r, ipv6()?),
+            S::Other(selector) => Self::Other {
+                selector,
+                data: data.to_vec(),
+            },
+        };
+
+        Some(value)
+    }
+
+    /// Write the data of the parameter, excluding the selector.
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::SetInProgress(v) => buffer.push((*v).into()),
+            Self::AuthTypeSupport(v) => buffer.push(v.bits()),
+            Self::AuthTypeEnables(v) => v.write_data(buffer),
+            Self::IpAddress(v)
+            | Self::SubnetMask(v)
+            | Self::DefaultGateway(v)
+            | Self::BackupGateway(v) => buffer.extend_from_slice(&v.octets()),
+            Self::IpAddressSource(v) => buffer.push((*v).into()),
+            Self::MacAddress(v)
+            | Self::DefaultGatewayMac(v)
+            | Self::BackupGatewayMac(v)
+            | Self::Ipv6StaticRouterMac(_, v) => buffer.extend_from_slice(v),
+            Self::CommunityString(v) => {
+                let mut data = [0u8; Self::COMMUNITY_STRING_LEN];
+                let len = v.len().min(data.len());
+                data[..len].copy_from_slice(&v.as_bytes()[..len]);
+                buffer.extend_from_slice(&data);
+            }
+            Self::DestinationCount(v) => buffer.push(v & 0xF),
+            Self::DestinationType(v) => v.write_data(buffer),
+            Self::DestinationAddress(v) => v.write_data(buffer),
+            Self::Vlan(v) => v.write_data(buffer),
+            Self::VlanPriority(v) => buffer.push(v & 0x7),
+            Self::CipherSuiteEntryCount(v) => buffer.push(v & 0x1F),
+            Self::CipherSuiteEntries(v) => {
+                buffer.push(0x00);
+                buffer.extend_from_slice(v);
+            }
+            Self::CipherSuitePrivileges(v) => {
+                let level = |idx: usize| v[idx].map(u8::from).unwrap_or(0);
+
+                buffer.push(0x00);
+                buffer.extend((0..8).map(|i| level(2 * i + 1) << 4 | level(2 * i)));
+            }
+            Self::Ipv6Support(v) => buffer.push((*v).into()),
+            Self::AddressingMode(v) => buffer.push((*v).into()),
+            Self::Ipv6Status(v) => v.write_data(buffer),
+            Self::Ipv6StaticAddress(v) | Self::Ipv6DynamicAddress(v) => v.write_data(buffer),
+            Self::Ipv6RouterConfig(v) => buffer.push((*v).into()),
+            Self::Ipv6StaticRouterAddress(_, v) | Self::Ipv6StaticRouterPrefix(_, v) => {
+                buffer.extend_from_slice(&v.octets())
+            }
+            Self::Ipv6StaticRouterPrefixLength(_, v) => buffer.push(*v),
+            Self::Other { data, .. } => buffer.extend_from_slice(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(parameter: LanParameter) {
+        let mut data = Vec::new();
+        parameter.write_data(&mut data);
+
+        let selector = parameter.selector();
+        assert_eq!(selector, LanParameterSelector::from(u8::from(selector)));
+
+        let parsed = LanParameter::parse(selector, &data);
+        assert_eq!(Some(parameter), parsed);
+    }
+
+    #[test]
+    fn addresses() {
+        round_trip(LanParameter::IpAddressSource(IpAddressSource::Dhcp));
+        round_trip(LanParameter::IpAddress(Ipv4Addr::new(10, 0, 0, 20)));
+        round_trip(LanParameter::SubnetMask(Ipv4Addr::new(255, 255, 255, 0)));
+        round_trip(LanParameter::DefaultGateway(Ipv4Addr::new(10, 0, 0, 1)));
+        round_trip(LanParameter::BackupGateway(Ipv4Addr::new(10, 0, 0, 2)));
+        round_trip(LanParameter::MacAddress([
+            0x00, 0x1B, 0x21, 0x3C, 0x4D, 0x5E,
+        ]));
+        round_trip(LanParameter::DefaultGatewayMac([0x02; 6]));
+        round_trip(LanParameter::BackupGatewayMac([0x04; 6]));
+    }
+
+    #[test]
+    fn vlan() {
+        let vlan = Vlan {
+            enabled: true,
+            id: 0x123,
+        };
+
+        let mut data = Vec::new();
+        LanParameter::Vlan(vlan).write_data(&mut data);
+        assert_eq!(vec![0x23, 0x81], data);
+
+        round_trip(LanParameter::Vlan(vlan));
+        round_trip(LanParameter::VlanPriority(5));
+    }
+
+    #[test]
+    fn authentication() {
+        round_trip(LanParameter::SetInProgress(SetInProgress::InProgress));
+        round_trip(LanParameter::AuthTypeSupport(
+            AuthTypes::MD5 | AuthTypes::KEY | AuthTypes::NONE,
+        ));
+        round_trip(LanParameter::AuthTypeEnables(AuthTypeEnables {
+            callback: AuthTypes::MD5,
+            user: AuthTypes::MD5,
+            operator: AuthTypes::MD5 | AuthTypes::MD2,
+            administrator: AuthTypes::MD5,
+            oem: AuthTypes::empty(),
+        }));
+        round_trip(LanParameter::CommunityString("public".into()));
+    }
+
+    #[test]
+    fn cipher_suites() {
+        round_trip(LanParameter::CipherSuiteEntryCount(4));
+        round_trip(LanParameter::CipherSuiteEntries(vec![0, 1, 2, 3, 17]));
+
+        let mut levels = [None; 16];
+        levels[0] = Some(PrivilegeLevel::Callback);
+        levels[3] = Some(PrivilegeLevel::Administrator);
+        levels[15] = Some(PrivilegeLevel::User);
+
+        let mut data = Vec::new();
+        LanParameter::CipherSuitePrivileges(levels).write_data(&mut data);
+        assert_eq!(vec![0x00, 0x01, 0x40, 0, 0, 0, 0, 0, 0x20], data);
+
+        round_trip(LanParameter::CipherSuitePrivileges(levels));
+    }
+
+    #[test]
+    fn alert_destinations() {
+        round_trip(LanParameter::DestinationCount(4));
+        round_trip(LanParameter::DestinationType(AlertDestinationType {
+            destination: 1,
+            acknowledge: true,
+            kind: DestinationKind::PetTrap,
+            timeout: 3,
+            retries: 2,
+        }));
+        round_trip(LanParameter::DestinationAddress(AlertDestinationAddress {
+            destination: 1,
+            use_backup_gateway: true,
+            address: Ipv4Addr::new(192, 168, 1, 10),
+            mac: [0x10; 6],
+        }));
+
+        // IPv6 destination addresses are not supported.
+        let mut data = vec![0x01, 0x10, 0x00];
+        data.extend_from_slice(&[0; 16]);
+        assert_eq!(
+            None,
+            LanParameter::parse(LanParameterSelector::DestinationAddress, &data)
+        );
+    }
+
+    #[test]
+    fn ipv6() {
+        round_trip(LanParameter::Ipv6Support(Ipv6Support {
+            ipv6_alerting: false,
+            dual_stack: true,
+            ipv6_only: true,
+        }));
+        round_trip(LanParameter::AddressingMode(AddressingMode::Both));
+        round_trip(LanParameter::Ipv6Status(Ipv6Status {
+            static_addresses: 1,
+            dynamic_addresses: 2,
+            dhcpv6: true,
+            slaac: true,
+        }));
+
+        let address = Ipv6AddressEntry {
+            set: 0,
+            enabled: true,
+            source: Ipv6AddressSource::Static,
+            address: "2001:db8::20".parse().unwrap(),
+            prefix_length: 64,
+            status: Ipv6AddressStatus::Active,
+        };
+
+        round_trip(LanParameter::Ipv6StaticAddress(address));
+        round_trip(LanParameter::Ipv6DynamicAddress(Ipv6AddressEntry {
+            set: 1,
+            source: Ipv6AddressSource::Slaac,
+            status: Ipv6AddressStatus::Deprecated,
+            ..address
+        }));
+    }
+
+    #[test]
+    fn ipv6_routing() {
+        round_trip(LanParameter::Ipv6RouterConfig(Ipv6RouterConfig {
+            dynamic_enabled: false,
+            static_enabled: true,
+        }));
+
+        for router in [StaticRouter::One, StaticRouter::Two] {
+            round_trip(LanParameter::Ipv6StaticRouterAddress(
+                router,
+                "fe80::1".parse().unwrap(),
+            ));
+            round_trip(LanParameter::Ipv6StaticRouterMac(router, [0x06; 6]));
+            round_trip(LanParameter::Ipv6StaticRouterPrefixLength(router, 64));
+            round_trip(LanParameter::Ipv6StaticRouterPrefix(
+                router,
+                "2001:db8::".parse().unwrap(),
+            ));
+        }
+
+        assert_eq!(
+            69,
+            u8::from(LanParameterSelector::Ipv6StaticRouterAddress(
+                StaticRouter::Two
+            ))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Commands of the Transport network function.
+
+pub mod lan;
+
+pub mod sol;
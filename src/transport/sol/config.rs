@@ -0,0 +1,50 @@
+use crate::{
+    app::auth::Channel,
+    connection::{
+        ConfigurationParameter, GetConfigurationParameter, NetFn, RawParameter,
+        SetConfigurationParameter,
+    },
+};
+
+use super::{SolParameter, SolParameterSelector};
+
+impl ConfigurationParameter for SolParameter {
+    type Selector = SolParameterSelector;
+
+    const NETFN: NetFn = NetFn::Transport;
+    const GET_CMD: u8 = 0x22;
+    const SET_CMD: u8 = 0x21;
+
+    fn selector(&self) -> Self::Selector {
+        SolParameter::selector(self)
+    }
+
+    fn parse(selector: Self::Selector, data: &[u8]) -> Option<Self> {
+        SolParameter::parse(selector, data)
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        SolParameter::write_data(self, buffer)
+    }
+}
+
+/// The data of a SOL configuration parameter.
+pub type RawSolParameter = RawParameter<SolParameter>;
+
+/// Get a SOL configuration parameter of a channel.
+pub type GetSolConfigurationParameter = GetConfigurationParameter<SolParameter>;
+
+impl GetConfigurationParameter<SolParameter> {
+    pub fn new(channel: Channel, selector: SolParameterSelector) -> Self {
+        Self::request(Some(channel.into()), selector, 0, 0)
+    }
+}
+
+/// Set a SOL configuration parameter of a channel.
+pub type SetSolConfigurationParameter = SetConfigurationParameter<SolParameter>;
+
+impl SetConfigurationParameter<SolParameter> {
+    pub fn new(channel: Channel, parameter: SolParameter) -> Self {
+        Self::request(Some(channel.into()), parameter)
+    }
+}
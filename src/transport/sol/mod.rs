@@ -0,0 +1,22 @@
+//! Serial over LAN (SOL).
+//!
+//! SOL is activated as a payload of an RMCP+ session using
+//! [`ActivatePayload::sol`](crate::app::payload::ActivatePayload::sol),
+//! after which [`SolStream`] exchanges characters with the serial port
+//! of the system over a [`SolTransport`], such as the RMCP+ session
+//! [`Rmcp<ActivePlus>`](crate::connection::rmcp::Rmcp). The payload is
+//! deactivated when that session is closed or dropped.
+
+mod parameter;
+pub use parameter::{
+    BitRate, CharacterAccumulation, SolAuthentication, SolParameter, SolParameterSelector, SolRetry,
+};
+
+mod config;
+pub use config::{GetSolConfigurationParameter, RawSolParameter, SetSolConfigurationParameter};
+
+mod packet;
+pub use packet::{SolOperation, SolPacket, SolStatus};
+
+mod stream;
+pub use stream::{SolStream, SolStreamConfig, SolTransport};
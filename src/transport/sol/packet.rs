@@ -0,0 +1,116 @@
+bitflags::bitflags! {
+    /// The operations that the remote console requests in a SOL packet.
+    #[derive(Default)]
+    pub struct SolOperation: u8 {
+        /// The packet was not accepted.
+        const NACK = 1 << 6;
+        /// Generate a ring indication or wake-on-ring.
+        const RING = 1 << 5;
+        /// Generate a break on the serial port.
+        const BREAK = 1 << 4;
+        /// Deassert CTS, pausing the transfer of characters by the system.
+        const DEASSERT_CTS = 1 << 3;
+        /// Drop DCD and DSR.
+        const DROP_DCD_DSR = 1 << 2;
+        /// Flush the inbound character buffer of the BMC.
+        const FLUSH_INBOUND = 1 << 1;
+        /// Flush the outbound character buffer of the BMC.
+        const FLUSH_OUTBOUND = 1 << 0;
+    }
+}
+
+bitflags::bitflags! {
+    /// The status reported by the BMC in a SOL packet.
+    #[derive(Default)]
+    pub struct SolStatus: u8 {
+        /// The packet was not accepted.
+        const NACK = 1 << 6;
+        /// Characters cannot be transferred, for instance because the
+        /// serial port is in use by the system.
+        const TRANSFER_UNAVAILABLE = 1 << 5;
+        /// The SOL payload is being deactivated.
+        const DEACTIVATING = 1 << 4;
+        /// Characters were dropped because the transmit buffer of the
+        /// BMC overflowed.
+        const TRANSMIT_OVERRUN = 1 << 3;
+        /// A break was detected on the serial port.
+        const BREAK = 1 << 2;
+    }
+}
+
+/// A SOL payload.
+///
+/// The operation/status byte is kept as raw bits: it contains a
+/// [`SolOperation`] in packets sent to the BMC, and a [`SolStatus`]
+/// in packets sent by the BMC.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SolPacket {
+    /// The sequence number of the packet, `1` through `15`, or `0` if
+    /// the packet only acknowledges another packet.
+    pub sequence: u8,
+    /// The sequence number of the packet that is acknowledged, or `0`
+    /// if no packet is acknowledged.
+    pub ack_sequence: u8,
+    /// The amount of characters of the acknowledged packet that
+    /// were accepted.
+    pub accepted: u8,
+    pub operation_status: u8,
+    pub data: Vec<u8>,
+}
+
+impl SolPacket {
+    /// The size of the header of a SOL packet.
+    pub const HEADER_SIZE: usize = 4;
+
+    /// The status of a packet received from the BMC.
+    pub fn status(&self) -> SolStatus {
+        SolStatus::from_bits_truncate(self.operation_status)
+    }
+
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::HEADER_SIZE {
+            return None;
+        }
+
+        Some(Self {
+            sequence: data[0] & 0xF,
+            ack_sequence: data[1] & 0xF,
+            accepted: data[2],
+            operation_status: data[3],
+            data: data[Self::HEADER_SIZE..].to_vec(),
+        })
+    }
+
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[
+            self.sequence & 0xF,
+            self.ack_sequence & 0xF,
+            self.accepted,
+            self.operation_status,
+        ]);
+        buffer.extend_from_slice(&self.data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let packet = SolPacket {
+            sequence: 3,
+            ack_sequence: 2,
+            accepted: 5,
+            operation_status: SolOperation::BREAK.bits(),
+            data: b"ls\r".to_vec(),
+        };
+
+        let mut data = Vec::new();
+        packet.write_data(&mut data);
+        assert_eq!(vec![0x03, 0x02, 0x05, 0x10, b'l', b's', b'\r'], data);
+
+        assert_eq!(Some(packet), SolPacket::parse(&data));
+        assert_eq!(None, SolPacket::parse(&data[..3]));
+    }
+}
@@ -0,0 +1,373 @@
+use std::time::Duration;
+
+use crate::{app::auth::PrivilegeLevel, sensor_event::pef::SetInProgress};
+
+/// The selector of a SOL configuration parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolParameterSelector {
+    SetInProgress,
+    Enable,
+    Authentication,
+    CharacterAccumulation,
+    Retry,
+    NonVolatileBitRate,
+    VolatileBitRate,
+    PayloadChannel,
+    PayloadPort,
+    /// A parameter that is not supported by this library.
+    Other(u8),
+}
+
+impl From<u8> for SolParameterSelector {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::SetInProgress,
+            1 => Self::Enable,
+            2 => Self::Authentication,
+            3 => Self::CharacterAccumulation,
+            4 => Self::Retry,
+            5 => Self::NonVolatileBitRate,
+            6 => Self::VolatileBitRate,
+            7 => Self::PayloadChannel,
+            8 => Self::PayloadPort,
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl From<SolParameterSelector> for u8 {
+    fn from(value: SolParameterSelector) -> Self {
+        type S = SolParameterSelector;
+
+        match value {
+            S::SetInProgress => 0,
+            S::Enable => 1,
+            S::Authentication => 2,
+            S::CharacterAccumulation => 3,
+            S::Retry => 4,
+            S::NonVolatileBitRate => 5,
+            S::VolatileBitRate => 6,
+            S::PayloadChannel => 7,
+            S::PayloadPort => 8,
+            S::Other(v) => v,
+        }
+    }
+}
+
+/// The authentication requirements of SOL.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolAuthentication {
+    /// Require the SOL payload to be encrypted.
+    pub force_encryption: bool,
+    /// Require the SOL payload to be authenticated.
+    pub force_authentication: bool,
+    /// The minimum privilege level that is required to activate SOL.
+    pub privilege: PrivilegeLevel,
+}
+
+impl SolAuthentication {
+    fn parse(value: u8) -> Option<Self> {
+        Some(Self {
+            force_encryption: (value & 0x80) == 0x80,
+            force_authentication: (value & 0x40) == 0x40,
+            privilege: PrivilegeLevel::try_from(value & 0xF).ok()?,
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.push(
+            (self.force_encryption as u8) << 7
+                | (self.force_authentication as u8) << 6
+                | u8::from(self.privilege),
+        );
+    }
+}
+
+/// When the BMC sends the characters that it receives from the
+/// serial port.
+///
+/// The BMC sends a packet when `threshold` characters have been
+/// accumulated, or when `interval` has passed since the first
+/// character was accumulated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CharacterAccumulation {
+    /// The accumulate interval, in increments of 5 ms. Must not be `0`.
+    pub interval: u8,
+    /// The character send threshold. Must not be `0`.
+    pub threshold: u8,
+}
+
+impl CharacterAccumulation {
+    /// The resolution of [`CharacterAccumulation::interval`].
+    pub const INTERVAL_TICK: Duration = Duration::from_millis(5);
+
+    /// The accumulate interval as a [`Duration`].
+    pub fn interval(&self) -> Duration {
+        Self::INTERVAL_TICK * self.interval as u32
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            interval: data[0],
+            threshold: data[1],
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[self.interval, self.threshold]);
+    }
+}
+
+/// How the BMC retries SOL packets that are not acknowledged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolRetry {
+    /// The amount of retries, `0` through `7`. A packet is dropped
+    /// if it is not acknowledged after the last retry.
+    pub count: u8,
+    /// The retry interval, in increments of 10 ms.
+    pub interval: u8,
+}
+
+impl SolRetry {
+    /// The resolution of [`SolRetry::interval`].
+    pub const INTERVAL_TICK: Duration = Duration::from_millis(10);
+
+    /// The retry interval as a [`Duration`].
+    pub fn interval(&self) -> Duration {
+        Self::INTERVAL_TICK * self.interval as u32
+    }
+
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            count: data[0] & 0x7,
+            interval: data[1],
+        })
+    }
+
+    fn write_data(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[self.count & 0x7, self.interval]);
+    }
+}
+
+/// The bit rate of the serial port while SOL is active.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitRate {
+    /// Use the bit rate of the IPMI over serial configuration.
+    Serial,
+    B9600,
+    B19200,
+    B38400,
+    B57600,
+    B115200,
+    Reserved(u8),
+}
+
+impl BitRate {
+    /// The bit rate in bits per second, or `None` if the bit
+    /// rate is not known.
+    pub fn bits_per_second(&self) -> Option<u32> {
+        match self {
+            BitRate::B9600 => Some(9600),
+            BitRate::B19200 => Some(19200),
+            BitRate::B38400 => Some(38400),
+            BitRate::B57600 => Some(57600),
+            BitRate::B115200 => Some(115200),
+            BitRate::Serial | BitRate::Reserved(_) => None,
+        }
+    }
+}
+
+impl From<u8> for BitRate {
+    fn from(value: u8) -> Self {
+        match value & 0xF {
+            0x0 => Self::Serial,
+            0x6 => Self::B9600,
+            0x7 => Self::B19200,
+            0x8 => Self::B38400,
+            0x9 => Self::B57600,
+            0xA => Self::B115200,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<BitRate> for u8 {
+    fn from(value: BitRate) -> Self {
+        match value {
+            BitRate::Serial => 0x0,
+            BitRate::B9600 => 0x6,
+            BitRate::B19200 => 0x7,
+            BitRate::B38400 => 0x8,
+            BitRate::B57600 => 0x9,
+            BitRate::B115200 => 0xA,
+            BitRate::Reserved(v) => v & 0xF,
+        }
+    }
+}
+
+/// A SOL configuration parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolParameter {
+    SetInProgress(SetInProgress),
+    /// Whether SOL is enabled.
+    Enable(bool),
+    Authentication(SolAuthentication),
+    CharacterAccumulation(CharacterAccumulation),
+    Retry(SolRetry),
+    NonVolatileBitRate(BitRate),
+    VolatileBitRate(BitRate),
+    /// The channel that SOL is activated on. Read only.
+    PayloadChannel(u8),
+    /// The UDP port that SOL is activated on.
+    PayloadPort(u16),
+    /// A parameter that is not supported by this library.
+    ///
+    /// `selector` must not be the selector of one of the supported
+    /// parameters.
+    Other {
+        selector: u8,
+        data: Vec<u8>,
+    },
+}
+
+impl SolParameter {
+    pub fn selector(&self) -> SolParameterSelector {
+        type S = SolParameterSelector;
+
+        match self {
+            Self::SetInProgress(_) => S::SetInProgress,
+            Self::Enable(_) => S::Enable,
+            Self::Authentication(_) => S::Authentication,
+            Self::CharacterAccumulation(_) => S::CharacterAccumulation,
+            Self::Retry(_) => S::Retry,
+            Self::NonVolatileBitRate(_) => S::NonVolatileBitRate,
+            Self::VolatileBitRate(_) => S::VolatileBitRate,
+            Self::PayloadChannel(_) => S::PayloadChannel,
+            Self::PayloadPort(_) => S::PayloadPort,
+            Self::Other { selector, .. } => S::Other(*selector),
+        }
+    }
+
+    /// Parse the data of the parameter selected by `selector`.
+    ///
+    /// Returns `None` if `data` is too short, or if it contains an
+    /// invalid privilege level.
+    pub fn parse(selector: SolParameterSelector, data: &[u8]) -> Option<Self> {
+        type S = SolParameterSelector;
+
+        let first = data.first().copied();
+
+        let value = match selector {
+            S::SetInProgress => Self::SetInProgress(first?.into()),
+            S::Enable => Self::Enable((first? & 0x01) == 0x01),
+            S::Authentication => Self::Authentication(SolAuthentication::parse(first?)?),
+            S::CharacterAccumulation => {
+                Self::CharacterAccumulation(CharacterAccumulation::parse(data)?)
+            }
+            S::Retry => Self::Retry(SolRetry::parse(data)?),
+            S::NonVolatileBitRate => Self::NonVolatileBitRate(first?.into()),
+            S::VolatileBitRate => Self::VolatileBitRate(first?.into()),
+            S::PayloadChannel => Self::PayloadChannel(first? & 0xF),
+            S::PayloadPort => {
+                let port = data.get(..2)?;
+                Self::PayloadPort(u16::from_le_bytes([port[0], port[1]]))
+            }
+            S::Other(selector) => Self::Other {
+                selector,
+                data: data.to_vec(),
+            },
+        };
+
+        Some(value)
+    }
+
+    /// Write the data of the parameter, excluding the selector.
+    pub fn write_data(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::SetInProgress(v) => buffer.push((*v).into()),
+            Self::Enable(v) => buffer.push(*v as u8),
+            Self::Authentication(v) => v.write_data(buffer),
+            Self::CharacterAccumulation(v) => v.write_data(buffer),
+            Self::Retry(v) => v.write_data(buffer),
+            Self::NonVolatileBitRate(v) | Self::VolatileBitRate(v) => buffer.push((*v).into()),
+            Self::PayloadChannel(v) => buffer.push(v & 0xF),
+            Self::PayloadPort(v) => buffer.extend_from_slice(&v.to_le_bytes()),
+            Self::Other { data, .. } => buffer.extend_from_slice(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(parameter: SolParameter) {
+        let mut data = Vec::new();
+        parameter.write_data(&mut data);
+
+        let selector = parameter.selector();
+        assert_eq!(selector, SolParameterSelector::from(u8::from(selector)));
+
+        let parsed = SolParameter::parse(selector, &data);
+        assert_eq!(Some(parameter), parsed);
+    }
+
+    #[test]
+    fn parameters() {
+        round_trip(SolParameter::SetInProgress(SetInProgress::InProgress));
+        round_trip(SolParameter::Enable(true));
+        round_trip(SolParameter::Enable(false));
+        round_trip(SolParameter::CharacterAccumulation(CharacterAccumulation {
+            interval: 12,
+            threshold: 96,
+        }));
+        round_trip(SolParameter::Retry(SolRetry {
+            count: 7,
+            interval: 50,
+        }));
+        round_trip(SolParameter::NonVolatileBitRate(BitRate::B115200));
+        round_trip(SolParameter::VolatileBitRate(BitRate::Serial));
+        round_trip(SolParameter::PayloadChannel(1));
+        round_trip(SolParameter::PayloadPort(623));
+        round_trip(SolParameter::Other {
+            selector: 0xC0,
+            data: vec![0x01, 0x02],
+        });
+    }
+
+    #[test]
+    fn authentication() {
+        let authentication = SolAuthentication {
+            force_encryption: true,
+            force_authentication: false,
+            privilege: PrivilegeLevel::User,
+        };
+
+        let mut data = Vec::new();
+        SolParameter::Authentication(authentication).write_data(&mut data);
+        assert_eq!(vec![0x82], data);
+
+        round_trip(SolParameter::Authentication(authentication));
+
+        assert_eq!(
+            None,
+            SolParameter::parse(SolParameterSelector::Authentication, &[0x80])
+        );
+    }
+
+    #[test]
+    fn bit_rate() {
+        assert_eq!(BitRate::B9600, BitRate::from(0x06));
+        assert_eq!(Some(57600), BitRate::from(0x09).bits_per_second());
+        assert_eq!(None, BitRate::Serial.bits_per_second());
+        assert_eq!(BitRate::Reserved(0x3), BitRate::from(0x03));
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+use crate::app::payload::ActivatedPayload;
+
+use super::{SolOperation, SolPacket, SolStatus};
+
+/// A transport over which SOL payloads are exchanged with the BMC,
+/// such as an RMCP+ session on which SOL has been activated.
+pub trait SolTransport {
+    /// Send a SOL payload to the BMC.
+    fn send(&mut self, payload: &[u8]) -> io::Result<()>;
+
+    /// Receive a SOL payload from the BMC, waiting at most `timeout`.
+    ///
+    /// Returns `Ok(None)` if no payload was received in time.
+    fn recv(&mut self, timeout: Duration) -> io::Result<Option<Vec<u8>>>;
+}
+
+/// The configuration of a [`SolStream`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolStreamConfig {
+    /// The maximum amount of characters in a packet.
+    pub max_characters: usize,
+    /// The maximum time that written characters are accumulated
+    /// before they are sent.
+    pub accumulate_interval: Duration,
+    /// Send accumulated characters as soon as this many are available.
+    pub send_threshold: usize,
+    /// The time to wait for a packet to be acknowledged before it
+    /// is sent again.
+    pub retry_interval: Duration,
+    /// The amount of times a packet is sent again before giving up.
+    pub retry_count: u8,
+}
+
+impl SolStreamConfig {
+    /// The default configuration for an activated SOL payload.
+    pub fn new(activated: &ActivatedPayload) -> Self {
+        let size = activated.inbound_payload_size as usize;
+
+        Self {
+            max_characters: size.saturating_sub(SolPacket::HEADER_SIZE).clamp(1, 255),
+            accumulate_interval: Duration::from_millis(60),
+            send_threshold: 96,
+            retry_interval: Duration::from_millis(500),
+            retry_count: 7,
+        }
+    }
+}
+
+struct Outstanding {
+    packet: SolPacket,
+    sent_at: Instant,
+    retries: u8,
+}
+
+/// A serial console on the other side of an activated SOL payload.
+///
+/// Written characters are accumulated until
+/// [`SolStreamConfig::send_threshold`] characters are available, or
+/// [`SolStreamConfig::accumulate_interval`] has passed, and are sent
+/// while reading or flushing. Only one packet is in flight at a time:
+/// it is sent again until it is acknowledged, and characters that
+/// the BMC did not accept are sent in the next packet.
+///
+/// Reading blocks until characters are received, the read timeout
+/// passes or the BMC deactivates the payload, after which reading
+/// returns `0`.
+pub struct SolStream<T> {
+    transport: T,
+    config: SolStreamConfig,
+    read_timeout: Option<Duration>,
+    next_sequence: u8,
+    outstanding: Option<Outstanding>,
+    pending: Vec<u8>,
+    pending_since: Option<Instant>,
+    operation: SolOperation,
+    received: VecDeque<u8>,
+    last_received: Option<u8>,
+    status: SolStatus,
+    break_detected: bool,
+}
+
+impl<T: SolTransport> SolStream<T> {
+    pub fn new(transport: T, config: SolStreamConfig) -> Self {
+        Self {
+            transport,
+            config,
+            read_timeout: None,
+            next_sequence: 1,
+            outstanding: None,
+            pending: Vec::new(),
+            pending_since: None,
+            operation: SolOperation::empty(),
+            received: VecDeque::new(),
+            last_received: None,
+            status: SolStatus::empty(),
+            break_detected: false,
+        }
+    }
+
+    /// Set the maximum time that reading blocks. If it passes, reading
+    /// fails with [`io::ErrorKind::TimedOut`].
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) {
+        self.read_timeout = timeout;
+    }
+
+    /// The status of the last packet received from the BMC.
+    pub fn status(&self) -> SolStatus {
+        self.status
+    }
+
+    /// Whether the BMC is deactivating the SOL payload.
+    pub fn is_deactivated(&self) -> bool {
+        self.status.contains(SolStatus::DEACTIVATING)
+    }
+
+    /// Whether a break was detected on the serial port since the
+    /// last call to this function.
+    pub fn take_break(&mut self) -> bool {
+        core::mem::take(&mut self.break_detected)
+    }
+
+    /// Generate a break on the serial port, after sending the
+    /// characters that have been written.
+    pub fn send_break(&mut self) -> io::Result<()> {
+        use io::Write;
+
+        self.flush()?;
+        self.operation |= SolOperation::BREAK;
+        self.flush()
+    }
+
+    pub fn get_ref(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn into_inner(self) -> T {
+        self.transport
+    }
+
+    fn poll_interval(&self) -> Duration {
+        self.config
+            .retry_interval
+            .min(self.config.accumulate_interval)
+            .max(Duration::from_millis(1))
+    }
+
+    fn check_active(&self) -> io::Result<()> {
+        if self.is_deactivated() {
+            Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "SOL payload was deactivated",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn sequence(&mut self) -> u8 {
+        let sequence = self.next_sequence;
+        self.next_sequence = if sequence >= 15 { 1 } else { sequence + 1 };
+        sequence
+    }
+
+    /// Send the next packet, if no packet is in flight and characters
+    /// or operations are waiting to be sent.
+    fn send_next(&mut self, force: bool) -> io::Result<()> {
+        if self.outstanding.is_some() || (self.pending.is_empty() && self.operation.is_empty()) {
+            return Ok(());
+        }
+
+        let accumulated = self
+            .pending_since
+            .map(|since| since.elapsed() >= self.config.accumulate_interval)
+            .unwrap_or(false);
+
+        let send = force
+            || accumulated
+            || !self.operation.is_empty()
+            || self.pending.len() >= self.config.send_threshold;
+
+        if !send {
+            return Ok(());
+        }
+
+        let len = self.pending.len().min(self.config.max_characters);
+
+        let packet = SolPacket {
+            sequence: self.sequence(),
+            ack_sequence: 0,
+            accepted: 0,
+            operation_status: core::mem::take(&mut self.operation).bits(),
+            data: self.pending.drain(..len).collect(),
+        };
+
+        if self.pending.is_empty() {
+            self.pending_since = None;
+        }
+
+        self.send_packet(&packet)?;
+
+        self.outstanding = Some(Outstanding {
+            packet,
+            sent_at: Instant::now(),
+            retries: 0,
+        });
+
+        Ok(())
+    }
+
+    /// Send the packet in flight again, if it has not been
+    /// acknowledged in time.
+    fn retransmit(&mut self) -> io::Result<()> {
+        let outstanding = match &mut self.outstanding {
+            Some(v) if v.sent_at.elapsed() >= self.config.retry_interval => v,
+            _ => return Ok(()),
+        };
+
+        if outstanding.retries >= self.config.retry_count {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "SOL packet was not acknowledged",
+            ));
+        }
+
+        outstanding.retries += 1;
+        outstanding.sent_at = Instant::now();
+
+        let mut data = Vec::new();
+        outstanding.packet.write_data(&mut data);
+        self.transport.send(&data)
+    }
+
+    fn send_packet(&mut self, packet: &SolPacket) -> io::Result<()> {
+        let mut data = Vec::new();
+        packet.write_data(&mut data);
+        self.transport.send(&data)
+    }
+
+    fn handle(&mut self, packet: SolPacket) -> io::Result<()> {
+        self.status = packet.status();
+
+        if self.status.contains(SolStatus::BREAK) {
+            self.break_detected = true;
+        }
+
+        let acknowledged = self
+            .outstanding
+            .as_ref()
+            .map(|v| packet.ack_sequence != 0 && v.packet.sequence == packet.ack_sequence)
+            .unwrap_or(false);
+
+        if acknowledged {
+            if self.status.contains(SolStatus::NACK) {
+                // The BMC cannot accept characters right now: send the
+                // packet again after the retry interval.
+                let outstanding = self.outstanding.as_mut().unwrap();
+                outstanding.retries = 0;
+                outstanding.sent_at = Instant::now();
+            } else {
+                let outstanding = self.outstanding.take().unwrap();
+                let data = outstanding.packet.data;
+                let accepted = (packet.accepted as usize).min(data.len());
+
+                if accepted < data.len() {
+                    self.pending.splice(..0, data[accepted..].iter().copied());
+                    self.send_next(true)?;
+                }
+            }
+        }
+
+        if packet.sequence != 0 {
+            if self.last_received == Some(packet.sequence) {
+                log::debug!(
+                    "Received SOL packet {} again, acknowledging it again",
+                    packet.sequence
+                );
+            } else {
+                self.received.extend(&packet.data);
+                self.last_received = Some(packet.sequence);
+            }
+
+            let ack = SolPacket {
+                sequence: 0,
+                ack_sequence: packet.sequence,
+                accepted: packet.data.len() as u8,
+                operation_status: 0,
+                data: Vec::new(),
+            };
+
+            self.send_packet(&ack)?;
+        }
+
+        Ok(())
+    }
+
+    /// Send packets that are due, and handle the next packet received
+    /// within `timeout`.
+    fn poll(&mut self, timeout: Duration) -> io::Result<()> {
+        self.send_next(false)?;
+
+        if let Some(payload) = self.transport.recv(timeout)? {
+            match SolPacket::parse(&payload) {
+                Some(packet) => self.handle(packet)?,
+                None => log::warn!("Ignoring SOL payload of {} bytes", payload.len()),
+            }
+        }
+
+        self.retransmit()
+    }
+}
+
+impl<T: SolTransport> io::Read for SolStream<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let deadline = self.read_timeout.map(|v| Instant::now() + v);
+
+        loop {
+            if !self.received.is_empty() {
+                let len = buf.len().min(self.received.len());
+                for (dst, src) in buf.iter_mut().zip(self.received.drain(..len)) {
+                    *dst = src;
+                }
+                return Ok(len);
+            }
+
+            if self.is_deactivated() {
+                return Ok(0);
+            }
+
+            let mut timeout = self.poll_interval();
+
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "no SOL characters received",
+                    ));
+                }
+                timeout = timeout.min(deadline - now);
+            }
+
+            self.poll(timeout)?;
+        }
+    }
+}
+
+impl<T: SolTransport> io::Write for SolStream<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.check_active()?;
+
+        // Limit the amount of characters that are waiting to be sent.
+        while self.pending.len() >= 4 * self.config.max_characters {
+            self.poll(self.poll_interval())?;
+            self.check_active()?;
+        }
+
+        if self.pending_since.is_none() {
+            self.pending_since = Some(Instant::now());
+        }
+
+        self.pending.extend_from_slice(buf);
+        self.send_next(false)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        while self.outstanding.is_some() || !self.pending.is_empty() || !self.operation.is_empty() {
+            self.check_active()?;
+            self.send_next(true)?;
+            self.poll(self.poll_interval())?;
+        }
+
+        Ok(())
+    }
+}